@@ -5,17 +5,45 @@
 //!
 //! - [`Model`] — a callable that maps a typed input to a typed output
 //! - [`Snapshot`] — a captured input/output pair from a model call
+//! - [`Chain`], [`Parallel`], [`MapInput`], [`MapOutput`] — combinators that
+//!   compose models into larger models, with [`StageError`] recording which
+//!   component failed
+//! - [`Cached`] — a model wrapper that reuses outputs for repeated inputs
+//! - [`Instrumented`] — a model wrapper that counts and times calls
 //! - [`Observer`] — receives solver events and optionally returns control actions
 //! - [`EquationProblem`], [`OptimizationProblem`], [`OdeProblem`] — problem
 //!   traits that adapt solver variables to model inputs and extract metrics from
 //!   outputs
+//! - [`LeastSquaresProblem`] — like [`EquationProblem`], but with any number of
+//!   residuals, whose sum of squares solvers minimize
+//! - [`BoundedProblem`], [`Bound`] — optimization problems whose variables have
+//!   lower and upper limits
+//! - [`ConstrainedOptimizationProblem`], [`Constraint`] — optimization problems
+//!   with equality and inequality constraints computed from model outputs
+//! - [`StepIntegrable`], [`ErrorNorm`], [`Flatten`] — capabilities of ODE
+//!   states that let solvers step them forward, measure local error, and solve
+//!   for them implicitly
+//! - [`Norm`] — measures the size of a state's derivative, so solvers can
+//!   detect a steady state
+//! - [`Interpolate`] — blends two states, so a solution history can be
+//!   evaluated between steps
+//! - [`IndependentVariable`] — exposes the current time (or other independent
+//!   variable) so ODE solvers can integrate up to a target value
+//! - [`ZeroCrossing`] — event functions whose sign changes mark discrete events
+//!   during ODE integration
 
 mod model;
 mod observer;
 mod problems;
 mod step;
 
+pub use model::{
+    CacheStats, Cached, CallStats, Chain, ExactBits, Instrumented, MapInput, MapOutput, Model,
+    Parallel, Snapshot, StageError, exact_bits,
+};
 pub use observer::Observer;
-pub use problems::{EquationProblem, OdeProblem, OptimizationProblem};
-pub use step::{DerivativeOf, StepIntegrable};
-pub use {model::Model, model::Snapshot};
+pub use problems::{
+    Bound, BoundedProblem, ConstrainedOptimizationProblem, Constraint, EquationProblem,
+    IndependentVariable, LeastSquaresProblem, OdeProblem, OptimizationProblem, ZeroCrossing,
+};
+pub use step::{DerivativeOf, ErrorNorm, Flatten, Interpolate, Norm, StepIntegrable};
@@ -1,3 +1,11 @@
+mod cached;
+mod combinators;
+mod instrumented;
+
+pub use cached::{CacheStats, Cached, ExactBits, exact_bits};
+pub use combinators::{Chain, MapInput, MapOutput, Parallel, StageError};
+pub use instrumented::{CallStats, Instrumented};
+
 /// A callable model that maps a typed input to a typed output.
 ///
 /// Models must be deterministic, always producing the same result for a given
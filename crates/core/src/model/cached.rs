@@ -0,0 +1,381 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    sync::{Mutex, PoisonError},
+};
+
+use super::Model;
+
+/// A model wrapper that remembers outputs and reuses them for repeated inputs.
+///
+/// Because models are deterministic, calling one twice with the same input
+/// gives the same output, so the second call can be answered from memory.
+/// Solvers revisit inputs more often than it might seem: during restarts,
+/// across the points of a sweep, or when an outer loop re-solves a problem.
+///
+/// Inputs are matched by a key computed with a user-supplied function, since
+/// many inputs (such as floats) are not hashable themselves. For `f64` and
+/// arrays of `f64`, [`exact_bits`] gives a key that matches inputs only when
+/// they are bit-for-bit identical.
+///
+/// Only successful outputs are cached. A call that fails is repeated the next
+/// time its input is seen, and counts as a miss.
+///
+/// The cache is unbounded by default. Use [`with_capacity`](Self::with_capacity)
+/// to keep only the most recently used outputs.
+///
+/// # Example
+///
+/// ```ignore
+/// let model = Cached::new(expensive, exact_bits).with_capacity(1024);
+/// let solution = newton::solve_unobserved(&model, &problem, x0, &config)?;
+/// println!("{} hits, {} misses", model.stats().hits, model.stats().misses);
+/// ```
+pub struct Cached<M: Model, F, K> {
+    model: M,
+    key: F,
+    capacity: Option<usize>,
+    state: Mutex<State<K, M::Output>>,
+}
+
+/// Hit and miss counts for a [`Cached`] model.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Calls answered from the cache.
+    pub hits: usize,
+    /// Calls passed through to the wrapped model.
+    pub misses: usize,
+}
+
+impl CacheStats {
+    /// Returns the total number of calls.
+    #[must_use]
+    pub fn calls(&self) -> usize {
+        self.hits + self.misses
+    }
+}
+
+impl<M: Model, F, K> Cached<M, F, K>
+where
+    F: Fn(&M::Input) -> K,
+    K: Hash + Eq + Clone,
+{
+    /// Wraps `model` with an unbounded cache keyed by `key(input)`.
+    pub fn new(model: M, key: F) -> Self {
+        Self {
+            model,
+            key,
+            capacity: None,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Limits the cache to the `capacity` most recently used outputs.
+    ///
+    /// A capacity of zero disables caching, while still counting calls.
+    #[must_use]
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self.state_mut().evict_to(capacity);
+        self
+    }
+}
+
+impl<M: Model, F, K> Cached<M, F, K> {
+    /// Returns the wrapped model.
+    pub fn inner(&self) -> &M {
+        &self.model
+    }
+
+    /// Consumes the wrapper and returns the wrapped model.
+    pub fn into_inner(self) -> M {
+        self.model
+    }
+
+    /// Returns the hit and miss counts since creation or the last
+    /// [`clear`](Self::clear).
+    pub fn stats(&self) -> CacheStats {
+        self.lock().stats
+    }
+
+    /// Returns the number of cached outputs.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns true if no outputs are cached.
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// Removes every cached output and resets the statistics.
+    pub fn clear(&self) {
+        *self.lock() = State::default();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State<K, M::Output>> {
+        // A panic elsewhere cannot leave the state inconsistent, so a poisoned
+        // lock is still safe to use.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn state_mut(&mut self) -> &mut State<K, M::Output> {
+        self.state.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<M, F, K> Model for Cached<M, F, K>
+where
+    M: Model,
+    M::Output: Clone,
+    F: Fn(&M::Input) -> K,
+    K: Hash + Eq + Clone,
+{
+    type Input = M::Input;
+    type Output = M::Output;
+    type Error = M::Error;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        let key = (self.key)(input);
+
+        {
+            let mut state = self.lock();
+            if let Some(output) = state.get(&key) {
+                state.stats.hits += 1;
+                return Ok(output);
+            }
+            state.stats.misses += 1;
+        }
+
+        // The lock is released while the model runs, so other threads can
+        // still use the cache. Two threads missing on the same input both
+        // call the model, which is harmless because the model is deterministic.
+        let output = self.model.call(input)?;
+
+        if self.capacity != Some(0) {
+            let mut state = self.lock();
+            state.insert(key, output.clone());
+            if let Some(capacity) = self.capacity {
+                state.evict_to(capacity);
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+/// Cache entries with least-recently-used ordering.
+struct State<K, O> {
+    /// Cached outputs and the tick at which each was last used.
+    entries: HashMap<K, (O, u64)>,
+    /// Keys ordered by the tick at which they were last used.
+    recency: BTreeMap<u64, K>,
+    tick: u64,
+    stats: CacheStats,
+}
+
+impl<K, O> Default for State<K, O> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            stats: CacheStats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, O: Clone> State<K, O> {
+    /// Returns the cached output for `key` and marks it as most recently used.
+    fn get(&mut self, key: &K) -> Option<O> {
+        let tick = self.next_tick();
+        let (output, last_used) = self.entries.get_mut(key)?;
+        let key = self
+            .recency
+            .remove(last_used)
+            .expect("every entry has a recency tick");
+        *last_used = tick;
+        self.recency.insert(tick, key);
+        Some(output.clone())
+    }
+
+    /// Caches `output` under `key` as the most recently used entry.
+    fn insert(&mut self, key: K, output: O) {
+        let tick = self.next_tick();
+        if let Some((_, last_used)) = self.entries.insert(key.clone(), (output, tick)) {
+            self.recency.remove(&last_used);
+        }
+        self.recency.insert(tick, key);
+    }
+}
+
+impl<K: Hash + Eq, O> State<K, O> {
+    /// Drops least recently used entries until at most `capacity` remain.
+    fn evict_to(&mut self, capacity: usize) {
+        while self.entries.len() > capacity {
+            let Some((_, key)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&key);
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+/// Inputs that can be keyed by their exact bit patterns.
+///
+/// See [`exact_bits`].
+pub trait ExactBits {
+    /// The bit pattern of the input, usable as a cache key.
+    type Bits: Hash + Eq + Clone;
+
+    /// Returns the bit pattern of the input.
+    fn exact_bits(&self) -> Self::Bits;
+}
+
+impl ExactBits for f64 {
+    type Bits = u64;
+
+    fn exact_bits(&self) -> u64 {
+        self.to_bits()
+    }
+}
+
+impl<const N: usize> ExactBits for [f64; N] {
+    type Bits = [u64; N];
+
+    fn exact_bits(&self) -> [u64; N] {
+        self.map(f64::to_bits)
+    }
+}
+
+/// Cache key that matches inputs only when they are bit-for-bit identical.
+///
+/// Pass it as the key function of [`Cached::new`] for models whose input is an
+/// `f64` or an array of `f64`. Values that compare equal but differ in bits,
+/// such as `0.0` and `-0.0`, get separate entries, and identical `NaN`s share
+/// one.
+pub fn exact_bits<T: ExactBits>(input: &T) -> T::Bits {
+    input.exact_bits()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{cell::Cell, fmt};
+
+    /// Squares its input, counting calls and failing for negative inputs.
+    #[derive(Default)]
+    struct Square {
+        calls: Cell<usize>,
+    }
+
+    #[derive(Debug)]
+    struct Negative;
+
+    impl fmt::Display for Negative {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("negative input")
+        }
+    }
+
+    impl std::error::Error for Negative {}
+
+    impl Model for Square {
+        type Input = f64;
+        type Output = f64;
+        type Error = Negative;
+
+        fn call(&self, input: &f64) -> Result<f64, Negative> {
+            self.calls.set(self.calls.get() + 1);
+            if *input < 0.0 {
+                Err(Negative)
+            } else {
+                Ok(input * input)
+            }
+        }
+    }
+
+    #[test]
+    fn repeated_inputs_are_hits() {
+        let model = Cached::new(Square::default(), exact_bits);
+
+        for x in [1.0, 2.0, 1.0, 1.0, 2.0] {
+            assert_eq!(model.call(&x).ok(), Some(x * x));
+        }
+
+        assert_eq!(model.stats(), CacheStats { hits: 3, misses: 2 });
+        assert_eq!(model.inner().calls.get(), 2);
+        assert_eq!(model.len(), 2);
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let model = Cached::new(Square::default(), exact_bits);
+
+        assert!(model.call(&-1.0).is_err());
+        assert!(model.call(&-1.0).is_err());
+
+        assert_eq!(model.stats(), CacheStats { hits: 0, misses: 2 });
+        assert!(model.is_empty());
+    }
+
+    #[test]
+    fn exact_bits_distinguishes_signed_zeros() {
+        let model = Cached::new(Square::default(), exact_bits);
+
+        model.call(&0.0).expect("should succeed");
+        model.call(&-0.0).expect("should succeed");
+
+        assert_eq!(model.stats().misses, 2);
+        assert_eq!(exact_bits(&[1.0, f64::NAN]), exact_bits(&[1.0, f64::NAN]));
+    }
+
+    #[test]
+    fn capacity_evicts_least_recently_used() {
+        let model = Cached::new(Square::default(), exact_bits).with_capacity(2);
+
+        // Touching 1.0 again makes 2.0 the least recently used when 3.0 arrives.
+        for x in [1.0, 2.0, 1.0, 3.0] {
+            model.call(&x).expect("should succeed");
+        }
+        assert_eq!(model.len(), 2);
+
+        model.call(&1.0).expect("should succeed");
+        model.call(&2.0).expect("should succeed");
+
+        assert_eq!(model.stats(), CacheStats { hits: 2, misses: 4 });
+    }
+
+    #[test]
+    fn zero_capacity_only_counts() {
+        let model = Cached::new(Square::default(), exact_bits).with_capacity(0);
+
+        model.call(&1.0).expect("should succeed");
+        model.call(&1.0).expect("should succeed");
+
+        assert_eq!(model.stats(), CacheStats { hits: 0, misses: 2 });
+        assert!(model.is_empty());
+    }
+
+    #[test]
+    fn custom_key_and_clear() {
+        // Round to the nearest integer, so nearby inputs share an entry.
+        #[allow(clippy::cast_possible_truncation)]
+        let model = Cached::new(Square::default(), |x: &f64| x.round() as i64);
+
+        model.call(&2.0).expect("should succeed");
+        assert_eq!(model.call(&2.2).ok(), Some(4.0));
+        assert_eq!(model.stats().hits, 1);
+
+        model.clear();
+
+        assert_eq!(model.stats(), CacheStats::default());
+        assert!(model.is_empty());
+    }
+}
@@ -0,0 +1,305 @@
+use std::{error::Error, fmt, marker::PhantomData};
+
+use super::Model;
+
+/// A model that feeds the output of one model into another.
+///
+/// The output of `first` is the input of `second`, so the composite maps
+/// `A::Input` to `B::Output`. Errors are wrapped in [`StageError`] to record
+/// which model failed.
+#[derive(Debug, Clone, Copy)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Chain<A, B> {
+    /// Creates a model that calls `first`, then `second` on its output.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Returns the first model.
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Returns the second model.
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<A, B> Model for Chain<A, B>
+where
+    A: Model,
+    B: Model<Input = A::Output>,
+{
+    type Input = A::Input;
+    type Output = B::Output;
+    type Error = StageError<A::Error, B::Error>;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        let intermediate = self.first.call(input).map_err(StageError::First)?;
+        self.second.call(&intermediate).map_err(StageError::Second)
+    }
+}
+
+/// A model that calls two models on the same input.
+///
+/// The output is the pair `(A::Output, B::Output)`. The first model is called
+/// first, and the second is not called if it fails. Errors are wrapped in
+/// [`StageError`] to record which model failed.
+#[derive(Debug, Clone, Copy)]
+pub struct Parallel<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Parallel<A, B> {
+    /// Creates a model that calls both `first` and `second` on its input.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Returns the first model.
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Returns the second model.
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<A, B> Model for Parallel<A, B>
+where
+    A: Model,
+    B: Model<Input = A::Input>,
+{
+    type Input = A::Input;
+    type Output = (A::Output, B::Output);
+    type Error = StageError<A::Error, B::Error>;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        let first = self.first.call(input).map_err(StageError::First)?;
+        let second = self.second.call(input).map_err(StageError::Second)?;
+        Ok((first, second))
+    }
+}
+
+/// A model that converts its input before calling an inner model.
+///
+/// Useful for adapting a component to the input type of a larger system, such
+/// as extracting one field of a shared state.
+pub struct MapInput<M, F, I> {
+    model: M,
+    map: F,
+    input: PhantomData<fn(&I)>,
+}
+
+impl<M, F, I> MapInput<M, F, I>
+where
+    M: Model,
+    F: Fn(&I) -> M::Input,
+{
+    /// Creates a model that calls `model` on `map(input)`.
+    pub fn new(model: M, map: F) -> Self {
+        Self {
+            model,
+            map,
+            input: PhantomData,
+        }
+    }
+}
+
+impl<M, F, I> MapInput<M, F, I> {
+    /// Returns the inner model.
+    pub fn inner(&self) -> &M {
+        &self.model
+    }
+}
+
+impl<M, F, I> Model for MapInput<M, F, I>
+where
+    M: Model,
+    F: Fn(&I) -> M::Input,
+{
+    type Input = I;
+    type Output = M::Output;
+    type Error = M::Error;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.model.call(&(self.map)(input))
+    }
+}
+
+/// A model that converts the output of an inner model.
+///
+/// Useful for keeping only the part of an output a problem needs, or for
+/// combining the pair produced by [`Parallel`] into one value.
+pub struct MapOutput<M, F> {
+    model: M,
+    map: F,
+}
+
+impl<M, F, O> MapOutput<M, F>
+where
+    M: Model,
+    F: Fn(M::Output) -> O,
+{
+    /// Creates a model that returns `map(model.call(input))`.
+    pub fn new(model: M, map: F) -> Self {
+        Self { model, map }
+    }
+}
+
+impl<M, F> MapOutput<M, F> {
+    /// Returns the inner model.
+    pub fn inner(&self) -> &M {
+        &self.model
+    }
+}
+
+impl<M, F, O> Model for MapOutput<M, F>
+where
+    M: Model,
+    F: Fn(M::Output) -> O,
+{
+    type Input = M::Input;
+    type Output = O;
+    type Error = M::Error;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.model.call(input).map(&self.map)
+    }
+}
+
+/// Error from a two-stage composite model, recording which stage failed.
+///
+/// For [`Chain`] and [`Parallel`], `First` holds an error from the first
+/// model and `Second` an error from the second. Nested composites produce
+/// nested errors, so the failing component can always be traced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StageError<A, B> {
+    First(A),
+    Second(B),
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for StageError<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::First(error) => write!(f, "first stage failed: {error}"),
+            Self::Second(error) => write!(f, "second stage failed: {error}"),
+        }
+    }
+}
+
+impl<A, B> Error for StageError<A, B>
+where
+    A: Error + 'static,
+    B: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::First(error) => Some(error),
+            Self::Second(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    /// Doubles its input.
+    struct Double;
+
+    impl Model for Double {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, input: &f64) -> Result<f64, Infallible> {
+            Ok(2.0 * input)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Negative(f64);
+
+    impl fmt::Display for Negative {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "negative input: {}", self.0)
+        }
+    }
+
+    impl Error for Negative {}
+
+    /// Takes the square root, failing for negative inputs.
+    struct Sqrt;
+
+    impl Model for Sqrt {
+        type Input = f64;
+        type Output = f64;
+        type Error = Negative;
+
+        fn call(&self, input: &f64) -> Result<f64, Negative> {
+            if *input < 0.0 {
+                Err(Negative(*input))
+            } else {
+                Ok(input.sqrt())
+            }
+        }
+    }
+
+    #[test]
+    fn chain_feeds_output_to_input() {
+        let model = Chain::new(Double, Sqrt);
+
+        assert_eq!(model.call(&8.0), Ok(4.0));
+        assert_eq!(model.call(&-2.0), Err(StageError::Second(Negative(-4.0))));
+    }
+
+    #[test]
+    fn parallel_shares_input() {
+        let model = Parallel::new(Sqrt, Double);
+
+        assert_eq!(model.call(&9.0), Ok((3.0, 18.0)));
+        assert_eq!(model.call(&-1.0), Err(StageError::First(Negative(-1.0))));
+    }
+
+    #[test]
+    fn maps_adapt_input_and_output() {
+        // Take the root of the first component, then add both results.
+        let model = MapOutput::new(
+            Parallel::new(
+                MapInput::new(Sqrt, |x: &[f64; 2]| x[0]),
+                MapInput::new(Double, |x: &[f64; 2]| x[1]),
+            ),
+            |(a, b)| a + b,
+        );
+
+        assert_eq!(model.call(&[16.0, 0.5]), Ok(5.0));
+    }
+
+    #[test]
+    fn nested_errors_trace_the_failing_stage() {
+        let model = Chain::new(Double, Chain::new(Double, Sqrt));
+
+        let error = model.call(&-1.0).expect_err("should fail");
+
+        assert_eq!(
+            error,
+            StageError::Second(StageError::Second(Negative(-4.0)))
+        );
+        assert_eq!(
+            error.to_string(),
+            "second stage failed: second stage failed: negative input: -4"
+        );
+        assert!(error.source().is_some());
+    }
+}
@@ -0,0 +1,266 @@
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use super::{Model, Snapshot};
+
+/// A model wrapper that counts and times every call.
+///
+/// Solver solutions report iterations, which do not include evaluations made
+/// while initializing, approximating derivatives, or searching along a step.
+/// Wrapping the model before a solve and reading [`stats`](Self::stats)
+/// afterward gives the true cost of the run.
+///
+/// Use [`with_log`](Self::with_log) to also keep the snapshots of the most
+/// recent successful calls.
+///
+/// # Example
+///
+/// ```ignore
+/// let model = Instrumented::new(model).with_log(100);
+/// let solution = newton::solve_unobserved(&model, &problem, x0, &config)?;
+/// let stats = model.stats();
+/// println!("{} calls in {:?}", stats.calls, stats.total);
+/// ```
+pub struct Instrumented<M: Model> {
+    model: M,
+    state: Mutex<State<M::Input, M::Output>>,
+}
+
+/// Call counts and timings recorded by an [`Instrumented`] model.
+///
+/// Timings are wall-clock durations of the wrapped model's calls, including
+/// calls that fail. `min` and `max` are zero until the first call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CallStats {
+    /// Total number of calls.
+    pub calls: usize,
+    /// Number of calls that returned an error.
+    pub errors: usize,
+    /// Total time spent in the wrapped model.
+    pub total: Duration,
+    /// Shortest call.
+    pub min: Duration,
+    /// Longest call.
+    pub max: Duration,
+}
+
+impl CallStats {
+    /// Returns the mean call duration, or `None` if there were no calls.
+    #[must_use]
+    pub fn mean(&self) -> Option<Duration> {
+        self.total.checked_div(u32::try_from(self.calls).ok()?)
+    }
+
+    fn record(&mut self, elapsed: Duration, is_error: bool) {
+        if self.calls == 0 {
+            self.min = elapsed;
+            self.max = elapsed;
+        } else {
+            self.min = self.min.min(elapsed);
+            self.max = self.max.max(elapsed);
+        }
+        self.calls += 1;
+        self.errors += usize::from(is_error);
+        self.total += elapsed;
+    }
+}
+
+impl<M: Model> Instrumented<M> {
+    /// Wraps `model` to record its calls.
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            state: Mutex::new(State {
+                stats: CallStats::default(),
+                log: None,
+            }),
+        }
+    }
+
+    /// Keeps snapshots of the `capacity` most recent successful calls.
+    ///
+    /// Recording a snapshot clones the call's input and output.
+    #[must_use]
+    pub fn with_log(mut self, capacity: usize) -> Self
+    where
+        M::Input: Clone,
+        M::Output: Clone,
+    {
+        self.state_mut().log = Some(Log {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            snapshot: |input, output| Snapshot::new(input.clone(), output.clone()),
+        });
+        self
+    }
+
+    /// Returns the wrapped model.
+    pub fn inner(&self) -> &M {
+        &self.model
+    }
+
+    /// Consumes the wrapper and returns the wrapped model.
+    pub fn into_inner(self) -> M {
+        self.model
+    }
+
+    /// Returns the counts and timings recorded so far.
+    pub fn stats(&self) -> CallStats {
+        self.lock().stats
+    }
+
+    /// Removes and returns the logged snapshots, oldest first.
+    ///
+    /// Returns an empty vector if logging is not enabled.
+    pub fn take_log(&self) -> Vec<Snapshot<M::Input, M::Output>> {
+        self.lock()
+            .log
+            .as_mut()
+            .map(|log| log.entries.drain(..).collect())
+            .unwrap_or_default()
+    }
+
+    /// Resets the statistics and clears the log.
+    pub fn reset(&self) {
+        let mut state = self.lock();
+        state.stats = CallStats::default();
+        if let Some(log) = state.log.as_mut() {
+            log.entries.clear();
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State<M::Input, M::Output>> {
+        // The state is updated in single steps, so a poisoned lock is still
+        // safe to use.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn state_mut(&mut self) -> &mut State<M::Input, M::Output> {
+        self.state.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<M: Model> Model for Instrumented<M> {
+    type Input = M::Input;
+    type Output = M::Output;
+    type Error = M::Error;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        let start = Instant::now();
+        let result = self.model.call(input);
+        let elapsed = start.elapsed();
+
+        let mut state = self.lock();
+        state.stats.record(elapsed, result.is_err());
+        if let (Some(log), Ok(output)) = (state.log.as_mut(), &result) {
+            log.push(input, output);
+        }
+
+        result
+    }
+}
+
+/// Recorded statistics and the optional snapshot log.
+struct State<I, O> {
+    stats: CallStats,
+    log: Option<Log<I, O>>,
+}
+
+/// Bounded log of the most recent successful calls.
+struct Log<I, O> {
+    entries: VecDeque<Snapshot<I, O>>,
+    capacity: usize,
+    /// Clones a call into a snapshot, captured where `I` and `O` are `Clone`.
+    snapshot: fn(&I, &O) -> Snapshot<I, O>,
+}
+
+impl<I, O> Log<I, O> {
+    fn push(&mut self, input: &I, output: &O) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((self.snapshot)(input, output));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct Negative;
+
+    impl fmt::Display for Negative {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("negative input")
+        }
+    }
+
+    impl std::error::Error for Negative {}
+
+    /// Takes the square root, failing for negative inputs.
+    struct Sqrt;
+
+    impl Model for Sqrt {
+        type Input = f64;
+        type Output = f64;
+        type Error = Negative;
+
+        fn call(&self, input: &f64) -> Result<f64, Negative> {
+            if *input < 0.0 {
+                Err(Negative)
+            } else {
+                Ok(input.sqrt())
+            }
+        }
+    }
+
+    #[test]
+    fn counts_calls_and_errors() {
+        let model = Instrumented::new(Sqrt);
+        assert_eq!(model.stats().mean(), None);
+
+        for x in [4.0, -1.0, 9.0] {
+            let _ = model.call(&x);
+        }
+
+        let stats = model.stats();
+        assert_eq!(stats.calls, 3);
+        assert_eq!(stats.errors, 1);
+        assert!(stats.min <= stats.max && stats.max <= stats.total);
+        assert!(stats.mean().is_some());
+        assert!(model.take_log().is_empty());
+    }
+
+    #[test]
+    fn log_keeps_most_recent_successes() {
+        let model = Instrumented::new(Sqrt).with_log(2);
+
+        for x in [1.0, 4.0, -1.0, 9.0] {
+            let _ = model.call(&x);
+        }
+
+        let inputs: Vec<_> = model.take_log().iter().map(|s| s.input).collect();
+        assert_eq!(inputs, [4.0, 9.0]);
+        assert!(model.take_log().is_empty());
+    }
+
+    #[test]
+    fn reset_clears_stats() {
+        let model = Instrumented::new(Sqrt).with_log(2);
+        model.call(&1.0).expect("should succeed");
+
+        model.reset();
+
+        assert_eq!(model.stats(), CallStats::default());
+        assert!(model.take_log().is_empty());
+    }
+}
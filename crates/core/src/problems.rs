@@ -1,7 +1,13 @@
+mod bounded;
+mod constrained;
 mod equation;
+mod least_squares;
 mod ode;
 mod optimization;
 
+pub use bounded::{Bound, BoundedProblem};
+pub use constrained::{ConstrainedOptimizationProblem, Constraint};
 pub use equation::EquationProblem;
-pub use ode::OdeProblem;
+pub use least_squares::LeastSquaresProblem;
+pub use ode::{IndependentVariable, OdeProblem, ZeroCrossing};
 pub use optimization::OptimizationProblem;
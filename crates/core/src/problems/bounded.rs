@@ -0,0 +1,103 @@
+use super::OptimizationProblem;
+
+/// Defines an optimization problem whose variables have hard limits.
+///
+/// Extends [`OptimizationProblem`] with the lower and upper limits of each
+/// solver variable, such as a valve position between 0 and 1 or a flow rate
+/// that must stay positive. Solvers and entry points that honor the limits
+/// require this trait; other optimizers evaluate the problem without them.
+pub trait BoundedProblem<const N: usize>: OptimizationProblem<N> {
+    /// Returns the lower and upper limits of each solver variable.
+    ///
+    /// Bound-aware solvers never evaluate the problem outside these limits,
+    /// including for finite difference perturbations. Use [`Bound::UNBOUNDED`]
+    /// for variables without limits.
+    fn bounds(&self) -> [Bound; N];
+}
+
+/// Lower and upper limits for one solver variable.
+///
+/// Either limit may be infinite. A variable with equal limits is fixed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bound {
+    /// The smallest allowed value.
+    pub lower: f64,
+
+    /// The largest allowed value.
+    pub upper: f64,
+}
+
+impl Bound {
+    /// A bound that allows every value.
+    pub const UNBOUNDED: Self = Self {
+        lower: f64::NEG_INFINITY,
+        upper: f64::INFINITY,
+    };
+
+    /// Creates a bound with both limits.
+    #[must_use]
+    pub fn new(lower: f64, upper: f64) -> Self {
+        Self { lower, upper }
+    }
+
+    /// Creates a bound with only a lower limit.
+    #[must_use]
+    pub fn at_least(lower: f64) -> Self {
+        Self {
+            lower,
+            upper: f64::INFINITY,
+        }
+    }
+
+    /// Creates a bound with only an upper limit.
+    #[must_use]
+    pub fn at_most(upper: f64) -> Self {
+        Self {
+            lower: f64::NEG_INFINITY,
+            upper,
+        }
+    }
+
+    /// Returns true if neither limit is `NaN` and `lower <= upper`.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.lower <= self.upper
+    }
+
+    /// Returns true if `x` lies within the limits.
+    #[must_use]
+    pub fn contains(&self, x: f64) -> bool {
+        self.lower <= x && x <= self.upper
+    }
+
+    /// Returns `x` moved onto the nearest limit if it lies outside them.
+    #[must_use]
+    pub fn clamp(&self, x: f64) -> f64 {
+        x.max(self.lower).min(self.upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn clamp_and_contains() {
+        let bound = Bound::new(0.0, 1.0);
+
+        assert!(bound.contains(0.5));
+        assert!(!bound.contains(1.5));
+        assert_eq!(bound.clamp(1.5), 1.0);
+        assert_eq!(bound.clamp(-0.5), 0.0);
+        assert_eq!(Bound::at_least(2.0).clamp(1e300), 1e300);
+    }
+
+    #[test]
+    fn validity() {
+        assert!(Bound::UNBOUNDED.is_valid());
+        assert!(Bound::new(1.0, 1.0).is_valid());
+        assert!(!Bound::new(2.0, 1.0).is_valid());
+        assert!(!Bound::at_most(f64::NAN).is_valid());
+    }
+}
@@ -0,0 +1,102 @@
+use super::OptimizationProblem;
+
+/// Defines an optimization problem with constraints on the model.
+///
+/// Extends [`OptimizationProblem`] with `M` constraints computed from the
+/// same model input and output as the objective, such as "pinch temperature
+/// at least 5 K" or "outlet pressure equal to 1 bar".
+///
+/// Constraint-aware solvers try to satisfy every constraint while optimizing
+/// the objective. Variable [bounds](crate::BoundedProblem) are separate:
+/// they limit where the model may be evaluated, while constraints only need to
+/// hold at the solution.
+///
+/// The const generic `M` is the number of constraints.
+pub trait ConstrainedOptimizationProblem<const N: usize, const M: usize>:
+    OptimizationProblem<N>
+{
+    /// Computes the constraint values from model input/output.
+    ///
+    /// Each constraint should keep the same kind (equality or inequality)
+    /// at every point.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OptimizationProblem::Error`] if the constraints cannot be computed.
+    fn constraints(
+        &self,
+        input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<[Constraint; M], Self::Error>;
+}
+
+/// The value of one constraint at a point.
+///
+/// Values are normalized so that zero is the boundary: an equality constraint
+/// holds when its value is zero, and an inequality constraint holds when its
+/// value is zero or negative. The constructors build these forms from a
+/// quantity and its limit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Constraint {
+    /// Satisfied when the value is zero.
+    Equality(f64),
+
+    /// Satisfied when the value is zero or negative.
+    Inequality(f64),
+}
+
+impl Constraint {
+    /// Requires `value` to equal `target`.
+    #[must_use]
+    pub fn equal(value: f64, target: f64) -> Self {
+        Self::Equality(value - target)
+    }
+
+    /// Requires `value` to be at least `limit`.
+    #[must_use]
+    pub fn at_least(value: f64, limit: f64) -> Self {
+        Self::Inequality(limit - value)
+    }
+
+    /// Requires `value` to be at most `limit`.
+    #[must_use]
+    pub fn at_most(value: f64, limit: f64) -> Self {
+        Self::Inequality(value - limit)
+    }
+
+    /// Returns the normalized constraint value.
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        match *self {
+            Self::Equality(value) | Self::Inequality(value) => value,
+        }
+    }
+
+    /// Returns how far the constraint is from being satisfied.
+    ///
+    /// The violation is zero for a satisfied constraint and `NaN` if the value
+    /// is `NaN`.
+    #[must_use]
+    pub fn violation(&self) -> f64 {
+        match *self {
+            Self::Equality(value) => value.abs(),
+            Self::Inequality(value) if value <= 0.0 => 0.0,
+            Self::Inequality(value) => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn violation_is_distance_from_feasible() {
+        assert_eq!(Constraint::at_least(6.0, 5.0).violation(), 0.0);
+        assert_eq!(Constraint::at_least(3.0, 5.0).violation(), 2.0);
+        assert_eq!(Constraint::at_most(7.0, 5.0).violation(), 2.0);
+        assert_eq!(Constraint::equal(4.0, 5.0).violation(), 1.0);
+        assert!(Constraint::Inequality(f64::NAN).violation().is_nan());
+    }
+}
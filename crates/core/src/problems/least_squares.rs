@@ -0,0 +1,40 @@
+/// Defines a least-squares problem to be solved.
+///
+/// A least-squares problem maps solver variables to a model input,
+/// then computes residuals from the model input and output.
+/// Solvers minimize the sum of squared residuals.
+///
+/// Unlike an [`EquationProblem`](super::EquationProblem), the number of
+/// residuals need not match the number of variables. Calibrating `N` model
+/// parameters against `M` measurements is the typical case, with one residual
+/// per measurement and `M > N`.
+///
+/// The const generic `N` is the number of solver variables and `M` is the
+/// number of residuals.
+pub trait LeastSquaresProblem<const N: usize, const M: usize> {
+    type Input;
+    type Output;
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Maps solver variables (`x`) into a model input.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::Error`] if the input cannot be constructed from `x`.
+    fn input(&self, x: &[f64; N]) -> Result<Self::Input, Self::Error>;
+
+    /// Computes residuals from model input/output.
+    ///
+    /// Solvers minimize the sum of their squares, so residuals should be
+    /// scaled to comparable magnitudes (for example, divided by each
+    /// measurement's uncertainty).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::Error`] if residuals cannot be computed.
+    fn residuals(
+        &self,
+        input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<[f64; M], Self::Error>;
+}
@@ -68,3 +68,42 @@ pub trait OdeProblem {
         Ok(next_input)
     }
 }
+
+/// An [`OdeProblem`] that can report where an input lies along the independent
+/// variable.
+///
+/// Solvers that integrate up to a target value, rather than for a fixed number
+/// of steps, use this to measure how far is left to go and to record the value
+/// actually reached. The independent variable shares the `Delta` type, so for
+/// time integration it is the current time expressed in the same units as the
+/// step size.
+pub trait IndependentVariable: OdeProblem {
+    /// Returns the independent variable (typically time) of a model input.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdeProblem::Error`] if the value cannot be extracted from the input.
+    fn independent_variable(&self, input: &Self::Input) -> Result<Self::Delta, Self::Error>;
+}
+
+/// An [`OdeProblem`] with scalar event functions that mark discrete events.
+///
+/// Each of the `K` event functions is evaluated from model input and output.
+/// A change of sign between two snapshots means an event (a tank empties,
+/// a valve hits a limit) happened in between, and event-aware solvers locate
+/// the crossing precisely instead of letting it land on the next step.
+///
+/// Zero is treated as positive, so a function that rests exactly at zero does
+/// not produce repeated crossings.
+pub trait ZeroCrossing<const K: usize>: OdeProblem {
+    /// Evaluates the event functions for a model input and output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdeProblem::Error`] if the event functions cannot be evaluated.
+    fn event_functions(
+        &self,
+        input: &Self::Input,
+        output: &Self::Output,
+    ) -> Result<[f64; K], Self::Error>;
+}
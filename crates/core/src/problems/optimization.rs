@@ -32,4 +32,25 @@ pub trait OptimizationProblem<const N: usize> {
     ///
     /// Returns [`Self::Error`] if the objective cannot be computed.
     fn objective(&self, input: &Self::Input, output: &Self::Output) -> Result<f64, Self::Error>;
+
+    /// Computes the gradient of the objective with respect to `x`.
+    ///
+    /// Gradient-based solvers call this at each point they evaluate, passing
+    /// the model input and output at `x`. Returning `Ok(None)` tells the
+    /// solver to approximate the gradient by finite differences instead.
+    ///
+    /// The default implementation returns `Ok(None)`.
+    /// Only implement this method if an analytic gradient is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::Error`] if the gradient cannot be computed.
+    fn gradient(
+        &self,
+        _x: &[f64; N],
+        _input: &Self::Input,
+        _output: &Self::Output,
+    ) -> Result<Option<[f64; N]>, Self::Error> {
+        Ok(None)
+    }
 }
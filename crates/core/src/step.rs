@@ -22,6 +22,86 @@ pub trait StepIntegrable<Delta> {
 /// associated type without writing out the fully qualified syntax.
 pub type DerivativeOf<T, Delta> = <T as StepIntegrable<Delta>>::Derivative;
 
+/// A trait for states that can measure the error between two estimates.
+///
+/// Adaptive ODE solvers compute two estimates of the next state and use their
+/// difference to decide whether to accept a step and how to size the next one.
+/// Implementing this trait alongside [`StepIntegrable`] tells the solver how to
+/// reduce that difference to a single scaled number.
+///
+/// The returned norm should be scaled by the tolerances so that a value of
+/// `1.0` or less means the step is within tolerance. A common choice is the
+/// root-mean-square over components of
+///
+/// ```text
+/// |self_i - other_i| / (abs_tol + rel_tol * max(|self_i|, |other_i|))
+/// ```
+///
+/// Components that do not matter for accuracy (discrete modes, bookkeeping)
+/// can simply be left out of the norm.
+pub trait ErrorNorm {
+    /// Returns the tolerance-scaled error between `self` and `other`.
+    ///
+    /// Return `f64::NAN` or `f64::INFINITY` if the estimates are unusable,
+    /// which causes the solver to reject the step.
+    fn error_norm(&self, other: &Self, abs_tol: f64, rel_tol: f64) -> f64;
+}
+
+/// A trait for states that can be blended between two values.
+///
+/// ODE solvers only produce states at step boundaries. Implementing this trait
+/// alongside [`StepIntegrable`] lets the solution history be interpolated at
+/// any value of the independent variable between them.
+///
+/// Implementations should blend each continuous component linearly, so that
+/// a `fraction` of `0.0` gives `self` and `1.0` gives `other`. Components that
+/// cannot be blended (discrete modes, bookkeeping) can be taken from whichever
+/// state is nearer.
+pub trait Interpolate {
+    /// Returns the state a `fraction` of the way from `self` to `other`.
+    #[must_use]
+    fn interpolate(&self, other: &Self, fraction: f64) -> Self;
+}
+
+/// A trait for derivatives that can measure their own size.
+///
+/// Steady-state solvers integrate until the state stops changing, which they
+/// detect by its derivative becoming small. Implementing this trait on a
+/// state's [`StepIntegrable::Derivative`] tells the solver how to reduce the
+/// derivative to a single number to compare against a tolerance.
+///
+/// Any norm works, such as the largest absolute component or the
+/// root-mean-square. Components with very different magnitudes should be
+/// scaled so that one tolerance is meaningful for all of them.
+pub trait Norm {
+    /// Returns the size of the derivative.
+    ///
+    /// Return `f64::NAN` if the size cannot be measured, which solvers treat
+    /// as not settled.
+    fn norm(&self) -> f64;
+}
+
+/// A trait for states that can be viewed as `N` plain numbers.
+///
+/// Implicit ODE solvers solve for the next state with a root finder, which
+/// works on `[f64; N]`. Implementing this trait alongside [`StepIntegrable`]
+/// lets the solver move between the state and its numeric components.
+///
+/// [`unflatten`](Self::unflatten) takes `&self` so that components outside
+/// the flat view (discrete modes, units, bookkeeping) carry over unchanged
+/// from an existing state.
+pub trait Flatten<const N: usize> {
+    /// Returns the numeric components of the state.
+    fn flatten(&self) -> [f64; N];
+
+    /// Returns a copy of the state with its numeric components replaced.
+    ///
+    /// Must be the inverse of [`flatten`](Self::flatten), so that
+    /// `state.unflatten(&state.flatten())` reproduces `state`.
+    #[must_use]
+    fn unflatten(&self, values: &[f64; N]) -> Self;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -31,6 +111,12 @@ mod tests {
     struct Position(f64);
     struct Velocity(f64);
 
+    impl Norm for Velocity {
+        fn norm(&self) -> f64 {
+            self.0.abs()
+        }
+    }
+
     impl StepIntegrable<f64> for Position {
         type Derivative = Velocity;
 
@@ -39,6 +125,29 @@ mod tests {
         }
     }
 
+    impl ErrorNorm for Position {
+        fn error_norm(&self, other: &Self, abs_tol: f64, rel_tol: f64) -> f64 {
+            let scale = abs_tol + rel_tol * self.0.abs().max(other.0.abs());
+            (self.0 - other.0).abs() / scale
+        }
+    }
+
+    impl Interpolate for Position {
+        fn interpolate(&self, other: &Self, fraction: f64) -> Self {
+            Position(self.0 + (other.0 - self.0) * fraction)
+        }
+    }
+
+    impl Flatten<1> for Position {
+        fn flatten(&self) -> [f64; 1] {
+            [self.0]
+        }
+
+        fn unflatten(&self, values: &[f64; 1]) -> Self {
+            Position(values[0])
+        }
+    }
+
     // Vector state and derivative
     #[derive(Debug, PartialEq)]
     struct StateVector(Vec<f64>);
@@ -79,4 +188,39 @@ mod tests {
 
         assert_eq!(next, StateVector(vec![2.0, 4.0, 6.0]));
     }
+
+    #[test]
+    fn error_norm_is_scaled_by_tolerances() {
+        let a = Position(100.0);
+        let b = Position(100.5);
+
+        // Within tolerance relative to the magnitude, outside in absolute terms.
+        assert!(a.error_norm(&b, 0.0, 1e-2) <= 1.0);
+        assert!(a.error_norm(&b, 1e-2, 0.0) > 1.0);
+    }
+
+    #[test]
+    fn interpolate_blends_between_states() {
+        let a = Position(1.0);
+        let b = Position(3.0);
+
+        assert_eq!(a.interpolate(&b, 0.0), a);
+        assert_eq!(a.interpolate(&b, 0.25), Position(1.5));
+        assert_eq!(a.interpolate(&b, 1.0), b);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn norm_measures_derivative() {
+        assert_eq!(Velocity(-0.5).norm(), 0.5);
+        assert_eq!(Velocity(0.0).norm(), 0.0);
+    }
+
+    #[test]
+    fn flatten_round_trips() {
+        let pos = Position(3.5);
+
+        assert_eq!(pos.unflatten(&pos.flatten()), pos);
+        assert_eq!(pos.unflatten(&[-1.0]), Position(-1.0));
+    }
 }
@@ -36,9 +36,13 @@
 //! }
 //! ```
 
-use twine_core::{EquationProblem, Model, OptimizationProblem};
+use twine_core::{EquationProblem, LeastSquaresProblem, Model, OptimizationProblem};
 
-use twine_solvers::{equation::bisection, optimization::golden_section, transient::euler};
+use twine_solvers::{
+    equation::{bisection, bracket, brent, continuation, newton, trust_region},
+    optimization::{augmented_lagrangian, golden_section, lbfgs, levenberg_marquardt, nelder_mead},
+    transient::{crossing, euler},
+};
 
 /// An event that carries a residual value.
 pub trait HasResidual {
@@ -85,6 +89,109 @@ where
     }
 }
 
+// --- HasResidual for bracket::Event ---
+
+impl<M, P> HasResidual for bracket::Event<'_, M, P>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    fn residual(&self) -> f64 {
+        match self.result() {
+            Ok(eval) => eval.residuals[0],
+            Err(_) => f64::NAN,
+        }
+    }
+}
+
+// --- HasResidual for brent::Event ---
+
+impl<M, P> HasResidual for brent::Event<'_, M, P>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    fn residual(&self) -> f64 {
+        match self.result() {
+            Ok(eval) => eval.residuals[0],
+            Err(_) => f64::NAN,
+        }
+    }
+}
+
+// --- HasResidual for newton::Event ---
+
+/// For systems of equations, the residual is the largest residual magnitude.
+impl<M, P, const N: usize> HasResidual for newton::Event<'_, M, P, N>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    fn residual(&self) -> f64 {
+        match self.result() {
+            Ok(eval) => max_abs(&eval.residuals),
+            Err(_) => f64::NAN,
+        }
+    }
+}
+
+// --- HasResidual for trust_region::Event ---
+
+/// For systems of equations, the residual is the largest residual magnitude.
+impl<M, P, const N: usize> HasResidual for trust_region::Event<'_, M, P, N>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    fn residual(&self) -> f64 {
+        match self.result() {
+            Ok(eval) => max_abs(&eval.residuals),
+            Err(_) => f64::NAN,
+        }
+    }
+}
+
+/// Returns the largest residual magnitude, or NaN if any residual is NaN.
+///
+/// This matches the solvers' own infinity norm, which they use for their
+/// convergence checks, so an observer never reports a finite residual for an
+/// iterate the solver treats as NaN. Keep the two in sync.
+fn max_abs(residuals: &[f64]) -> f64 {
+    residuals
+        .iter()
+        .map(|r| r.abs())
+        .fold(0.0, |acc, r| if r > acc || r.is_nan() { r } else { acc })
+}
+
+// --- HasObjective for levenberg_marquardt::Event ---
+
+/// For least-squares problems, the objective is the sum of squared residuals.
+impl<M, P, const N: usize, const R: usize> HasObjective
+    for levenberg_marquardt::Event<'_, M, P, N, R>
+where
+    M: Model,
+    P: LeastSquaresProblem<N, R, Input = M::Input, Output = M::Output>,
+{
+    fn objective(&self) -> f64 {
+        match self.result() {
+            Ok(eval) => eval.cost(),
+            Err(_) => f64::NAN,
+        }
+    }
+}
+
+// --- HasObjective for augmented_lagrangian::Event ---
+
+impl<M, const N: usize, const C: usize> HasObjective for augmented_lagrangian::Event<'_, M, N, C>
+where
+    M: Model,
+{
+    fn objective(&self) -> f64 {
+        let augmented_lagrangian::Event::Iterate { objective, .. } = self;
+        *objective
+    }
+}
+
 // --- HasObjective for golden_section::Event ---
 
 impl<M, P> HasObjective for golden_section::Event<'_, M, P>
@@ -101,6 +208,41 @@ where
     }
 }
 
+// --- HasObjective for lbfgs::Event ---
+
+impl<M, P, const N: usize> HasObjective for lbfgs::Event<'_, M, P, N>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    fn objective(&self) -> f64 {
+        match self {
+            lbfgs::Event::Trial { result, .. } | lbfgs::Event::Gradient { result, .. } => {
+                match result {
+                    Ok(eval) => eval.objective,
+                    Err(_) => f64::NAN,
+                }
+            }
+            lbfgs::Event::Iterate { objective, .. } => *objective,
+        }
+    }
+}
+
+// --- HasObjective for nelder_mead::Event ---
+
+impl<M, P, const N: usize> HasObjective for nelder_mead::Event<'_, M, P, N>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    fn objective(&self) -> f64 {
+        match self.result() {
+            Ok(eval) => eval.objective,
+            Err(_) => f64::NAN,
+        }
+    }
+}
+
 // --- CanStopEarly impls ---
 
 impl CanStopEarly for bisection::Action {
@@ -109,19 +251,67 @@ impl CanStopEarly for bisection::Action {
     }
 }
 
+impl CanStopEarly for newton::Action {
+    fn stop_early() -> Self {
+        Self::StopEarly
+    }
+}
+
+impl CanStopEarly for continuation::Action {
+    fn stop_early() -> Self {
+        Self::StopEarly
+    }
+}
+
+impl CanStopEarly for trust_region::Action {
+    fn stop_early() -> Self {
+        Self::StopEarly
+    }
+}
+
+impl CanStopEarly for augmented_lagrangian::Action {
+    fn stop_early() -> Self {
+        Self::StopEarly
+    }
+}
+
 impl CanStopEarly for golden_section::Action {
     fn stop_early() -> Self {
         Self::StopEarly
     }
 }
 
+impl CanStopEarly for lbfgs::Action {
+    fn stop_early() -> Self {
+        Self::StopEarly
+    }
+}
+
+impl CanStopEarly for levenberg_marquardt::Action {
+    fn stop_early() -> Self {
+        Self::StopEarly
+    }
+}
+
+impl CanStopEarly for nelder_mead::Action {
+    fn stop_early() -> Self {
+        Self::StopEarly
+    }
+}
+
 impl CanStopEarly for euler::Action {
     fn stop_early() -> Self {
         Self::StopEarly
     }
 }
 
-// --- CanAssumeWorse for golden_section::Action ---
+impl CanStopEarly for crossing::Action {
+    fn stop_early() -> Self {
+        Self::StopEarly
+    }
+}
+
+// --- CanAssumeWorse impls ---
 
 impl CanAssumeWorse for golden_section::Action {
     fn assume_worse() -> Self {
@@ -129,6 +319,12 @@ impl CanAssumeWorse for golden_section::Action {
     }
 }
 
+impl CanAssumeWorse for nelder_mead::Action {
+    fn assume_worse() -> Self {
+        Self::AssumeWorse
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,7 +334,7 @@ mod tests {
     use approx::assert_relative_eq;
     use twine_core::{EquationProblem, Model, OptimizationProblem};
     use twine_solvers::{
-        equation::bisection,
+        equation::{bisection, brent, newton},
         optimization::golden_section::{self, Point},
     };
 
@@ -269,6 +465,72 @@ mod tests {
         assert!(got_nan);
     }
 
+    // --- HasResidual for brent::Event ---
+
+    #[test]
+    fn brent_residual_nan_on_model_error() {
+        let model = FailingModel;
+        let problem = LinearProblem;
+        let mut got_nan = false;
+        let _ = brent::solve(
+            &model,
+            &problem,
+            [-1.0, 1.0],
+            &brent::Config::default(),
+            |event: &brent::Event<'_, FailingModel, LinearProblem>| {
+                got_nan = event.residual().is_nan();
+                Some(brent::Action::stop_early())
+            },
+        );
+        assert!(got_nan);
+    }
+
+    // --- HasResidual for newton::Event ---
+
+    #[test]
+    fn newton_residual_is_largest_magnitude() {
+        let model = Identity;
+        let problem = LinearProblem;
+        let mut residual_seen = None;
+        let _ = newton::solve(
+            &model,
+            &problem,
+            [-0.5],
+            &newton::Config::default(),
+            |event: &newton::Event<'_, Identity, LinearProblem, 1>| {
+                residual_seen = Some(event.residual());
+                Some(newton::Action::StopEarly)
+            },
+        );
+        assert_relative_eq!(residual_seen.expect("initial event emitted"), 0.5);
+    }
+
+    #[test]
+    fn newton_residual_is_nan_when_any_residual_is_nan() {
+        let model = Identity;
+        let problem = LinearProblem;
+        let mut residual_seen = None;
+        let _ = newton::solve(
+            &model,
+            &problem,
+            [f64::NAN],
+            &newton::Config::default(),
+            |event: &newton::Event<'_, Identity, LinearProblem, 1>| {
+                residual_seen = Some(event.residual());
+                Some(newton::Action::StopEarly)
+            },
+        );
+        assert!(residual_seen.expect("initial event emitted").is_nan());
+    }
+
+    #[test]
+    fn max_abs_propagates_nan_in_any_position() {
+        assert_relative_eq!(max_abs(&[1.0, -3.0, 2.0]), 3.0);
+        assert_relative_eq!(max_abs(&[]), 0.0);
+        assert!(max_abs(&[1.0, f64::NAN, 2.0]).is_nan());
+        assert!(max_abs(&[f64::NAN, 5.0]).is_nan());
+    }
+
     // --- HasObjective for golden_section::Event ---
 
     #[test]
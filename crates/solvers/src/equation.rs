@@ -7,6 +7,21 @@
 //! # Solvers
 //!
 //! - [`bisection`] — guaranteed convergence on a bracketed interval
+//! - [`brent`] — bracketed like bisection, but with interpolation steps for
+//!   much faster convergence on smooth residuals
+//! - [`newton`] — fast local convergence for square systems using a
+//!   finite-difference Jacobian
+//! - [`broyden`] — like [`newton`], but updates the Jacobian from each step
+//!   instead of rebuilding it, for models that are expensive to call
+//! - [`trust_region`] — Newton steps safeguarded by a line search or a dogleg
+//!   trust region, for poor initial guesses
+//!
+//! For problems that only converge when a parameter is ramped,
+//! [`continuation`] traces the solution path using any of the square-system
+//! solvers as its corrector.
+//!
+//! When no bracket is known up front, [`bracket::expand`] searches outward
+//! from a guess for one that [`bisection`] or [`brent`] can use.
 //!
 //! [`EquationProblem`]: twine_core::EquationProblem
 
@@ -14,4 +29,13 @@ mod evaluate;
 
 pub use evaluate::{EvalError, EvaluateResult, Evaluation, evaluate};
 
+#[cfg(feature = "parallel")]
+pub use evaluate::evaluate_batch;
+
 pub mod bisection;
+pub mod bracket;
+pub mod brent;
+pub mod broyden;
+pub mod continuation;
+pub mod newton;
+pub mod trust_region;
@@ -17,9 +17,10 @@ pub use solution::{Solution, Status};
 
 use twine_core::{EquationProblem, Model, Observer};
 
-use best::Best;
-use bracket::Bounds;
-use decision::Decision;
+pub(crate) use best::Best;
+pub(crate) use bracket::Bounds;
+pub(crate) use decision::Decision;
+
 use eval_context::EvalContext;
 
 /// Finds a root of the equation using the bisection method.
@@ -47,7 +48,8 @@ use eval_context::EvalContext;
 ///
 /// The returned [`Solution`] always reflects the best successful evaluation
 /// seen so far (by residual magnitude).
-/// Iteration counts correspond to the number of midpoint evaluations performed.
+/// Iteration counts correspond to the number of midpoint evaluations performed,
+/// while [`Solution::stats`] counts every evaluation, including the endpoints.
 ///
 /// # Errors
 ///
@@ -83,7 +85,7 @@ where
     }
     let left_sign = match left_decision {
         Decision::Continue(sign) => sign,
-        Decision::StopEarly => return best.finish(Status::StoppedByObserver, 0),
+        Decision::StopEarly => return best.finish(Status::StoppedByObserver, 0, ctx.stats()),
         Decision::Error(error) => return Err(error),
     };
 
@@ -94,7 +96,7 @@ where
     }
     let right_sign = match right_decision {
         Decision::Continue(sign) => sign,
-        Decision::StopEarly => return best.finish(Status::StoppedByObserver, 0),
+        Decision::StopEarly => return best.finish(Status::StoppedByObserver, 0, ctx.stats()),
         Decision::Error(error) => return Err(error),
     };
 
@@ -102,13 +104,13 @@ where
     let mut bracket = Bracket::new(bounds, left_sign, right_sign)?;
 
     if best.is_residual_converged(config.residual_tol) {
-        return best.finish(Status::Converged, 0);
+        return best.finish(Status::Converged, 0, ctx.stats());
     }
 
     // Iterate by shrinking the bracket with midpoint evaluations.
     for iter in 1..=config.max_iters {
         if bracket.is_x_converged(config.x_abs_tol, config.x_rel_tol) {
-            return best.finish(Status::Converged, iter - 1);
+            return best.finish(Status::Converged, iter - 1, ctx.stats());
         }
 
         // Evaluate the midpoint and update the bracket.
@@ -120,17 +122,17 @@ where
         match mid_decision {
             Decision::Continue(sign) => bracket.shrink(mid, sign),
             Decision::StopEarly => {
-                return best.finish(Status::StoppedByObserver, iter);
+                return best.finish(Status::StoppedByObserver, iter, ctx.stats());
             }
             Decision::Error(error) => return Err(error),
         }
 
         if best.is_residual_converged(config.residual_tol) {
-            return best.finish(Status::Converged, iter);
+            return best.finish(Status::Converged, iter, ctx.stats());
         }
     }
 
-    best.finish(Status::MaxIters, config.max_iters)
+    best.finish(Status::MaxIters, config.max_iters, ctx.stats())
 }
 
 /// Runs bisection without observation.
@@ -281,6 +283,9 @@ mod tests {
         assert_eq!(solution.status, Status::StoppedByObserver);
         assert_eq!(solution.iters, 3);
         assert_eq!(midpoint_count, 3);
+        // Both endpoints and three midpoints, with one observer action.
+        assert_eq!(solution.stats.evaluations, 5);
+        assert_eq!(solution.stats.overridden, 1);
     }
 
     #[test]
@@ -326,6 +331,10 @@ mod tests {
 
         assert_eq!(solution.status, Status::Converged);
         assert_relative_eq!(solution.x, 3.0, epsilon = 1e-10);
+        // Only the right endpoint fails, and the observer overrides it.
+        assert_eq!(solution.stats.failures, 1);
+        assert_eq!(solution.stats.overridden, 1);
+        assert_eq!(solution.stats.evaluations, solution.iters + 2);
     }
 
     #[test]
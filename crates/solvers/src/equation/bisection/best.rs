@@ -1,23 +1,25 @@
 use crate::equation::Evaluation;
 
+use crate::stats::Stats;
+
 use super::{Error, Solution, Status};
 
 /// Tracks the best evaluation encountered so far.
 ///
 /// The best evaluation is defined by minimum residual magnitude.
 /// The `Option` lets us represent the state before any successful evaluation.
-pub(super) struct Best<I, O> {
+pub(crate) struct Best<I, O> {
     eval: Option<Evaluation<I, O, 1>>,
 }
 
 impl<I, O> Best<I, O> {
     /// Creates an empty best tracker.
-    pub(super) fn empty() -> Self {
+    pub(crate) fn empty() -> Self {
         Self { eval: None }
     }
 
     /// Updates the best evaluation if the residual magnitude improves.
-    pub(super) fn update(&mut self, eval: Evaluation<I, O, 1>) {
+    pub(crate) fn update(&mut self, eval: Evaluation<I, O, 1>) {
         if let Some(best) = self.eval.as_ref()
             && eval.residuals[0].abs() >= best.residuals[0].abs()
         {
@@ -27,7 +29,7 @@ impl<I, O> Best<I, O> {
     }
 
     /// Returns true if the best residual meets the tolerance.
-    pub(super) fn is_residual_converged(&self, residual_tol: f64) -> bool {
+    pub(crate) fn is_residual_converged(&self, residual_tol: f64) -> bool {
         self.eval
             .as_ref()
             .is_some_and(|eval| eval.residuals[0].abs() <= residual_tol)
@@ -38,7 +40,13 @@ impl<I, O> Best<I, O> {
     /// # Errors
     ///
     /// Returns `Error::NoSuccessfulEvaluation` if no successful evaluation is stored.
-    pub(super) fn finish(self, status: Status, iters: usize) -> Result<Solution<I, O>, Error> {
+    #[allow(clippy::similar_names)]
+    pub(crate) fn finish(
+        self,
+        status: Status,
+        iters: usize,
+        stats: Stats,
+    ) -> Result<Solution<I, O>, Error> {
         let eval = self.eval.ok_or(Error::NoSuccessfulEvaluation)?;
         Ok(Solution {
             status,
@@ -46,6 +54,7 @@ impl<I, O> Best<I, O> {
             residual: eval.residuals[0],
             snapshot: eval.snapshot,
             iters,
+            stats,
         })
     }
 }
@@ -74,7 +83,7 @@ mod tests {
         best.update(eval(3.0, 1.0));
 
         let solution = best
-            .finish(Status::StoppedByObserver, 0)
+            .finish(Status::StoppedByObserver, 0, Stats::default())
             .expect("best eval");
 
         assert_relative_eq!(solution.x, 3.0);
@@ -88,7 +97,7 @@ mod tests {
         best.update(eval(2.0, 2.0));
 
         let solution = best
-            .finish(Status::StoppedByObserver, 0)
+            .finish(Status::StoppedByObserver, 0, Stats::default())
             .expect("best eval");
 
         assert_relative_eq!(solution.x, 1.0);
@@ -113,7 +122,7 @@ mod tests {
     #[test]
     fn finish_errors_without_eval() {
         let best: Best<(), ()> = Best::empty();
-        let err = best.finish(Status::StoppedByObserver, 0);
+        let err = best.finish(Status::StoppedByObserver, 0, Stats::default());
         assert!(matches!(err, Err(Error::NoSuccessfulEvaluation)));
     }
 
@@ -122,7 +131,9 @@ mod tests {
         let mut best = Best::empty();
         best.update(eval(2.0, -1.25));
 
-        let solution = best.finish(Status::Converged, 4).expect("best eval");
+        let solution = best
+            .finish(Status::Converged, 4, Stats::default())
+            .expect("best eval");
 
         assert_eq!(solution.status, Status::Converged);
         assert_eq!(solution.iters, 4);
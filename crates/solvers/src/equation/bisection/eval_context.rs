@@ -1,6 +1,9 @@
 use twine_core::{EquationProblem, Model, Observer};
 
-use crate::equation::{Evaluation, evaluate};
+use crate::{
+    equation::{Evaluation, evaluate},
+    stats::{Stats, Tally},
+};
 
 use super::{Action, Bracket, Decision, Event};
 
@@ -19,6 +22,7 @@ pub(crate) struct EvalContext<'ctx, M, P, Obs> {
     model: &'ctx M,
     problem: &'ctx P,
     observer: &'ctx mut Obs,
+    tally: Tally,
 }
 
 impl<'ctx, M, P, Obs> EvalContext<'ctx, M, P, Obs>
@@ -35,13 +39,21 @@ where
             model,
             problem,
             observer,
+            tally: Tally::start(),
         }
     }
 
+    /// Returns the cost of the solve so far.
+    pub(crate) fn stats(&self) -> Stats {
+        self.tally.stats()
+    }
+
     /// Evaluates the left endpoint and returns the observer decision.
     pub(crate) fn left_endpoint(&mut self, x: f64) -> EvalOutcome<M::Input, M::Output> {
         let result = evaluate(self.model, self.problem, [x]);
+        self.tally.evaluated(&result);
         let action = self.observer.observe(&Event::Left { x, result: &result });
+        self.tally.observed(action.as_ref());
 
         let (residual, mut eval) = match result {
             Ok(eval) => (Ok(eval.residuals[0]), Some(eval)),
@@ -60,7 +72,9 @@ where
     /// Evaluates the right endpoint and returns the observer decision.
     pub(crate) fn right_endpoint(&mut self, x: f64) -> EvalOutcome<M::Input, M::Output> {
         let result = evaluate(self.model, self.problem, [x]);
+        self.tally.evaluated(&result);
         let action = self.observer.observe(&Event::Right { x, result: &result });
+        self.tally.observed(action.as_ref());
 
         let (residual, mut eval) = match result {
             Ok(eval) => (Ok(eval.residuals[0]), Some(eval)),
@@ -83,11 +97,13 @@ where
         bracket: &Bracket,
     ) -> EvalOutcome<M::Input, M::Output> {
         let result = evaluate(self.model, self.problem, [x]);
+        self.tally.evaluated(&result);
         let action = self.observer.observe(&Event::Midpoint {
             x,
             bracket,
             result: &result,
         });
+        self.tally.observed(action.as_ref());
 
         let (residual, mut eval) = match result {
             Ok(eval) => (Ok(eval.residuals[0]), Some(eval)),
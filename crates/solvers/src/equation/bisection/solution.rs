@@ -1,5 +1,7 @@
 use twine_core::Snapshot;
 
+use crate::Stats;
+
 /// Indicates whether the solver converged or hit the iteration limit.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -24,4 +26,6 @@ pub struct Solution<I, O> {
     pub snapshot: Snapshot<I, O>,
     /// Iteration count when the solver finished.
     pub iters: usize,
+    /// Model evaluations and time spent, including the endpoints.
+    pub stats: Stats,
 }
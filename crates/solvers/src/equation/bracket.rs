@@ -0,0 +1,404 @@
+//! Automatic bracket discovery for scalar equation problems.
+//!
+//! Bracketing solvers like [`bisection`] and [`brent`] need an interval whose
+//! endpoints have residuals of opposite sign. When only a rough guess is known,
+//! [`expand`] searches outward from it, probing `guess ± step` and multiplying
+//! the step by a growth factor after each expansion, until a sign change is
+//! found. Optional hard limits keep probes inside the model's valid domain.
+//!
+//! The returned [`Bracket`] is the tightest interval found, between the last
+//! probe and its inner neighbor, and can be handed straight to a solver with
+//! [`Bracket::as_array`].
+//!
+//! # Observer Events
+//!
+//! The search emits one [`Event`] per evaluation: [`Event::Guess`] for the
+//! initial guess and [`Event::Probe`] for each probe. It shares bisection's
+//! [`Action`]:
+//!
+//! - [`Action::StopEarly`] abandons the search with [`Error::StoppedByObserver`].
+//! - [`Action::AssumeResidualSign`] supplies a residual sign for a point,
+//!   which is useful for recovering from evaluation failures.
+//!
+//! [`bisection`]: crate::equation::bisection
+//! [`brent`]: crate::equation::brent
+
+mod config;
+mod error;
+mod event;
+
+pub use config::{Config, ConfigError};
+pub use error::Error;
+pub use event::Event;
+
+pub use super::bisection::{Action, Bracket, Sign};
+
+use twine_core::{EquationProblem, Model, Observer};
+
+use super::{EvaluateResult, bisection::Bounds, evaluate};
+
+/// Expands outward from `guess` until the residual changes sign.
+///
+/// # Algorithm
+///
+/// 1. Evaluate the guess to establish a reference sign.
+/// 2. For each expansion, probe `guess + step` and then `guess - step`,
+///    clamped to the configured limits, and multiply `step` by
+///    `config.growth`.
+/// 3. Return the bracket between the first probe with the opposite sign and
+///    the previous point on the same side.
+///
+/// A side stops being probed once it reaches its limit.
+///
+/// # Errors
+///
+/// Returns an error if the config or guess is invalid, no sign change is found
+/// within the expansions or limits, the observer stops the search, or the
+/// model or problem returns an unrecovered error during evaluation.
+pub fn expand<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    guess: f64,
+    config: &Config,
+    mut observer: Obs,
+) -> Result<Bracket, Error>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P>, Action>,
+{
+    config.validate()?;
+    if !guess.is_finite() || !config.contains(guess) {
+        return Err(Error::InvalidGuess);
+    }
+
+    let result = evaluate(model, problem, [guess]);
+    let action = observer.observe(&Event::Guess {
+        x: guess,
+        result: &result,
+    });
+    let sign = resolve::<M, P>(action, result)?;
+
+    // Innermost points on each side, all sharing the guess's sign.
+    let mut left = guess;
+    let mut right = guess;
+    let mut step = config.initial_step;
+
+    for expansion in 1..=config.max_expansions {
+        let mut probed = false;
+
+        let x = config.clamp(guess + step);
+        if x > right && x.is_finite() {
+            probed = true;
+            let probe_sign = probe(model, problem, &mut observer, expansion, x)?;
+            if probe_sign != sign {
+                return Ok(bracket([right, x], [sign, probe_sign]));
+            }
+            right = x;
+        }
+
+        let x = config.clamp(guess - step);
+        if x < left && x.is_finite() {
+            probed = true;
+            let probe_sign = probe(model, problem, &mut observer, expansion, x)?;
+            if probe_sign != sign {
+                return Ok(bracket([x, left], [probe_sign, sign]));
+            }
+            left = x;
+        }
+
+        if !probed {
+            break;
+        }
+        step *= config.growth;
+    }
+
+    Err(Error::NotFound)
+}
+
+/// Runs bracket expansion without observation.
+///
+/// # Errors
+///
+/// Returns an error if the config or guess is invalid, no sign change is found,
+/// or the model or problem returns an error during evaluation.
+pub fn expand_unobserved<M, P>(
+    model: &M,
+    problem: &P,
+    guess: f64,
+    config: &Config,
+) -> Result<Bracket, Error>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    expand(model, problem, guess, config, ())
+}
+
+/// Evaluates a probe point and returns its (possibly assumed) residual sign.
+fn probe<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    observer: &mut Obs,
+    expansion: usize,
+    x: f64,
+) -> Result<Sign, Error>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P>, Action>,
+{
+    let result = evaluate(model, problem, [x]);
+    let action = observer.observe(&Event::Probe {
+        expansion,
+        x,
+        result: &result,
+    });
+    resolve::<M, P>(action, result)
+}
+
+/// Resolves the residual sign from an observer action and evaluation result.
+fn resolve<M, P>(action: Option<Action>, result: EvaluateResult<M, P, 1>) -> Result<Sign, Error>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    match (action, result) {
+        (Some(Action::StopEarly), _) => Err(Error::StoppedByObserver),
+        (Some(Action::AssumeResidualSign(sign)), _) => Ok(sign),
+        (None, Ok(eval)) => Ok(Sign::of(eval.residuals[0])),
+        (None, Err(error)) => Err(error.into()),
+    }
+}
+
+/// Builds a bracket from ordered, distinct, finite points with opposite signs.
+fn bracket(x: [f64; 2], signs: [Sign; 2]) -> Bracket {
+    let bounds = Bounds::new(x).expect("probes are finite and distinct");
+    Bracket::new(bounds, signs[0], signs[1]).expect("probe signs differ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use thiserror::Error;
+
+    use crate::equation::bisection;
+
+    /// Model that squares its input.
+    struct SquareModel;
+    impl Model for SquareModel {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(input * input)
+        }
+    }
+
+    /// Model that fails above a threshold.
+    struct ThresholdModel {
+        threshold: f64,
+    }
+    #[derive(Debug, Clone, Error)]
+    #[error("exceeded threshold at x={x}")]
+    struct ThresholdError {
+        x: f64,
+    }
+    impl Model for ThresholdModel {
+        type Input = f64;
+        type Output = f64;
+        type Error = ThresholdError;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            if *input > self.threshold {
+                Err(ThresholdError { x: *input })
+            } else {
+                Ok(input * input)
+            }
+        }
+    }
+
+    /// Equation problem that drives the model output to a target value.
+    struct TargetOutputProblem {
+        target: f64,
+    }
+    impl EquationProblem<1> for TargetOutputProblem {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+            Ok(x[0])
+        }
+
+        fn residuals(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<[f64; 1], Self::Error> {
+            Ok([output - self.target])
+        }
+    }
+
+    #[test]
+    fn finds_bracket_and_hands_it_to_bisection() {
+        let problem = TargetOutputProblem { target: 9.0 };
+
+        // Probes at 1, -1, 2, -2, then 4 changes sign.
+        let bracket = expand_unobserved(&SquareModel, &problem, 0.0, &Config::default())
+            .expect("should find bracket");
+        let [left, right] = bracket.as_array();
+        assert_relative_eq!(left, 2.0);
+        assert_relative_eq!(right, 4.0);
+
+        let solution = bisection::solve_unobserved(
+            &SquareModel,
+            &problem,
+            bracket.as_array(),
+            &bisection::Config::default(),
+        )
+        .expect("should solve");
+        assert_relative_eq!(solution.x, 3.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn finds_bracket_on_left_side() {
+        let problem = TargetOutputProblem { target: 9.0 };
+
+        // The right probe at -1.5 moves away from the root, and the left probe
+        // at -3.5 crosses it.
+        let bracket = expand_unobserved(&SquareModel, &problem, -2.5, &Config::default())
+            .expect("should find bracket");
+
+        let [left, right] = bracket.as_array();
+        assert_relative_eq!(left, -3.5);
+        assert_relative_eq!(right, -2.5);
+    }
+
+    #[test]
+    fn probes_respect_limits() {
+        let config = Config {
+            lower: Some(0.0),
+            upper: Some(3.5),
+            ..Config::default()
+        };
+
+        let mut probes = Vec::new();
+        let observer = |event: &Event<'_, _, _>| {
+            probes.push(event.x());
+            None
+        };
+
+        let bracket = expand(
+            &SquareModel,
+            &TargetOutputProblem { target: 10.0 },
+            1.0,
+            &config,
+            observer,
+        )
+        .expect("should find bracket");
+
+        assert!(probes.iter().all(|x| (0.0..=3.5).contains(x)));
+        let [left, right] = bracket.as_array();
+        assert_relative_eq!(left, 3.0);
+        assert_relative_eq!(right, 3.5);
+    }
+
+    #[test]
+    fn not_found_when_limits_are_exhausted() {
+        let config = Config {
+            lower: Some(-2.0),
+            upper: Some(2.0),
+            ..Config::default()
+        };
+
+        let result = expand_unobserved(
+            &SquareModel,
+            &TargetOutputProblem { target: 9.0 },
+            0.0,
+            &config,
+        );
+
+        assert!(matches!(result, Err(Error::NotFound)));
+    }
+
+    #[test]
+    fn not_found_after_max_expansions() {
+        let config = Config {
+            max_expansions: 3,
+            ..Config::default()
+        };
+
+        let result = expand_unobserved(
+            &SquareModel,
+            &TargetOutputProblem { target: -1.0 },
+            0.0,
+            &config,
+        );
+
+        assert!(matches!(result, Err(Error::NotFound)));
+    }
+
+    #[test]
+    fn observer_can_assume_sign_for_failures() {
+        // The model fails above x=2.5; the observer treats failures as overshoot.
+        let observer = |event: &Event<'_, _, _>| {
+            event
+                .result()
+                .is_err()
+                .then(bisection::Action::assume_positive)
+        };
+
+        let bracket = expand(
+            &ThresholdModel { threshold: 2.5 },
+            &TargetOutputProblem { target: 9.0 },
+            0.0,
+            &Config::default(),
+            observer,
+        )
+        .expect("should find bracket");
+
+        let [left, right] = bracket.as_array();
+        assert_relative_eq!(left, 2.0);
+        assert_relative_eq!(right, 4.0);
+    }
+
+    #[test]
+    fn observer_can_stop_search() {
+        let observer = |event: &Event<'_, _, _>| {
+            matches!(event, Event::Probe { expansion: 2, .. }).then_some(Action::StopEarly)
+        };
+
+        let result = expand(
+            &SquareModel,
+            &TargetOutputProblem { target: 9.0 },
+            0.0,
+            &Config::default(),
+            observer,
+        );
+
+        assert!(matches!(result, Err(Error::StoppedByObserver)));
+    }
+
+    #[test]
+    fn rejects_guess_outside_limits() {
+        let config = Config {
+            lower: Some(0.0),
+            ..Config::default()
+        };
+
+        let result = expand_unobserved(
+            &SquareModel,
+            &TargetOutputProblem { target: 9.0 },
+            -1.0,
+            &config,
+        );
+
+        assert!(matches!(result, Err(Error::InvalidGuess)));
+    }
+}
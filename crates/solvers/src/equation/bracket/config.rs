@@ -0,0 +1,123 @@
+use thiserror::Error;
+
+/// Configuration for bracket expansion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    /// Distance from the guess to the first pair of probes.
+    pub initial_step: f64,
+    /// Factor applied to the step after each expansion.
+    pub growth: f64,
+    /// Maximum number of expansions (each probes up to two points).
+    pub max_expansions: usize,
+    /// Optional hard lower limit that probes never go below.
+    pub lower: Option<f64>,
+    /// Optional hard upper limit that probes never go above.
+    pub upper: Option<f64>,
+}
+
+/// Errors that can occur when validating a bracket expansion config.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("initial_step must be finite and positive")]
+    InitialStep,
+
+    #[error("growth must be finite and greater than one")]
+    Growth,
+
+    #[error("limits must be finite with lower < upper")]
+    Limits,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            initial_step: 1.0,
+            growth: 2.0,
+            max_expansions: 50,
+            lower: None,
+            upper: None,
+        }
+    }
+}
+
+impl Config {
+    /// Validates the step, growth factor, and limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the step or growth factor is unusable,
+    /// or if the limits are non-finite or out of order.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !self.initial_step.is_finite() || self.initial_step <= 0.0 {
+            return Err(ConfigError::InitialStep);
+        }
+        if !self.growth.is_finite() || self.growth <= 1.0 {
+            return Err(ConfigError::Growth);
+        }
+        let finite = |limit: Option<f64>| limit.is_none_or(f64::is_finite);
+        if !finite(self.lower) || !finite(self.upper) {
+            return Err(ConfigError::Limits);
+        }
+        if let (Some(lower), Some(upper)) = (self.lower, self.upper)
+            && lower >= upper
+        {
+            return Err(ConfigError::Limits);
+        }
+        Ok(())
+    }
+
+    /// Returns true if `x` lies within the configured limits.
+    pub(super) fn contains(&self, x: f64) -> bool {
+        self.lower.is_none_or(|lower| x >= lower) && self.upper.is_none_or(|upper| x <= upper)
+    }
+
+    /// Clamps `x` to the configured limits.
+    pub(super) fn clamp(&self, x: f64) -> f64 {
+        let x = self.lower.map_or(x, |lower| x.max(lower));
+        self.upper.map_or(x, |upper| x.min(upper))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_non_expanding_growth() {
+        let config = Config {
+            growth: 1.0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Growth));
+    }
+
+    #[test]
+    fn rejects_inverted_limits() {
+        let config = Config {
+            lower: Some(2.0),
+            upper: Some(1.0),
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Limits));
+    }
+
+    #[test]
+    fn clamps_to_limits() {
+        let config = Config {
+            lower: Some(-1.0),
+            upper: Some(1.0),
+            ..Config::default()
+        };
+        assert_relative_eq!(config.clamp(5.0), 1.0);
+        assert_relative_eq!(config.clamp(-5.0), -1.0);
+        assert!(config.contains(0.5));
+        assert!(!config.contains(1.5));
+    }
+}
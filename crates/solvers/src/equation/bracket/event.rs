@@ -0,0 +1,48 @@
+use twine_core::{EquationProblem, Model};
+
+use crate::equation::EvaluateResult;
+
+/// Event emitted during bracket expansion for each evaluation.
+pub enum Event<'a, M, P>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    /// Evaluation of the initial guess.
+    Guess {
+        /// The x value that was evaluated.
+        x: f64,
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, 1>,
+    },
+    /// Evaluation of a probe point on either side of the guess.
+    Probe {
+        /// The expansion that produced this probe, starting at 1.
+        expansion: usize,
+        /// The x value that was evaluated.
+        x: f64,
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, 1>,
+    },
+}
+
+impl<'a, M, P> Event<'a, M, P>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    /// Returns the evaluated x value.
+    #[must_use]
+    pub fn x(&self) -> f64 {
+        match self {
+            Event::Guess { x, .. } | Event::Probe { x, .. } => *x,
+        }
+    }
+
+    /// Returns the evaluation result.
+    pub fn result(&self) -> &'a EvaluateResult<M, P, 1> {
+        match self {
+            Event::Guess { result, .. } | Event::Probe { result, .. } => result,
+        }
+    }
+}
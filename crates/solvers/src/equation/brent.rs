@@ -0,0 +1,403 @@
+//! Brent's method for bracketed scalar root finding.
+//!
+//! # Algorithm
+//!
+//! Brent's method keeps a bracket with a sign change, like bisection, but
+//! chooses each new point by inverse quadratic interpolation or the secant
+//! method whenever that step lands safely inside the bracket and shrinks it
+//! fast enough. Otherwise it falls back to a bisection step, so it never
+//! converges slower than bisection and typically needs far fewer evaluations
+//! on smooth residuals.
+//!
+//! # Shared Types
+//!
+//! Brent's method is a drop-in alternative to [`bisection`]: it takes the same
+//! `[f64; 2]` bracket and shares its [`Config`], [`Action`], [`Solution`], and
+//! error types, so observers written for bisection actions work unchanged.
+//!
+//! # Observer Events
+//!
+//! The solver emits one [`Event`] per evaluation:
+//!
+//! - [`Event::Left`] and [`Event::Right`] — bracket endpoint evaluations
+//! - [`Event::Iterate`] — interior point evaluations, tagged with the [`Step`]
+//!   used to choose them
+//!
+//! Observers can return [`Action::StopEarly`] to halt, or
+//! [`Action::AssumeResidualSign`] to recover from evaluation failures. A point
+//! with an assumed sign narrows the bracket but is not used for interpolation
+//! or best tracking, so the next step is a bisection step.
+//!
+//! [`bisection`]: crate::equation::bisection
+
+mod eval_context;
+mod event;
+mod state;
+
+pub use event::{Event, Step};
+
+pub use super::bisection::{
+    Action, Bracket, BracketError, Config, ConfigError, Error, Sign, Solution, Status,
+};
+
+use twine_core::{EquationProblem, Model, Observer};
+
+use super::bisection::{Best, Bounds, Decision};
+
+use eval_context::EvalContext;
+use state::{Point, State};
+
+/// Finds a root of the equation using Brent's method.
+///
+/// # Algorithm
+///
+/// 1. Evaluate the left and right endpoints.
+/// 2. Validate that the endpoints bracket a root using residual signs.
+/// 3. Iterate: choose an interior point by interpolation (or bisection),
+///    evaluate it, shrink the bracket, and update the best evaluation.
+///
+/// Convergence is reported when either:
+/// - The best residual magnitude is within `config.residual_tol` (absolute only), or
+/// - The bracket width satisfies `x_abs_tol + x_rel_tol * |mid|`.
+///
+/// # Observer
+///
+/// The observer receives an [`Event`] for each evaluation and may:
+/// - Return `Action::StopEarly` to stop and return the best evaluation so far.
+/// - Return `Action::AssumeResidualSign(Sign)` to recover from evaluation
+///   failures by providing a residual sign for bracket updates.
+///   When this action is used on a successful evaluation, that evaluation is
+///   not considered for the best solution or for interpolation.
+///
+/// # Notes
+///
+/// The returned [`Solution`] always reflects the best successful evaluation
+/// seen so far (by residual magnitude).
+/// Iteration counts correspond to the number of interior evaluations performed,
+/// while [`Solution::stats`] counts every evaluation, including the endpoints.
+///
+/// # Errors
+///
+/// Returns an error if the bracket is invalid, the config is invalid,
+/// or the model or problem returns an unrecovered error during evaluation.
+pub fn solve<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    bracket: [f64; 2],
+    config: &Config,
+    mut observer: Obs,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P>, Action>,
+{
+    config.validate()?;
+
+    // Validate and order initial bounds.
+    let bounds = Bounds::new(bracket)?;
+    let [left, right] = bounds.as_array();
+
+    let mut best = Best::empty();
+    let mut ctx = EvalContext::new(model, problem, &mut observer);
+
+    // Resolve left endpoint.
+    let (left_eval, left_decision) = ctx.left_endpoint(left);
+    let left_residual = left_eval.as_ref().map(|eval| eval.residuals[0]);
+    if let Some(eval) = left_eval {
+        best.update(eval);
+    }
+    let left_sign = match left_decision {
+        Decision::Continue(sign) => sign,
+        Decision::StopEarly => return best.finish(Status::StoppedByObserver, 0, ctx.stats()),
+        Decision::Error(error) => return Err(error),
+    };
+
+    // Resolve right endpoint.
+    let (right_eval, right_decision) = ctx.right_endpoint(right);
+    let right_residual = right_eval.as_ref().map(|eval| eval.residuals[0]);
+    if let Some(eval) = right_eval {
+        best.update(eval);
+    }
+    let right_sign = match right_decision {
+        Decision::Continue(sign) => sign,
+        Decision::StopEarly => return best.finish(Status::StoppedByObserver, 0, ctx.stats()),
+        Decision::Error(error) => return Err(error),
+    };
+
+    // Validate bracket signs now that both endpoints are known.
+    let bracket = Bracket::new(bounds, left_sign, right_sign)?;
+    let mut state = State::new(
+        bracket,
+        Point::new(left, left_sign, left_residual),
+        Point::new(right, right_sign, right_residual),
+    );
+
+    if best.is_residual_converged(config.residual_tol) {
+        return best.finish(Status::Converged, 0, ctx.stats());
+    }
+
+    for iter in 1..=config.max_iters {
+        if state
+            .bracket()
+            .is_x_converged(config.x_abs_tol, config.x_rel_tol)
+        {
+            return best.finish(Status::Converged, iter - 1, ctx.stats());
+        }
+
+        // Choose and evaluate the next interior point.
+        let (x, step) = state.propose(config.x_abs_tol, config.x_rel_tol);
+        let (eval, decision) = ctx.iterate(x, step, state.bracket());
+        let residual = eval.as_ref().map(|eval| eval.residuals[0]);
+        if let Some(eval) = eval {
+            best.update(eval);
+        }
+        match decision {
+            Decision::Continue(sign) => state.update(Point::new(x, sign, residual)),
+            Decision::StopEarly => {
+                return best.finish(Status::StoppedByObserver, iter, ctx.stats());
+            }
+            Decision::Error(error) => return Err(error),
+        }
+
+        if best.is_residual_converged(config.residual_tol) {
+            return best.finish(Status::Converged, iter, ctx.stats());
+        }
+    }
+
+    best.finish(Status::MaxIters, config.max_iters, ctx.stats())
+}
+
+/// Runs Brent's method without observation.
+///
+/// # Errors
+///
+/// Returns an error if the bracket is invalid, the config is invalid,
+/// or the model or problem returns an error during evaluation.
+pub fn solve_unobserved<M, P>(
+    model: &M,
+    problem: &P,
+    bracket: [f64; 2],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    solve(model, problem, bracket, config, ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use thiserror::Error;
+
+    use crate::equation::bisection;
+
+    /// Model that cubes its input.
+    struct CubeModel;
+    impl Model for CubeModel {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(input * input * input)
+        }
+    }
+
+    /// Model that fails above a threshold (like HX second law violations).
+    struct ThresholdModel {
+        threshold: f64,
+    }
+    #[derive(Debug, Clone, Error)]
+    #[error("exceeded threshold at x={x}")]
+    struct ThresholdError {
+        x: f64,
+    }
+    impl Model for ThresholdModel {
+        type Input = f64;
+        type Output = f64;
+        type Error = ThresholdError;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            if *input > self.threshold {
+                Err(ThresholdError { x: *input })
+            } else {
+                Ok(input * input)
+            }
+        }
+    }
+
+    /// Equation problem that drives the model output to a target value.
+    struct TargetOutputProblem {
+        target: f64,
+    }
+    impl EquationProblem<1> for TargetOutputProblem {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+            Ok(x[0])
+        }
+
+        fn residuals(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<[f64; 1], Self::Error> {
+            Ok([output - self.target])
+        }
+    }
+
+    #[test]
+    fn finds_cube_root() {
+        let solution = solve_unobserved(
+            &CubeModel,
+            &TargetOutputProblem { target: 27.0 },
+            [0.0, 10.0],
+            &Config::default(),
+        )
+        .expect("should solve");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x, 3.0, epsilon = 1e-10);
+        assert_relative_eq!(solution.snapshot.output, 27.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn needs_fewer_iterations_than_bisection() {
+        let problem = TargetOutputProblem { target: 27.0 };
+        let config = Config::default();
+
+        let brent = solve_unobserved(&CubeModel, &problem, [0.0, 10.0], &config)
+            .expect("brent should solve");
+        let bisection = bisection::solve_unobserved(&CubeModel, &problem, [0.0, 10.0], &config)
+            .expect("bisection should solve");
+
+        assert!(
+            brent.iters * 3 < bisection.iters,
+            "brent took {} iterations, bisection took {}",
+            brent.iters,
+            bisection.iters
+        );
+    }
+
+    #[test]
+    fn rejects_bracket_without_sign_change() {
+        let result = solve_unobserved(
+            &CubeModel,
+            &TargetOutputProblem { target: 27.0 },
+            [4.0, 10.0],
+            &Config::default(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidBracket(BracketError::NoSignChange))
+        ));
+    }
+
+    #[test]
+    fn observer_can_stop_iteration() {
+        let mut iterate_count = 0usize;
+        let observer = |event: &Event<'_, _, _>| {
+            if matches!(event, Event::Iterate { .. }) {
+                iterate_count += 1;
+                if iterate_count >= 2 {
+                    return Some(Action::StopEarly);
+                }
+            }
+            None
+        };
+
+        let solution = solve(
+            &CubeModel,
+            &TargetOutputProblem { target: 27.0 },
+            [0.0, 10.0],
+            &Config::default(),
+            observer,
+        )
+        .expect("should stop cleanly");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.iters, 2);
+    }
+
+    #[test]
+    fn observer_can_recover_from_eval_failure() {
+        // Model fails above x=7, root is at x=3 (for target=9).
+        // Failed points would have large positive residuals: x^2 - 9 > 0.
+        let mut recoveries = 0usize;
+        let observer = |event: &Event<'_, _, _>| {
+            if event.result().is_err() {
+                recoveries += 1;
+                Some(Action::assume_positive())
+            } else {
+                None
+            }
+        };
+
+        let solution = solve(
+            &ThresholdModel { threshold: 7.0 },
+            &TargetOutputProblem { target: 9.0 },
+            [0.0, 10.0],
+            &Config::default(),
+            observer,
+        )
+        .expect("should recover and solve");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x, 3.0, epsilon = 1e-10);
+        assert!(recoveries >= 1);
+    }
+
+    #[test]
+    fn reports_interpolation_steps() {
+        let mut steps = Vec::new();
+        let observer = |event: &Event<'_, _, _>| {
+            if let Event::Iterate { step, .. } = event {
+                steps.push(*step);
+            }
+            None
+        };
+
+        solve(
+            &CubeModel,
+            &TargetOutputProblem { target: 27.0 },
+            [0.0, 10.0],
+            &Config::default(),
+            observer,
+        )
+        .expect("should solve");
+
+        assert!(steps.iter().any(|step| *step != Step::Bisection));
+    }
+
+    #[test]
+    fn errors_when_no_successful_evaluations() {
+        let observer = |event: &Event<'_, _, _>| match event {
+            Event::Left { .. } => Some(Action::assume_negative()),
+            Event::Right { .. } | Event::Iterate { .. } => Some(Action::assume_positive()),
+        };
+
+        let result = solve(
+            &ThresholdModel { threshold: -1.0 },
+            &TargetOutputProblem { target: 9.0 },
+            [0.0, 10.0],
+            &Config::default(),
+            observer,
+        );
+
+        assert!(matches!(result, Err(Error::NoSuccessfulEvaluation)));
+    }
+}
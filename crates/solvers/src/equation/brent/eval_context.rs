@@ -0,0 +1,108 @@
+use twine_core::{EquationProblem, Model, Observer};
+
+use crate::equation::{
+    EvaluateResult, Evaluation,
+    bisection::{Action, Bracket, Decision},
+    evaluate,
+};
+
+use crate::stats::{Stats, Tally};
+
+use super::{Event, Step};
+
+type EvalOutcome<I, O> = (Option<Evaluation<I, O, 1>>, Decision);
+
+/// Bundles evaluation and observation for a single Brent solve.
+///
+/// As in bisection, each evaluation is split into a residual result used for
+/// bracket decisions and an optional evaluation used for best tracking and
+/// interpolation. When the observer assumes a residual sign, the evaluation is
+/// dropped so only its sign is used.
+pub(super) struct EvalContext<'ctx, M, P, Obs> {
+    model: &'ctx M,
+    problem: &'ctx P,
+    observer: &'ctx mut Obs,
+    tally: Tally,
+}
+
+impl<'ctx, M, P, Obs> EvalContext<'ctx, M, P, Obs>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+    Obs: for<'evt> Observer<Event<'evt, M, P>, Action>,
+{
+    /// Creates a new evaluation context.
+    pub(super) fn new(model: &'ctx M, problem: &'ctx P, observer: &'ctx mut Obs) -> Self {
+        Self {
+            model,
+            problem,
+            observer,
+            tally: Tally::start(),
+        }
+    }
+
+    /// Returns the cost of the solve so far.
+    pub(super) fn stats(&self) -> Stats {
+        self.tally.stats()
+    }
+
+    /// Evaluates the left endpoint and returns the observer decision.
+    pub(super) fn left_endpoint(&mut self, x: f64) -> EvalOutcome<M::Input, M::Output> {
+        let result = evaluate(self.model, self.problem, [x]);
+        self.tally.evaluated(&result);
+        let action = self.observer.observe(&Event::Left { x, result: &result });
+        self.tally.observed(action.as_ref());
+        resolve::<M, P>(action, result)
+    }
+
+    /// Evaluates the right endpoint and returns the observer decision.
+    pub(super) fn right_endpoint(&mut self, x: f64) -> EvalOutcome<M::Input, M::Output> {
+        let result = evaluate(self.model, self.problem, [x]);
+        self.tally.evaluated(&result);
+        let action = self.observer.observe(&Event::Right { x, result: &result });
+        self.tally.observed(action.as_ref());
+        resolve::<M, P>(action, result)
+    }
+
+    /// Evaluates an interior point and returns the observer decision.
+    pub(super) fn iterate(
+        &mut self,
+        x: f64,
+        step: Step,
+        bracket: &Bracket,
+    ) -> EvalOutcome<M::Input, M::Output> {
+        let result = evaluate(self.model, self.problem, [x]);
+        self.tally.evaluated(&result);
+        let action = self.observer.observe(&Event::Iterate {
+            x,
+            step,
+            bracket,
+            result: &result,
+        });
+        self.tally.observed(action.as_ref());
+        resolve::<M, P>(action, result)
+    }
+}
+
+/// Splits an observed evaluation into a best-tracking candidate and a decision.
+fn resolve<M, P>(
+    action: Option<Action>,
+    result: EvaluateResult<M, P, 1>,
+) -> EvalOutcome<M::Input, M::Output>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    let (residual, mut eval) = match result {
+        Ok(eval) => (Ok(eval.residuals[0]), Some(eval)),
+        Err(error) => (Err(error.into()), None),
+    };
+
+    let decision = Decision::new(action, residual);
+
+    if matches!(action, Some(Action::AssumeResidualSign(_))) {
+        eval = None;
+    }
+
+    (eval, decision)
+}
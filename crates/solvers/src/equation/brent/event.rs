@@ -0,0 +1,70 @@
+use twine_core::{EquationProblem, Model};
+
+use crate::equation::{EvaluateResult, bisection::Bracket};
+
+/// How Brent's method chose an interior point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// Midpoint of the current bracket.
+    Bisection,
+    /// Linear interpolation through the two most recent points.
+    Secant,
+    /// Inverse quadratic interpolation through the three most recent points.
+    InverseQuadratic,
+}
+
+/// Event emitted by Brent's method for each evaluation.
+pub enum Event<'a, M, P>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    /// Left bracket endpoint evaluation.
+    Left {
+        /// The x value that was evaluated.
+        x: f64,
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, 1>,
+    },
+    /// Right bracket endpoint evaluation.
+    Right {
+        /// The x value that was evaluated.
+        x: f64,
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, 1>,
+    },
+    /// Interior point evaluation with a validated bracket.
+    Iterate {
+        /// The x value that was evaluated.
+        x: f64,
+        /// How the x value was chosen.
+        step: Step,
+        /// Current search bracket.
+        bracket: &'a Bracket,
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, 1>,
+    },
+}
+
+impl<'a, M, P> Event<'a, M, P>
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    /// Returns the evaluated x value.
+    #[must_use]
+    pub fn x(&self) -> f64 {
+        match self {
+            Event::Left { x, .. } | Event::Right { x, .. } | Event::Iterate { x, .. } => *x,
+        }
+    }
+
+    /// Returns the evaluation result.
+    pub fn result(&self) -> &'a EvaluateResult<M, P, 1> {
+        match self {
+            Event::Left { result, .. }
+            | Event::Right { result, .. }
+            | Event::Iterate { result, .. } => result,
+        }
+    }
+}
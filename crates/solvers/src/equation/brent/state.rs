@@ -0,0 +1,251 @@
+use crate::equation::bisection::{Bracket, Sign};
+
+use super::Step;
+
+/// A point known to Brent's method.
+///
+/// The residual value is `None` when the observer supplied only a sign,
+/// in which case the point can bound the root but not be interpolated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Point {
+    x: f64,
+    sign: Sign,
+    residual: Option<f64>,
+}
+
+impl Point {
+    pub(super) fn new(x: f64, sign: Sign, residual: Option<f64>) -> Self {
+        Self { x, sign, residual }
+    }
+
+    /// Residual magnitude, treating an unknown residual as infinitely large.
+    fn magnitude(&self) -> f64 {
+        self.residual.map_or(f64::INFINITY, f64::abs)
+    }
+}
+
+/// Iteration state for Brent's method.
+///
+/// Follows the classic Brent–Dekker bookkeeping:
+/// - `b` is the current estimate (smallest known residual),
+/// - `c` is the contrapoint, so the root lies between `b` and `c`,
+/// - `a` is the previous estimate,
+/// - `d` and `e` are the last two step sizes, used to reject interpolation
+///   steps that are not shrinking fast enough.
+pub(super) struct State {
+    bracket: Bracket,
+    a: Point,
+    b: Point,
+    c: Point,
+    d: f64,
+    e: f64,
+}
+
+impl State {
+    /// Creates the state from a validated bracket and its endpoints.
+    pub(super) fn new(bracket: Bracket, left: Point, right: Point) -> Self {
+        let width = right.x - left.x;
+        let mut state = Self {
+            bracket,
+            a: left,
+            b: right,
+            c: left,
+            d: width,
+            e: width,
+        };
+        state.normalize();
+        state
+    }
+
+    /// Returns the current bracket.
+    pub(super) fn bracket(&self) -> &Bracket {
+        &self.bracket
+    }
+
+    /// Chooses the next point to evaluate.
+    ///
+    /// Interpolation is attempted when the residuals it needs are known and the
+    /// previous steps are shrinking; otherwise the bracket is bisected.
+    pub(super) fn propose(&mut self, x_abs_tol: f64, x_rel_tol: f64) -> (f64, Step) {
+        let (a, b, c) = (self.a, self.b, self.c);
+
+        let tol = 2.0 * f64::EPSILON * b.x.abs() + 0.5 * (x_abs_tol + x_rel_tol * b.x.abs());
+        let half_width = 0.5 * (c.x - b.x);
+
+        let step = match (a.residual, b.residual) {
+            (Some(fa), Some(fb)) if self.e.abs() >= tol && fa.abs() > fb.abs() => {
+                self.interpolate(fa, fb, half_width, tol)
+            }
+            _ => Step::Bisection,
+        };
+
+        if step == Step::Bisection {
+            self.d = half_width;
+            self.e = half_width;
+        }
+
+        let x = if self.d.abs() > tol {
+            b.x + self.d
+        } else {
+            b.x + tol.copysign(half_width)
+        };
+
+        (x, step)
+    }
+
+    /// Attempts an interpolation step, updating `d` and `e` if it is accepted.
+    ///
+    /// Variable names follow the standard presentation of the algorithm.
+    #[allow(clippy::many_single_char_names)]
+    fn interpolate(&mut self, fa: f64, fb: f64, half_width: f64, tol: f64) -> Step {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let s = fb / fa;
+
+        #[allow(clippy::float_cmp)]
+        let collinear = a.x == c.x;
+
+        let (mut p, mut q, step) = match c.residual {
+            Some(fc) if !collinear => {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * half_width * q * (q - r) - (b.x - a.x) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                    Step::InverseQuadratic,
+                )
+            }
+            _ => ((b.x - a.x) * s, s - 1.0, Step::Secant),
+        };
+
+        if p > 0.0 {
+            q = -q;
+        }
+        p = p.abs();
+
+        let min1 = 3.0 * half_width * q - (tol * q).abs();
+        let min2 = (self.e * q).abs();
+        if 2.0 * p < min1.min(min2) {
+            self.e = self.d;
+            self.d = p / q;
+            step
+        } else {
+            Step::Bisection
+        }
+    }
+
+    /// Incorporates a newly evaluated point.
+    pub(super) fn update(&mut self, point: Point) {
+        self.bracket.shrink(point.x, point.sign);
+
+        self.a = self.b;
+        self.b = point;
+
+        // Keep the root between `b` and `c`.
+        if self.b.sign == self.c.sign {
+            self.c = self.a;
+            self.d = self.b.x - self.a.x;
+            self.e = self.d;
+        }
+
+        self.normalize();
+    }
+
+    /// Ensures `b` has the smallest known residual magnitude.
+    fn normalize(&mut self) {
+        if self.c.magnitude() < self.b.magnitude() {
+            self.a = self.b;
+            self.b = self.c;
+            self.c = self.a;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::equation::bisection::Bounds;
+
+    fn f(x: f64) -> f64 {
+        x * x - 2.0
+    }
+
+    fn point(x: f64) -> Point {
+        Point::new(x, Sign::of(f(x)), Some(f(x)))
+    }
+
+    fn state(left: f64, right: f64) -> State {
+        let bracket = Bracket::new(
+            Bounds::new([left, right]).expect("valid bounds"),
+            Sign::of(f(left)),
+            Sign::of(f(right)),
+        )
+        .expect("valid bracket");
+        State::new(bracket, point(left), point(right))
+    }
+
+    #[test]
+    fn first_step_is_secant_between_endpoints() {
+        let mut state = state(0.0, 3.0);
+
+        let (x, step) = state.propose(1e-12, 1e-12);
+
+        // Secant through (0, -2) and (3, 7) crosses zero at x = 2/3.
+        assert_eq!(step, Step::Secant);
+        assert_relative_eq!(x, 2.0 / 3.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn converges_with_interpolation() {
+        let mut state = state(0.0, 2.0);
+        let mut steps = Vec::new();
+
+        for _ in 0..10 {
+            if state.bracket().is_x_converged(1e-12, 1e-12) {
+                break;
+            }
+            let (x, step) = state.propose(1e-12, 1e-12);
+            steps.push(step);
+            state.update(point(x));
+        }
+
+        assert!(state.bracket().is_x_converged(1e-12, 1e-12));
+        assert_relative_eq!(state.b.x, 2.0_f64.sqrt(), epsilon = 1e-12);
+        assert!(steps.contains(&Step::InverseQuadratic));
+    }
+
+    #[test]
+    fn unknown_residuals_force_bisection() {
+        let bracket = Bracket::new(
+            Bounds::new([0.0, 2.0]).expect("valid bounds"),
+            Sign::Negative,
+            Sign::Positive,
+        )
+        .expect("valid bracket");
+        let mut state = State::new(
+            bracket,
+            Point::new(0.0, Sign::Negative, None),
+            Point::new(2.0, Sign::Positive, Some(2.0)),
+        );
+
+        let (x, step) = state.propose(1e-12, 1e-12);
+
+        assert_eq!(step, Step::Bisection);
+        assert_relative_eq!(x, 1.0);
+    }
+
+    #[test]
+    fn update_keeps_root_bracketed() {
+        let mut state = state(0.0, 3.0);
+
+        let (x, _) = state.propose(1e-12, 1e-12);
+        state.update(point(x));
+
+        let [left, right] = state.bracket().as_array();
+        assert_relative_eq!(left, 2.0 / 3.0, epsilon = 1e-12);
+        assert_relative_eq!(right, 3.0);
+        assert_ne!(state.b.sign, state.c.sign);
+    }
+}
@@ -0,0 +1,419 @@
+//! Broyden's method for square systems of equations with expensive models.
+//!
+//! # Algorithm
+//!
+//! Broyden's method takes Newton steps with an approximate Jacobian:
+//!
+//! ```text
+//! B_k · Δx = -r(x_k)
+//! x_{k+1} = x_k + Δx
+//! ```
+//!
+//! The first approximation `B_0` is built by finite differences, exactly as in
+//! [`newton`]. After that, each step updates `B` with a rank-one correction so
+//! that it reproduces the residual change just observed (the secant
+//! condition), without any extra model calls. Each iteration therefore costs
+//! one evaluation instead of Newton's `N + 1`, in exchange for superlinear
+//! rather than quadratic convergence.
+//!
+//! # Restarts
+//!
+//! The secant updates can drift away from the true Jacobian. When
+//! [`stall_iters`](Config::stall_iters) consecutive iterations fail to shrink
+//! the largest residual magnitude by [`progress_ratio`](Config::progress_ratio),
+//! or when the approximation becomes singular, the solver returns to the best
+//! iterate found so far and rebuilds the Jacobian by finite differences.
+//!
+//! # When to Use
+//!
+//! Broyden's method is appropriate when:
+//! - The system is square (`N` residuals in `N` unknowns)
+//! - Each model call is expensive, so Newton's Jacobian dominates the cost
+//! - A reasonable initial guess is available
+//!
+//! # Shared Types
+//!
+//! Broyden's method shares its [`Action`], [`Event`], [`Solution`], and
+//! [`Status`] types with [`newton`], so observers written for Newton's method
+//! work unchanged.
+//!
+//! # Observer Events
+//!
+//! The solver emits one [`Event`] per evaluation:
+//!
+//! - [`Event::Iterate`] — evaluation at an iterate (iteration 0 is the initial
+//!   guess)
+//! - [`Event::Jacobian`] — perturbed evaluation for a finite difference
+//!   Jacobian column, emitted only for the initial Jacobian and restarts
+//!
+//! Observers can return [`Action::StopEarly`] to halt and return the best
+//! iterate found so far.
+//!
+//! [`newton`]: crate::equation::newton
+
+mod config;
+mod error;
+mod update;
+
+pub use config::{Config, ConfigError};
+pub use error::Error;
+
+pub use super::newton::{Action, Difference, Event, Solution, Status};
+
+use twine_core::{EquationProblem, Model, Observer};
+
+use crate::{Stats, linalg};
+
+use super::newton::{
+    best::Best,
+    eval_context::{EvalContext, Outcome},
+};
+
+/// Finds a root of a square system of equations using Broyden's method.
+///
+/// Convergence is reported when either:
+/// - Every residual magnitude of the best iterate is within `config.residual_tol`, or
+/// - The step satisfies `max|Δx| <= x_abs_tol + x_rel_tol * max|x|`.
+///
+/// The observer receives an [`Event`] for each evaluation and may return
+/// `Action::StopEarly` to stop and return the best iterate so far.
+/// The returned [`Solution`] reflects the best iterate seen (by largest
+/// residual magnitude).
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, a freshly built Jacobian is
+/// singular, or the model or problem returns an error during evaluation.
+pub fn solve<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    mut observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    config.validate()?;
+
+    let mut best = Best::empty();
+    let mut ctx = EvalContext::new(model, problem, &mut observer);
+
+    // Evaluate the initial guess.
+    let mut x = x0;
+    let mut r = match ctx.iterate(0, x, &mut best)? {
+        Outcome::Continue(r) => r,
+        Outcome::StopEarly => return finish(best, Status::StoppedByObserver, 0, ctx.stats()),
+    };
+
+    if best.is_residual_converged(config.residual_tol) {
+        return finish(best, Status::Converged, 0, ctx.stats());
+    }
+
+    let mut jacobian = match ctx.jacobian(&x, &r, config.difference, config.fd_step)? {
+        Outcome::Continue(jacobian) => jacobian,
+        Outcome::StopEarly => return finish(best, Status::StoppedByObserver, 0, ctx.stats()),
+    };
+    let mut is_fresh = true;
+    let mut stalled = 0;
+
+    for iter in 1..=config.max_iters {
+        let step = if let Some(step) = linalg::solve(jacobian, r.map(|v| -v)) {
+            step
+        } else {
+            if is_fresh {
+                return Err(Error::SingularJacobian);
+            }
+
+            // The updates made the approximation singular; restart.
+            (x, r) = restart_point(&best);
+            jacobian = match ctx.jacobian(&x, &r, config.difference, config.fd_step)? {
+                Outcome::Continue(jacobian) => jacobian,
+                Outcome::StopEarly => {
+                    return finish(best, Status::StoppedByObserver, iter - 1, ctx.stats());
+                }
+            };
+            stalled = 0;
+            linalg::solve(jacobian, r.map(|v| -v)).ok_or(Error::SingularJacobian)?
+        };
+
+        let x_next = std::array::from_fn(|i| x[i] + step[i]);
+        let r_next = match ctx.iterate(iter, x_next, &mut best)? {
+            Outcome::Continue(r) => r,
+            Outcome::StopEarly => {
+                return finish(best, Status::StoppedByObserver, iter, ctx.stats());
+            }
+        };
+
+        if best.is_residual_converged(config.residual_tol)
+            || linalg::is_step_converged(&step, &x_next, config.x_abs_tol, config.x_rel_tol)
+        {
+            return finish(best, Status::Converged, iter, ctx.stats());
+        }
+
+        let made_progress = linalg::max_abs(&r_next) <= config.progress_ratio * linalg::max_abs(&r);
+        stalled = if made_progress { 0 } else { stalled + 1 };
+
+        if stalled >= config.stall_iters {
+            (x, r) = restart_point(&best);
+            jacobian = match ctx.jacobian(&x, &r, config.difference, config.fd_step)? {
+                Outcome::Continue(jacobian) => jacobian,
+                Outcome::StopEarly => {
+                    return finish(best, Status::StoppedByObserver, iter, ctx.stats());
+                }
+            };
+            is_fresh = true;
+            stalled = 0;
+        } else {
+            let dr = std::array::from_fn(|i| r_next[i] - r[i]);
+            update::update(&mut jacobian, &step, &dr);
+            is_fresh = false;
+            (x, r) = (x_next, r_next);
+        }
+    }
+
+    finish(best, Status::MaxIters, config.max_iters, ctx.stats())
+}
+
+/// Runs Broyden's method without observation.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, a freshly built Jacobian is
+/// singular, or the model or problem returns an error during evaluation.
+pub fn solve_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    solve(model, problem, x0, config, ())
+}
+
+/// Returns the x and residuals of the best iterate to restart from.
+fn restart_point<I, O, const N: usize>(best: &Best<I, O, N>) -> ([f64; N], [f64; N]) {
+    let eval = best
+        .get()
+        .expect("an iterate is evaluated before any restart");
+    (eval.x, eval.residuals)
+}
+
+/// Finalizes the solve using the best iterate.
+#[allow(clippy::similar_names)]
+fn finish<I, O, const N: usize>(
+    best: Best<I, O, N>,
+    status: Status,
+    iters: usize,
+    stats: Stats,
+) -> Result<Solution<I, O, N>, Error> {
+    best.finish(status, iters, stats).map_err(Error::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+
+    use crate::equation::newton;
+
+    /// Passes solver variables straight through as the model output.
+    struct Identity;
+    impl Model for Identity {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(*input)
+        }
+    }
+
+    /// Intersection of the circle `x² + y² = 4` with the curve `y = x³`.
+    struct CircleCubic;
+    impl EquationProblem<2> for CircleCubic {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<Self::Input, Self::Error> {
+            Ok(*x)
+        }
+
+        fn residuals(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<[f64; 2], Self::Error> {
+            let [x, y] = *output;
+            Ok([x * x + y * y - 4.0, y - x * x * x])
+        }
+    }
+
+    /// Model that squares its input.
+    struct SquareModel;
+    impl Model for SquareModel {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(input * input)
+        }
+    }
+
+    /// Drives the model output to a target value.
+    struct TargetOutputProblem {
+        target: f64,
+    }
+    impl EquationProblem<1> for TargetOutputProblem {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+            Ok(x[0])
+        }
+
+        fn residuals(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<[f64; 1], Self::Error> {
+            Ok([output - self.target])
+        }
+    }
+
+    /// Counts iterate and Jacobian events.
+    #[derive(Default)]
+    struct Counts {
+        iterates: usize,
+        jacobian: usize,
+    }
+
+    impl Counts {
+        fn observe<M, P, const N: usize>(&mut self, event: &Event<'_, M, P, N>) -> Option<Action>
+        where
+            M: Model,
+            P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+        {
+            match event {
+                Event::Iterate { .. } => self.iterates += 1,
+                Event::Jacobian { .. } => self.jacobian += 1,
+            }
+            None
+        }
+    }
+
+    #[test]
+    fn solves_two_equation_system() {
+        let solution = solve_unobserved(&Identity, &CircleCubic, [1.0, 1.0], &Config::default())
+            .expect("should solve");
+
+        assert_eq!(solution.status, Status::Converged);
+        let [x, y] = solution.x;
+        assert_relative_eq!(x * x + y * y, 4.0, epsilon = 1e-10);
+        assert_relative_eq!(y, x.powi(3), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn uses_fewer_evaluations_than_newton() {
+        let mut broyden = Counts::default();
+        solve(
+            &Identity,
+            &CircleCubic,
+            [1.0, 1.0],
+            &Config::default(),
+            |event: &Event<'_, Identity, CircleCubic, 2>| broyden.observe(event),
+        )
+        .expect("should solve");
+
+        let mut newton = Counts::default();
+        newton::solve(
+            &Identity,
+            &CircleCubic,
+            [1.0, 1.0],
+            &newton::Config::default(),
+            |event: &Event<'_, Identity, CircleCubic, 2>| newton.observe(event),
+        )
+        .expect("should solve");
+
+        // Only the initial Jacobian is built by finite differences.
+        assert_eq!(broyden.jacobian, 2);
+        assert!(
+            broyden.iterates + broyden.jacobian < newton.iterates + newton.jacobian,
+            "broyden: {}, newton: {}",
+            broyden.iterates + broyden.jacobian,
+            newton.iterates + newton.jacobian,
+        );
+    }
+
+    #[test]
+    fn restarts_when_progress_stalls() {
+        // Demanding a large reduction every iteration forces restarts.
+        let config = Config {
+            progress_ratio: 1e-3,
+            stall_iters: 1,
+            ..Config::default()
+        };
+
+        let mut counts = Counts::default();
+        let solution = solve(
+            &Identity,
+            &CircleCubic,
+            [1.0, 1.0],
+            &config,
+            |event: &Event<'_, Identity, CircleCubic, 2>| counts.observe(event),
+        )
+        .expect("should solve");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert!(counts.jacobian > 2, "jacobian evals = {}", counts.jacobian);
+    }
+
+    #[test]
+    fn singular_initial_jacobian_is_an_error() {
+        // d(x²)/dx = 0 at x = 0.
+        let result = solve_unobserved(
+            &SquareModel,
+            &TargetOutputProblem { target: 9.0 },
+            [0.0],
+            &Config {
+                fd_step: 1e-300,
+                ..Config::default()
+            },
+        );
+
+        assert!(matches!(result, Err(Error::SingularJacobian)));
+    }
+
+    #[test]
+    fn observer_can_stop_early() {
+        let mut iterates = 0;
+
+        let solution = solve(
+            &SquareModel,
+            &TargetOutputProblem { target: 9.0 },
+            [1.0],
+            &Config::default(),
+            |event: &Event<'_, SquareModel, TargetOutputProblem, 1>| {
+                if let Event::Iterate { .. } = event {
+                    iterates += 1;
+                }
+                (iterates == 3).then_some(Action::StopEarly)
+            },
+        )
+        .expect("should stop cleanly");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.iters, 2);
+    }
+}
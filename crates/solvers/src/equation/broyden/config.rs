@@ -0,0 +1,119 @@
+use thiserror::Error;
+
+use crate::equation::newton::Difference;
+
+/// Configuration for Broyden's method.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    pub max_iters: usize,
+    pub x_abs_tol: f64,
+    pub x_rel_tol: f64,
+    pub residual_tol: f64,
+    /// Finite difference scheme used for the initial and restart Jacobians.
+    pub difference: Difference,
+    /// Relative perturbation size for finite differences.
+    ///
+    /// Each variable is perturbed by `fd_step * max(|x_j|, 1)`.
+    pub fd_step: f64,
+    /// Required reduction in the largest residual magnitude per iteration.
+    ///
+    /// An iteration makes progress if its largest residual magnitude is at
+    /// most this fraction of the previous one.
+    pub progress_ratio: f64,
+    /// Number of consecutive iterations without progress before the Jacobian
+    /// is rebuilt by finite differences.
+    pub stall_iters: usize,
+}
+
+/// Errors that can occur when validating a Broyden config.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("x_abs_tol must be finite and non-negative")]
+    XAbs,
+
+    #[error("x_rel_tol must be finite and non-negative")]
+    XRel,
+
+    #[error("residual_tol must be finite and non-negative")]
+    Residual,
+
+    #[error("fd_step must be finite and positive")]
+    FdStep,
+
+    #[error("progress_ratio must be in (0, 1]")]
+    ProgressRatio,
+
+    #[error("stall_iters must be at least 1")]
+    StallIters,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_iters: 100,
+            x_abs_tol: 1e-12,
+            x_rel_tol: 1e-12,
+            residual_tol: 1e-12,
+            difference: Difference::Forward,
+            fd_step: f64::EPSILON.sqrt(),
+            progress_ratio: 0.9,
+            stall_iters: 2,
+        }
+    }
+}
+
+impl Config {
+    /// Validates the tolerances, finite difference step, and restart policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any tolerance is negative or non-finite, if
+    /// `fd_step` is not finite and positive, if `progress_ratio` is outside
+    /// `(0, 1]`, or if `stall_iters` is zero.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !self.x_abs_tol.is_finite() || self.x_abs_tol < 0.0 {
+            return Err(ConfigError::XAbs);
+        }
+        if !self.x_rel_tol.is_finite() || self.x_rel_tol < 0.0 {
+            return Err(ConfigError::XRel);
+        }
+        if !self.residual_tol.is_finite() || self.residual_tol < 0.0 {
+            return Err(ConfigError::Residual);
+        }
+        if !self.fd_step.is_finite() || self.fd_step <= 0.0 {
+            return Err(ConfigError::FdStep);
+        }
+        if !(0.0 < self.progress_ratio && self.progress_ratio <= 1.0) {
+            return Err(ConfigError::ProgressRatio);
+        }
+        if self.stall_iters == 0 {
+            return Err(ConfigError::StallIters);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_bad_restart_policy() {
+        let config = Config {
+            progress_ratio: 0.0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::ProgressRatio));
+
+        let config = Config {
+            stall_iters: 0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::StallIters));
+    }
+}
@@ -0,0 +1,41 @@
+use std::error::Error as StdError;
+
+use thiserror::Error;
+
+use crate::equation::newton;
+
+use super::config::ConfigError;
+
+/// Errors that can occur during a Broyden solve.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("invalid config: {0}")]
+    InvalidConfig(#[from] ConfigError),
+
+    #[error("singular Jacobian")]
+    SingularJacobian,
+
+    #[error("no successful evaluations")]
+    NoSuccessfulEvaluation,
+
+    #[error("problem error")]
+    Problem(#[source] Box<dyn StdError + Send + Sync>),
+
+    #[error("model call failed")]
+    Model(#[source] Box<dyn StdError + Send + Sync>),
+}
+
+/// Converts errors from the evaluation helpers shared with Newton's method.
+impl From<newton::Error> for Error {
+    fn from(err: newton::Error) -> Self {
+        match err {
+            newton::Error::InvalidConfig(_) => {
+                unreachable!("Broyden validates its own config")
+            }
+            newton::Error::SingularJacobian => Self::SingularJacobian,
+            newton::Error::NoSuccessfulEvaluation => Self::NoSuccessfulEvaluation,
+            newton::Error::Problem(e) => Self::Problem(e),
+            newton::Error::Model(e) => Self::Model(e),
+        }
+    }
+}
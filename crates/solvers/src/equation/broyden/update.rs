@@ -0,0 +1,58 @@
+use crate::linalg::dot;
+
+/// Applies Broyden's rank-one update to a Jacobian approximation.
+///
+/// After a step `s` that changed the residuals by `y`, the updated matrix
+/// satisfies the secant condition `J s = y` while changing `J` as little as
+/// possible (in the Frobenius norm):
+///
+/// ```text
+/// J += (y - J s) sᵀ / (sᵀ s)
+/// ```
+///
+/// A zero step leaves `J` unchanged.
+pub(super) fn update<const N: usize>(jacobian: &mut [[f64; N]; N], s: &[f64; N], y: &[f64; N]) {
+    let ss = dot(s, s);
+    if ss == 0.0 {
+        return;
+    }
+
+    for (row, yi) in jacobian.iter_mut().zip(y) {
+        let scale = (yi - dot(row, s)) / ss;
+        for (value, sj) in row.iter_mut().zip(s) {
+            *value += scale * sj;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn satisfies_secant_condition() {
+        let mut jacobian = [[1.0, 0.0], [0.0, 1.0]];
+        let s = [0.5, -1.0];
+        let y = [2.0, 3.0];
+
+        update(&mut jacobian, &s, &y);
+
+        for (row, yi) in jacobian.iter().zip(y) {
+            assert_relative_eq!(dot(row, &s), yi, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn leaves_orthogonal_directions_unchanged() {
+        let mut jacobian = [[2.0, 1.0], [0.0, 3.0]];
+        update(&mut jacobian, &[1.0, 0.0], &[5.0, 5.0]);
+
+        // Only the first column changes for a step along x0.
+        assert_relative_eq!(jacobian[0][1], 1.0);
+        assert_relative_eq!(jacobian[1][1], 3.0);
+        assert_relative_eq!(jacobian[0][0], 5.0);
+        assert_relative_eq!(jacobian[1][0], 5.0);
+    }
+}
@@ -0,0 +1,426 @@
+//! Continuation (homotopy) for equation problems that only converge when a
+//! parameter is ramped.
+//!
+//! # Algorithm
+//!
+//! The caller provides a family of problems indexed by a scalar `λ`, such as
+//! a load fraction. The driver solves the problem at the starting λ, then
+//! steps λ toward its final value, solving each problem with a [`Corrector`]
+//! started from the previous point's `x`:
+//!
+//! ```text
+//! x(λ_{k+1}) = correct(problem(λ_{k+1}), x(λ_k))
+//! ```
+//!
+//! The λ step grows by [`step_growth`](Config::step_growth) after each
+//! converged point and shrinks by [`step_reduction`](Config::step_reduction)
+//! after each failure, so easy stretches are crossed quickly and hard ones
+//! are approached carefully.
+//!
+//! # When to Use
+//!
+//! Continuation is appropriate when:
+//! - A direct solve fails from every reasonable initial guess
+//! - An easy member of the problem family is known, such as a low load
+//! - The solution changes smoothly with the parameter
+//!
+//! # Observer Events
+//!
+//! The driver emits one [`Event`] per corrector run:
+//!
+//! - [`Event::Converged`] — the corrector converged at a λ value
+//! - [`Event::Failed`] — the corrector failed at a λ value
+//!
+//! Observers can return [`Action::StopEarly`] to return the path traced so
+//! far, or [`Action::RejectStep`] to discard a converged point and retry with
+//! a smaller λ step.
+
+mod action;
+mod config;
+mod corrector;
+mod error;
+mod event;
+mod solution;
+
+pub use action::Action;
+pub use config::{Config, ConfigError};
+pub use corrector::Corrector;
+pub use error::Error;
+pub use event::Event;
+pub use solution::{Point, Solution, Status};
+
+use twine_core::{EquationProblem, Model, Observer};
+
+use crate::stats::{Stats, Tally};
+
+/// Traces the solution path of a problem family from `lambda[0]` to `lambda[1]`.
+///
+/// `family` builds the problem for a given λ. The range may decrease, and
+/// `x0` is the initial guess for the problem at `lambda[0]`.
+///
+/// # Observer
+///
+/// The observer receives an [`Event`] after each corrector run and may return
+/// `Action::StopEarly` to stop, or `Action::RejectStep` to discard a
+/// converged point after the start.
+///
+/// # Notes
+///
+/// Failures after the starting point are not errors: they shrink the λ step,
+/// and the run ends with [`Status::StepTooSmall`] once the step falls below
+/// `config.min_step`. The returned path holds every accepted point, so a
+/// partial path is still available.
+///
+/// A zero-width range, with both ends equal, completes after the starting
+/// point.
+///
+/// # Errors
+///
+/// Returns an error if the config or λ range is invalid, or if the corrector
+/// fails at the starting λ.
+pub fn solve<M, P, F, Obs, const N: usize>(
+    model: &M,
+    family: F,
+    lambda: [f64; 2],
+    x0: [f64; N],
+    config: &Config,
+    mut observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+    F: Fn(f64) -> P,
+    Obs: for<'a> Observer<Event<'a, M, N>, Action>,
+{
+    config.validate()?;
+
+    let [start, end] = lambda;
+    if !start.is_finite() || !end.is_finite() {
+        return Err(Error::InvalidRange);
+    }
+    let span = end - start;
+    let tally = Tally::start();
+
+    let first = match config
+        .corrector
+        .correct(model, &family(start), start, x0, &tally)
+    {
+        Ok(point) => point,
+        Err(error) => {
+            let action = observer.observe(&Event::Failed {
+                lambda: start,
+                error: &error,
+            });
+            tally.observed(action.as_ref());
+            return Err(error);
+        }
+    };
+    let action = observer.observe(&Event::Converged { point: &first });
+    tally.observed(action.as_ref());
+    let mut x = first.x;
+    let mut path = vec![first];
+
+    if let Some(Action::StopEarly) = action {
+        return Ok(finish(path, Status::StoppedByObserver, 0, tally.stats()));
+    }
+
+    // A zero-width range ends at its starting point.
+    if span == 0.0 {
+        return Ok(finish(path, Status::Completed, 0, tally.stats()));
+    }
+
+    // Progress along the range as a fraction in [0, 1], so the final point
+    // lands exactly on `end`.
+    let mut progress = 0.0;
+    let mut step = config.initial_step;
+
+    for steps in 1..=config.max_steps {
+        if progress >= 1.0 {
+            return Ok(finish(path, Status::Completed, steps - 1, tally.stats()));
+        }
+
+        let is_last = progress + step >= 1.0;
+        let next_progress = if is_last { 1.0 } else { progress + step };
+        let next_lambda = if is_last {
+            end
+        } else {
+            start + next_progress * span
+        };
+
+        let result = config
+            .corrector
+            .correct(model, &family(next_lambda), next_lambda, x, &tally);
+
+        let action = match &result {
+            Ok(point) => observer.observe(&Event::Converged { point }),
+            Err(error) => observer.observe(&Event::Failed {
+                lambda: next_lambda,
+                error,
+            }),
+        };
+        tally.observed(action.as_ref());
+
+        match (action, result) {
+            (Some(Action::StopEarly), _) => {
+                return Ok(finish(
+                    path,
+                    Status::StoppedByObserver,
+                    steps,
+                    tally.stats(),
+                ));
+            }
+            (None, Ok(point)) => {
+                x = point.x;
+                path.push(point);
+                progress = next_progress;
+                step = (step * config.step_growth).min(config.max_step);
+            }
+            (Some(Action::RejectStep), _) | (None, Err(_)) => {
+                step *= config.step_reduction;
+                if step < config.min_step {
+                    return Ok(finish(path, Status::StepTooSmall, steps, tally.stats()));
+                }
+            }
+        }
+    }
+
+    let status = if progress >= 1.0 {
+        Status::Completed
+    } else {
+        Status::MaxSteps
+    };
+    Ok(finish(path, status, config.max_steps, tally.stats()))
+}
+
+/// Runs continuation without observation.
+///
+/// # Errors
+///
+/// Returns an error if the config or λ range is invalid, or if the corrector
+/// fails at the starting λ.
+pub fn solve_unobserved<M, P, F, const N: usize>(
+    model: &M,
+    family: F,
+    lambda: [f64; 2],
+    x0: [f64; N],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+    F: Fn(f64) -> P,
+{
+    solve(model, family, lambda, x0, config, ())
+}
+
+/// Builds the solution from the traced path.
+#[allow(clippy::similar_names)]
+fn finish<I, O, const N: usize>(
+    path: Vec<Point<I, O, N>>,
+    status: Status,
+    steps: usize,
+    stats: Stats,
+) -> Solution<I, O, N> {
+    Solution {
+        status,
+        path,
+        steps,
+        stats,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use thiserror::Error;
+
+    use crate::equation::{newton, trust_region};
+
+    /// Model that takes the square root, failing for negative inputs.
+    struct SqrtModel;
+    #[derive(Debug, Error)]
+    #[error("negative input: {0}")]
+    struct NegativeInput(f64);
+    impl Model for SqrtModel {
+        type Input = f64;
+        type Output = f64;
+        type Error = NegativeInput;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            if *input < 0.0 {
+                Err(NegativeInput(*input))
+            } else {
+                Ok(input.sqrt())
+            }
+        }
+    }
+
+    /// Drives the model output to a target value.
+    struct TargetOutputProblem {
+        target: f64,
+    }
+    impl EquationProblem<1> for TargetOutputProblem {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+            Ok(x[0])
+        }
+
+        fn residuals(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<[f64; 1], Self::Error> {
+            Ok([output - self.target])
+        }
+    }
+
+    /// Ramps the target of `√x` from 10 down to 1.
+    ///
+    /// A direct Newton solve at λ = 1 from x = 100 steps to x = -80.
+    fn ramp(lambda: f64) -> TargetOutputProblem {
+        TargetOutputProblem {
+            target: 10.0 - 9.0 * lambda,
+        }
+    }
+
+    #[test]
+    fn traces_path_where_direct_solve_fails() {
+        let direct =
+            newton::solve_unobserved(&SqrtModel, &ramp(1.0), [100.0], &newton::Config::default());
+        assert!(matches!(direct, Err(newton::Error::Model(_))));
+
+        let solution = solve_unobserved(&SqrtModel, ramp, [0.0, 1.0], [100.0], &Config::default())
+            .expect("should trace path");
+
+        assert_eq!(solution.status, Status::Completed);
+        assert!(solution.path.windows(2).all(|w| w[0].lambda < w[1].lambda));
+
+        let first = solution.path.first().expect("start point");
+        let last = solution.path.last().expect("end point");
+        assert_relative_eq!(first.lambda, 0.0);
+        assert_relative_eq!(first.x[0], 100.0, epsilon = 1e-10);
+        assert_relative_eq!(last.lambda, 1.0);
+        assert_relative_eq!(last.x[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(last.snapshot.output, 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn shrinks_step_after_failure() {
+        let config = Config {
+            initial_step: 1.0,
+            ..Config::default()
+        };
+        let mut failures = 0;
+
+        let solution = solve(
+            &SqrtModel,
+            ramp,
+            [0.0, 1.0],
+            [100.0],
+            &config,
+            |event: &Event<'_, SqrtModel, 1>| {
+                if let Event::Failed { .. } = event {
+                    failures += 1;
+                }
+                None
+            },
+        )
+        .expect("should trace path");
+
+        assert_eq!(solution.status, Status::Completed);
+        assert!(failures > 0);
+        assert_eq!(solution.steps, solution.path.len() - 1 + failures);
+    }
+
+    #[test]
+    fn reports_step_too_small_past_a_dead_end() {
+        // √x cannot reach a negative target, which happens past λ = 0.5.
+        let family = |lambda: f64| TargetOutputProblem {
+            target: 1.0 - 2.0 * lambda,
+        };
+        let config = Config {
+            corrector: Corrector::TrustRegion(trust_region::Config::default()),
+            ..Config::default()
+        };
+
+        let solution =
+            solve_unobserved(&SqrtModel, family, [0.0, 1.0], [1.0], &config).expect("partial path");
+
+        assert_eq!(solution.status, Status::StepTooSmall);
+        let last = solution.path.last().expect("end point");
+        assert!(last.lambda < 0.5 && last.lambda > 0.45, "{}", last.lambda);
+    }
+
+    #[test]
+    fn observer_can_reject_points() {
+        // Reject the first attempt at λ = 0.5.
+        let config = Config {
+            initial_step: 0.5,
+            ..Config::default()
+        };
+        let mut rejected = 0;
+
+        let solution = solve(
+            &SqrtModel,
+            ramp,
+            [0.0, 1.0],
+            [100.0],
+            &config,
+            |event: &Event<'_, SqrtModel, 1>| {
+                let is_rejected = rejected == 0
+                    && matches!(event, Event::Converged { point } if point.lambda > 0.4);
+                if is_rejected {
+                    rejected += 1;
+                }
+                is_rejected.then_some(Action::RejectStep)
+            },
+        )
+        .expect("should trace path");
+
+        assert_eq!(solution.status, Status::Completed);
+        assert_eq!(rejected, 1);
+        assert_relative_eq!(solution.path[1].lambda, 0.25);
+    }
+
+    #[test]
+    fn decreasing_range_and_early_stop() {
+        let solution = solve(
+            &SqrtModel,
+            ramp,
+            [1.0, 0.0],
+            [1.0],
+            &Config::default(),
+            |event: &Event<'_, SqrtModel, 1>| (event.lambda() < 0.75).then_some(Action::StopEarly),
+        )
+        .expect("should stop cleanly");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert!(solution.path.windows(2).all(|w| w[0].lambda > w[1].lambda));
+        assert!(solution.path.last().expect("point").lambda >= 0.75);
+    }
+
+    #[test]
+    fn failure_at_start_is_an_error() {
+        let result = solve_unobserved(&SqrtModel, ramp, [0.0, 1.0], [-1.0], &Config::default());
+
+        assert!(matches!(result, Err(Error::Model(_))));
+    }
+
+    #[test]
+    fn zero_width_range_solves_only_the_start() {
+        let solution = solve_unobserved(&SqrtModel, ramp, [0.5, 0.5], [1.0], &Config::default())
+            .expect("should solve the start");
+
+        assert_eq!(solution.status, Status::Completed);
+        assert_eq!(solution.steps, 0);
+        assert_eq!(solution.path.len(), 1);
+        assert_relative_eq!(solution.path[0].lambda, 0.5);
+    }
+}
@@ -0,0 +1,13 @@
+/// Control actions supported by the continuation driver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Stop early and return the path traced so far.
+    StopEarly,
+
+    /// Reject a converged point and retry with a smaller λ step.
+    ///
+    /// This lets domain checks discard a solution the corrector accepted,
+    /// such as one on the wrong branch. It is ignored for the starting point,
+    /// which has no previous point to step back to.
+    RejectStep,
+}
@@ -0,0 +1,142 @@
+use thiserror::Error;
+
+use crate::equation::{broyden, newton, trust_region};
+
+use super::Corrector;
+
+/// Configuration for the continuation driver.
+///
+/// Step sizes are fractions of the λ range, so `initial_step: 0.1` takes ten
+/// equal steps if every point converges without growth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    /// Solver used to converge each point.
+    pub corrector: Corrector,
+    /// Maximum number of corrector runs after the starting point.
+    pub max_steps: usize,
+    /// First λ step, as a fraction of the range.
+    pub initial_step: f64,
+    /// Smallest λ step before giving up, as a fraction of the range.
+    pub min_step: f64,
+    /// Largest λ step, as a fraction of the range.
+    pub max_step: f64,
+    /// Factor applied to the λ step after a converged point.
+    pub step_growth: f64,
+    /// Factor applied to the λ step after a failed or rejected point.
+    pub step_reduction: f64,
+}
+
+/// Errors that can occur when validating a continuation config.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("min_step must be finite and positive")]
+    MinStep,
+
+    #[error("max_step must be in [min_step, 1]")]
+    MaxStep,
+
+    #[error("initial_step must be in [min_step, max_step]")]
+    InitialStep,
+
+    #[error("step_growth must be finite and at least 1")]
+    StepGrowth,
+
+    #[error("step_reduction must be in (0, 1)")]
+    StepReduction,
+
+    #[error("invalid Newton corrector config: {0}")]
+    Newton(newton::ConfigError),
+
+    #[error("invalid Broyden corrector config: {0}")]
+    Broyden(broyden::ConfigError),
+
+    #[error("invalid trust-region corrector config: {0}")]
+    TrustRegion(trust_region::ConfigError),
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            corrector: Corrector::default(),
+            max_steps: 1000,
+            initial_step: 0.1,
+            min_step: 1e-6,
+            max_step: 1.0,
+            step_growth: 2.0,
+            step_reduction: 0.5,
+        }
+    }
+}
+
+impl Config {
+    /// Validates the step controls and the corrector config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the step sizes are not ordered as
+    /// `0 < min_step <= initial_step <= max_step <= 1`, if `step_growth` is
+    /// less than 1, if `step_reduction` is outside `(0, 1)`, or if the
+    /// corrector config is invalid.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !self.min_step.is_finite() || self.min_step <= 0.0 {
+            return Err(ConfigError::MinStep);
+        }
+        if !(self.min_step <= self.max_step && self.max_step <= 1.0) {
+            return Err(ConfigError::MaxStep);
+        }
+        if !(self.min_step <= self.initial_step && self.initial_step <= self.max_step) {
+            return Err(ConfigError::InitialStep);
+        }
+        if !self.step_growth.is_finite() || self.step_growth < 1.0 {
+            return Err(ConfigError::StepGrowth);
+        }
+        if !(0.0 < self.step_reduction && self.step_reduction < 1.0) {
+            return Err(ConfigError::StepReduction);
+        }
+        match self.corrector {
+            Corrector::Newton(config) => config.validate().map_err(ConfigError::Newton),
+            Corrector::Broyden(config) => config.validate().map_err(ConfigError::Broyden),
+            Corrector::TrustRegion(config) => config.validate().map_err(ConfigError::TrustRegion),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_unordered_steps() {
+        let config = Config {
+            initial_step: 1e-9,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::InitialStep));
+
+        let config = Config {
+            max_step: 2.0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::MaxStep));
+    }
+
+    #[test]
+    fn validates_corrector_config() {
+        let config = Config {
+            corrector: Corrector::Newton(newton::Config {
+                fd_step: 0.0,
+                ..newton::Config::default()
+            }),
+            ..Config::default()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::Newton(newton::ConfigError::FdStep))
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use twine_core::{EquationProblem, Model};
+
+use crate::{
+    equation::{broyden, newton, trust_region},
+    stats::Tally,
+};
+
+use super::{Error, Point};
+
+/// The equation solver used to converge each point on the path.
+///
+/// Every corrector runs without the caller's observer, starting from the
+/// previous point's `x`.
+/// A point only counts as solved if the corrector reports convergence.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Corrector {
+    /// Newton's method with a finite difference Jacobian.
+    Newton(newton::Config),
+    /// Broyden's method, for models that are expensive to call.
+    Broyden(broyden::Config),
+    /// Newton steps safeguarded by a line search or trust region.
+    TrustRegion(trust_region::Config),
+}
+
+impl Default for Corrector {
+    fn default() -> Self {
+        Self::Newton(newton::Config::default())
+    }
+}
+
+impl Corrector {
+    /// Solves `problem` from `x0` and returns the converged point at `lambda`.
+    ///
+    /// Every evaluation is recorded in `tally`, whether or not the corrector
+    /// converges.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotConverged` if the corrector finishes without
+    /// converging, or the corrector's error mapped into [`Error`].
+    pub(super) fn correct<M, P, const N: usize>(
+        &self,
+        model: &M,
+        problem: &P,
+        lambda: f64,
+        x0: [f64; N],
+        tally: &Tally,
+    ) -> Result<Point<M::Input, M::Output, N>, Error>
+    where
+        M: Model,
+        P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+    {
+        // The correctors differ only in their module, so each arm expands the
+        // same solve, convergence check, and conversion.
+        macro_rules! correct_with {
+            ($solver:ident, $config:expr) => {{
+                let solution = $solver::solve(
+                    model,
+                    problem,
+                    x0,
+                    $config,
+                    |event: &$solver::Event<'_, M, P, N>| {
+                        tally.evaluated(event.result());
+                        None
+                    },
+                )?;
+                if solution.status != $solver::Status::Converged {
+                    return Err(Error::NotConverged { lambda });
+                }
+                Ok(Point {
+                    lambda,
+                    x: solution.x,
+                    residuals: solution.residuals,
+                    snapshot: solution.snapshot,
+                    iters: solution.iters,
+                })
+            }};
+        }
+
+        match self {
+            Self::Newton(config) => correct_with!(newton, config),
+            Self::Broyden(config) => correct_with!(broyden, config),
+            Self::TrustRegion(config) => correct_with!(trust_region, config),
+        }
+    }
+}
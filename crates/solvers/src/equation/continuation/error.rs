@@ -0,0 +1,76 @@
+use std::error::Error as StdError;
+
+use thiserror::Error;
+
+use crate::equation::{broyden, newton, trust_region};
+
+use super::config::ConfigError;
+
+/// Errors that can occur during continuation.
+///
+/// Errors at points after the start are not returned; they are reported to
+/// the observer and the λ step is reduced instead.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("invalid config: {0}")]
+    InvalidConfig(#[from] ConfigError),
+
+    #[error("lambda range must be finite")]
+    InvalidRange,
+
+    #[error("corrector did not converge at lambda = {lambda}")]
+    NotConverged { lambda: f64 },
+
+    #[error("singular Jacobian")]
+    SingularJacobian,
+
+    #[error("problem error")]
+    Problem(#[source] Box<dyn StdError + Send + Sync>),
+
+    #[error("model call failed")]
+    Model(#[source] Box<dyn StdError + Send + Sync>),
+}
+
+impl From<newton::Error> for Error {
+    fn from(err: newton::Error) -> Self {
+        match err {
+            newton::Error::InvalidConfig(e) => Self::InvalidConfig(ConfigError::Newton(e)),
+            newton::Error::SingularJacobian => Self::SingularJacobian,
+            newton::Error::Problem(e) => Self::Problem(e),
+            newton::Error::Model(e) => Self::Model(e),
+            newton::Error::NoSuccessfulEvaluation => {
+                unreachable!("the corrector is unobserved and cannot stop early")
+            }
+        }
+    }
+}
+
+impl From<broyden::Error> for Error {
+    fn from(err: broyden::Error) -> Self {
+        match err {
+            broyden::Error::InvalidConfig(e) => Self::InvalidConfig(ConfigError::Broyden(e)),
+            broyden::Error::SingularJacobian => Self::SingularJacobian,
+            broyden::Error::Problem(e) => Self::Problem(e),
+            broyden::Error::Model(e) => Self::Model(e),
+            broyden::Error::NoSuccessfulEvaluation => {
+                unreachable!("the corrector is unobserved and cannot stop early")
+            }
+        }
+    }
+}
+
+impl From<trust_region::Error> for Error {
+    fn from(err: trust_region::Error) -> Self {
+        match err {
+            trust_region::Error::InvalidConfig(e) => {
+                Self::InvalidConfig(ConfigError::TrustRegion(e))
+            }
+            trust_region::Error::SingularJacobian => Self::SingularJacobian,
+            trust_region::Error::Problem(e) => Self::Problem(e),
+            trust_region::Error::Model(e) => Self::Model(e),
+            trust_region::Error::NoSuccessfulEvaluation => {
+                unreachable!("the corrector is unobserved and cannot stop early")
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use twine_core::Model;
+
+use super::{Error, Point};
+
+/// Event emitted by the continuation driver after each corrector run.
+///
+/// Evaluations inside each corrector run are not observed.
+pub enum Event<'a, M, const N: usize>
+where
+    M: Model,
+{
+    /// The corrector converged at `lambda`.
+    ///
+    /// The point joins the path unless the observer rejects it.
+    Converged {
+        /// The converged point.
+        point: &'a Point<M::Input, M::Output, N>,
+    },
+    /// The corrector failed at `lambda`; the λ step will be reduced.
+    ///
+    /// A failure at the starting λ ends the run with this error.
+    Failed {
+        /// The λ value that failed.
+        lambda: f64,
+        /// Why the corrector failed.
+        error: &'a Error,
+    },
+}
+
+impl<M, const N: usize> Event<'_, M, N>
+where
+    M: Model,
+{
+    /// Returns the λ value of the corrector run.
+    #[must_use]
+    pub fn lambda(&self) -> f64 {
+        match self {
+            Event::Converged { point } => point.lambda,
+            Event::Failed { lambda, .. } => *lambda,
+        }
+    }
+}
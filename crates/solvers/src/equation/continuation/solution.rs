@@ -0,0 +1,47 @@
+use twine_core::Snapshot;
+
+use crate::Stats;
+
+/// Indicates whether the path reached the final λ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The path reached the final λ.
+    Completed,
+    /// The λ step shrank below `min_step` without the corrector converging.
+    StepTooSmall,
+    /// Reached the corrector run limit before the final λ.
+    MaxSteps,
+    /// Stopped early due to an observer decision.
+    StoppedByObserver,
+}
+
+/// A converged point on the solution path.
+#[derive(Debug, Clone)]
+pub struct Point<I, O, const N: usize> {
+    /// The λ value of the problem solved.
+    pub lambda: f64,
+    /// The root at `lambda`.
+    pub x: [f64; N],
+    /// Residuals at the root.
+    pub residuals: [f64; N],
+    /// Snapshot at the root.
+    pub snapshot: Snapshot<I, O>,
+    /// Corrector iterations used to converge this point.
+    pub iters: usize,
+}
+
+/// The result of a continuation run.
+#[derive(Debug, Clone)]
+pub struct Solution<I, O, const N: usize> {
+    /// Final status.
+    pub status: Status,
+    /// Converged points in order of λ, starting at the initial λ.
+    ///
+    /// The path is never empty; its last point is the furthest λ reached.
+    pub path: Vec<Point<I, O, N>>,
+    /// Number of corrector runs after the starting point, including failures.
+    pub steps: usize,
+    /// Model evaluations and time spent across every corrector run, including
+    /// failed and rejected ones.
+    pub stats: Stats,
+}
@@ -56,3 +56,23 @@ where
         snapshot: Snapshot::new(input, output),
     })
 }
+
+/// Evaluates the model at each `x` in `xs` across threads.
+///
+/// Results are always in the same order as `xs`.
+#[cfg(feature = "parallel")]
+pub fn evaluate_batch<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    xs: &[[f64; N]],
+) -> Vec<EvaluateResult<M, P, N>>
+where
+    M: Model + Sync,
+    M::Input: Send,
+    M::Output: Send,
+    M::Error: Send,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output> + Sync,
+    P::Error: Send,
+{
+    crate::parallel::map(xs, |&x| evaluate(model, problem, x))
+}
@@ -0,0 +1,423 @@
+//! Newton's method for square systems of equations.
+//!
+//! # Algorithm
+//!
+//! Newton's method linearizes the residuals around the current iterate and
+//! solves for the step that drives the linearization to zero:
+//!
+//! ```text
+//! J(x_k) · Δx = -r(x_k)
+//! x_{k+1} = x_k + Δx
+//! ```
+//!
+//! The Jacobian `J` is approximated by finite differences through
+//! [`evaluate`](crate::equation::evaluate), so the problem only needs to provide
+//! residuals. Forward differences cost `N` extra evaluations per iteration and
+//! central differences cost `2N`.
+//!
+//! # When to Use
+//!
+//! Newton's method is appropriate when:
+//! - The system is square (`N` residuals in `N` unknowns)
+//! - The residuals are smooth near the solution
+//! - A reasonable initial guess is available
+//!
+//! Convergence is quadratic near a simple root, but the undamped iteration can
+//! diverge from a poor initial guess.
+//!
+//! # Observer Events
+//!
+//! The solver emits one [`Event`] per evaluation:
+//!
+//! - [`Event::Iterate`] — evaluation at a Newton iterate (iteration 0 is the
+//!   initial guess)
+//! - [`Event::Jacobian`] — perturbed evaluation for a Jacobian column
+//!
+//! Observers can return [`Action::StopEarly`] to halt and return the best
+//! iterate found so far.
+
+mod action;
+pub(crate) mod best;
+mod config;
+mod error;
+pub(crate) mod eval_context;
+mod event;
+pub(crate) mod jacobian;
+mod solution;
+
+pub use action::Action;
+pub use config::{Config, ConfigError};
+pub use error::Error;
+pub use event::Event;
+pub use jacobian::Difference;
+pub use solution::{Solution, Status};
+
+use twine_core::{EquationProblem, Model, Observer};
+
+use crate::linalg;
+
+use best::Best;
+use eval_context::{EvalContext, Outcome};
+
+/// Finds a root of a square system of equations using Newton's method.
+///
+/// # Algorithm
+///
+/// 1. Evaluate the initial guess.
+/// 2. Iterate: approximate the Jacobian by finite differences, solve for the
+///    Newton step, and evaluate the new iterate.
+///
+/// Convergence is reported when either:
+/// - Every residual magnitude of the best iterate is within `config.residual_tol`, or
+/// - The step satisfies `max|Δx| <= x_abs_tol + x_rel_tol * max|x|`.
+///
+/// # Observer
+///
+/// The observer receives an [`Event`] for each evaluation and may return
+/// `Action::StopEarly` to stop and return the best iterate so far.
+///
+/// # Notes
+///
+/// The returned [`Solution`] reflects the best iterate seen (by largest
+/// residual magnitude). Perturbed Jacobian evaluations are not considered.
+/// Iteration counts correspond to the number of Newton steps taken.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, the Jacobian is singular,
+/// or the model or problem returns an error during evaluation.
+pub fn solve<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    mut observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    config.validate()?;
+
+    let mut best = Best::empty();
+    let mut ctx = EvalContext::new(model, problem, &mut observer);
+
+    // Evaluate the initial guess.
+    let mut x = x0;
+    let mut r = match ctx.iterate(0, x, &mut best)? {
+        Outcome::Continue(r) => r,
+        Outcome::StopEarly => return best.finish(Status::StoppedByObserver, 0, ctx.stats()),
+    };
+
+    if best.is_residual_converged(config.residual_tol) {
+        return best.finish(Status::Converged, 0, ctx.stats());
+    }
+
+    for iter in 1..=config.max_iters {
+        let jacobian = match ctx.jacobian(&x, &r, config.difference, config.fd_step)? {
+            Outcome::Continue(jacobian) => jacobian,
+            Outcome::StopEarly => {
+                return best.finish(Status::StoppedByObserver, iter - 1, ctx.stats());
+            }
+        };
+
+        // Solve J · Δx = -r for the Newton step.
+        let step = linalg::solve(jacobian, r.map(|v| -v)).ok_or(Error::SingularJacobian)?;
+        x = std::array::from_fn(|i| x[i] + step[i]);
+
+        r = match ctx.iterate(iter, x, &mut best)? {
+            Outcome::Continue(r) => r,
+            Outcome::StopEarly => return best.finish(Status::StoppedByObserver, iter, ctx.stats()),
+        };
+
+        if best.is_residual_converged(config.residual_tol)
+            || linalg::is_step_converged(&step, &x, config.x_abs_tol, config.x_rel_tol)
+        {
+            return best.finish(Status::Converged, iter, ctx.stats());
+        }
+    }
+
+    best.finish(Status::MaxIters, config.max_iters, ctx.stats())
+}
+
+/// Runs Newton's method without observation.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, the Jacobian is singular,
+/// or the model or problem returns an error during evaluation.
+pub fn solve_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    solve(model, problem, x0, config, ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use thiserror::Error;
+
+    /// Passes solver variables straight through as the model output.
+    struct Identity;
+    impl Model for Identity {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(*input)
+        }
+    }
+
+    /// Intersection of the circle `x² + y² = 4` with the curve `y = x³`.
+    struct CircleCubic;
+    impl EquationProblem<2> for CircleCubic {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<Self::Input, Self::Error> {
+            Ok(*x)
+        }
+
+        fn residuals(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<[f64; 2], Self::Error> {
+            let [x, y] = *output;
+            Ok([x * x + y * y - 4.0, y - x * x * x])
+        }
+    }
+
+    /// Model that squares its input.
+    struct SquareModel;
+    impl Model for SquareModel {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(input * input)
+        }
+    }
+
+    /// Drives the model output to a target value.
+    struct TargetOutputProblem {
+        target: f64,
+    }
+    impl EquationProblem<1> for TargetOutputProblem {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+            Ok(x[0])
+        }
+
+        fn residuals(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<[f64; 1], Self::Error> {
+            Ok([output - self.target])
+        }
+    }
+
+    /// Model that fails for negative inputs.
+    struct NonNegativeModel;
+    #[derive(Debug, Error)]
+    #[error("negative input: {0}")]
+    struct NegativeInput(f64);
+    impl Model for NonNegativeModel {
+        type Input = f64;
+        type Output = f64;
+        type Error = NegativeInput;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            if *input < 0.0 {
+                Err(NegativeInput(*input))
+            } else {
+                Ok(input * input)
+            }
+        }
+    }
+
+    /// Model that squares its input, but returns NaN above 2.
+    struct NanAboveTwo;
+    impl Model for NanAboveTwo {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(if *input > 2.0 {
+                f64::NAN
+            } else {
+                input * input
+            })
+        }
+    }
+
+    #[test]
+    fn solves_scalar_equation() {
+        let solution = solve_unobserved(
+            &SquareModel,
+            &TargetOutputProblem { target: 9.0 },
+            [1.0],
+            &Config::default(),
+        )
+        .expect("should solve");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 3.0, epsilon = 1e-10);
+        assert_relative_eq!(solution.snapshot.output, 9.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn nan_residuals_never_converge() {
+        // The first Newton step from x = 1 lands at x = 5, where the model
+        // returns NaN.
+        let result = solve_unobserved(
+            &NanAboveTwo,
+            &TargetOutputProblem { target: 9.0 },
+            [1.0],
+            &Config::default(),
+        );
+
+        assert!(
+            !matches!(result, Ok(ref solution) if solution.status == Status::Converged),
+            "NaN residuals must not be reported as converged: {result:?}"
+        );
+    }
+
+    #[test]
+    fn solves_two_equation_system() {
+        let solution = solve_unobserved(&Identity, &CircleCubic, [1.0, 1.0], &Config::default())
+            .expect("should solve");
+
+        assert_eq!(solution.status, Status::Converged);
+        let [x, y] = solution.x;
+        assert_relative_eq!(x * x + y * y, 4.0, epsilon = 1e-10);
+        assert_relative_eq!(y, x.powi(3), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn central_differences_converge() {
+        let config = Config {
+            difference: Difference::Central,
+            fd_step: 1e-6,
+            ..Config::default()
+        };
+
+        let solution =
+            solve_unobserved(&Identity, &CircleCubic, [1.0, 1.0], &config).expect("should solve");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert!(linalg::max_abs(&solution.residuals) <= 1e-10);
+    }
+
+    #[test]
+    fn emits_jacobian_events_per_column() {
+        let mut jacobian_columns = Vec::new();
+        let mut iterates = 0usize;
+
+        let observer = |event: &Event<'_, _, _, 2>| {
+            match event {
+                Event::Iterate { .. } => iterates += 1,
+                Event::Jacobian { column, .. } => jacobian_columns.push(*column),
+            }
+            (iterates == 2).then_some(Action::StopEarly)
+        };
+
+        let solution = solve(
+            &Identity,
+            &CircleCubic,
+            [1.0, 1.0],
+            &Config::default(),
+            observer,
+        )
+        .expect("should stop cleanly");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.iters, 1);
+        assert_eq!(jacobian_columns, vec![0, 1]);
+    }
+
+    #[test]
+    fn singular_jacobian_is_an_error() {
+        // d(x²)/dx = 0 at x = 0.
+        let result = solve_unobserved(
+            &SquareModel,
+            &TargetOutputProblem { target: 9.0 },
+            [0.0],
+            &Config {
+                fd_step: 1e-300,
+                ..Config::default()
+            },
+        );
+
+        assert!(matches!(result, Err(Error::SingularJacobian)));
+    }
+
+    #[test]
+    fn model_failure_is_an_error() {
+        // The initial guess is outside the model's valid domain.
+        let result = solve_unobserved(
+            &NonNegativeModel,
+            &TargetOutputProblem { target: 9.0 },
+            [-1.0],
+            &Config::default(),
+        );
+
+        assert!(matches!(result, Err(Error::Model(_))));
+    }
+
+    #[test]
+    fn stop_on_failed_initial_guess_has_no_solution() {
+        let observer = |_: &Event<'_, _, _, 1>| Some(Action::StopEarly);
+
+        let result = solve(
+            &NonNegativeModel,
+            &TargetOutputProblem { target: 9.0 },
+            [-1.0],
+            &Config::default(),
+            observer,
+        );
+
+        assert!(matches!(result, Err(Error::NoSuccessfulEvaluation)));
+    }
+
+    #[test]
+    fn zero_iters_returns_initial_guess() {
+        let config = Config {
+            max_iters: 0,
+            ..Config::default()
+        };
+
+        let solution = solve_unobserved(
+            &SquareModel,
+            &TargetOutputProblem { target: 9.0 },
+            [2.0],
+            &config,
+        )
+        .expect("should return initial guess");
+
+        assert_eq!(solution.status, Status::MaxIters);
+        assert_eq!(solution.iters, 0);
+        assert_relative_eq!(solution.x[0], 2.0);
+        assert_relative_eq!(solution.residuals[0], -5.0);
+    }
+}
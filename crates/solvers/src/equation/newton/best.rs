@@ -0,0 +1,118 @@
+use crate::{equation::Evaluation, linalg, stats::Stats};
+
+use super::{Error, Solution, Status};
+
+/// Tracks the best evaluation encountered so far.
+///
+/// The best evaluation is defined by the smallest largest-magnitude residual.
+/// The `Option` lets us represent the state before any successful evaluation.
+pub(crate) struct Best<I, O, const N: usize> {
+    eval: Option<Evaluation<I, O, N>>,
+}
+
+impl<I, O, const N: usize> Best<I, O, N> {
+    /// Creates an empty best tracker.
+    pub(crate) fn empty() -> Self {
+        Self { eval: None }
+    }
+
+    /// Updates the best evaluation if the residual norm improves.
+    ///
+    /// Evaluations with a non-finite residual norm are never kept.
+    pub(crate) fn update(&mut self, eval: Evaluation<I, O, N>) {
+        let norm = linalg::max_abs(&eval.residuals);
+        if !norm.is_finite() {
+            return;
+        }
+        if let Some(best) = self.eval.as_ref()
+            && norm >= linalg::max_abs(&best.residuals)
+        {
+            return;
+        }
+        self.eval = Some(eval);
+    }
+
+    /// Returns the best evaluation, if any.
+    pub(crate) fn get(&self) -> Option<&Evaluation<I, O, N>> {
+        self.eval.as_ref()
+    }
+
+    /// Returns true if every residual of the best evaluation meets the tolerance.
+    pub(crate) fn is_residual_converged(&self, residual_tol: f64) -> bool {
+        self.eval
+            .as_ref()
+            .is_some_and(|eval| linalg::max_abs(&eval.residuals) <= residual_tol)
+    }
+
+    /// Finalizes the solver using the best available evaluation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NoSuccessfulEvaluation` if no successful evaluation is stored.
+    #[allow(clippy::similar_names)]
+    pub(crate) fn finish(
+        self,
+        status: Status,
+        iters: usize,
+        stats: Stats,
+    ) -> Result<Solution<I, O, N>, Error> {
+        let eval = self.eval.ok_or(Error::NoSuccessfulEvaluation)?;
+        Ok(Solution {
+            status,
+            x: eval.x,
+            residuals: eval.residuals,
+            snapshot: eval.snapshot,
+            iters,
+            stats,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use twine_core::Snapshot;
+
+    fn eval(x: [f64; 2], residuals: [f64; 2]) -> Evaluation<(), (), 2> {
+        Evaluation {
+            x,
+            residuals,
+            snapshot: Snapshot::new((), ()),
+        }
+    }
+
+    #[test]
+    fn update_keeps_smallest_max_residual() {
+        let mut best = Best::empty();
+        best.update(eval([1.0, 1.0], [2.0, 0.0]));
+        best.update(eval([2.0, 2.0], [0.5, -0.75]));
+        best.update(eval([3.0, 3.0], [0.0, 1.0]));
+
+        let solution = best
+            .finish(Status::Converged, 2, Stats::default())
+            .expect("best eval");
+
+        assert_relative_eq!(solution.x[0], 2.0);
+        assert_relative_eq!(solution.residuals[1], -0.75);
+        assert_eq!(solution.iters, 2);
+    }
+
+    #[test]
+    fn residual_converged_checks_every_component() {
+        let mut best = Best::empty();
+        best.update(eval([0.0, 0.0], [1e-4, 1e-2]));
+
+        assert!(!best.is_residual_converged(1e-3));
+        assert!(best.is_residual_converged(1e-1));
+    }
+
+    #[test]
+    fn finish_errors_without_eval() {
+        let best: Best<(), (), 2> = Best::empty();
+        let err = best.finish(Status::StoppedByObserver, 0, Stats::default());
+        assert!(matches!(err, Err(Error::NoSuccessfulEvaluation)));
+    }
+}
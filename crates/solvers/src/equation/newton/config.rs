@@ -0,0 +1,105 @@
+use thiserror::Error;
+
+use super::jacobian::Difference;
+
+/// Configuration for the Newton solver.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    pub max_iters: usize,
+    pub x_abs_tol: f64,
+    pub x_rel_tol: f64,
+    pub residual_tol: f64,
+    /// Finite difference scheme used to approximate the Jacobian.
+    pub difference: Difference,
+    /// Relative perturbation size for finite differences.
+    ///
+    /// Each variable is perturbed by `fd_step * max(|x_j|, 1)`.
+    pub fd_step: f64,
+}
+
+/// Errors that can occur when validating a Newton config.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("x_abs_tol must be finite and non-negative")]
+    XAbs,
+
+    #[error("x_rel_tol must be finite and non-negative")]
+    XRel,
+
+    #[error("residual_tol must be finite and non-negative")]
+    Residual,
+
+    #[error("fd_step must be finite and positive")]
+    FdStep,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_iters: 50,
+            x_abs_tol: 1e-12,
+            x_rel_tol: 1e-12,
+            residual_tol: 1e-12,
+            difference: Difference::Forward,
+            fd_step: f64::EPSILON.sqrt(),
+        }
+    }
+}
+
+impl Config {
+    /// Validates that all tolerances and the finite difference step are usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any tolerance is negative or non-finite,
+    /// or if `fd_step` is not finite and positive.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !self.x_abs_tol.is_finite() || self.x_abs_tol < 0.0 {
+            return Err(ConfigError::XAbs);
+        }
+        if !self.x_rel_tol.is_finite() || self.x_rel_tol < 0.0 {
+            return Err(ConfigError::XRel);
+        }
+        if !self.residual_tol.is_finite() || self.residual_tol < 0.0 {
+            return Err(ConfigError::Residual);
+        }
+        if !self.fd_step.is_finite() || self.fd_step <= 0.0 {
+            return Err(ConfigError::FdStep);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_bad_fd_step() {
+        let config = Config {
+            fd_step: 0.0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::FdStep));
+
+        let config = Config {
+            fd_step: f64::NAN,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::FdStep));
+    }
+
+    #[test]
+    fn rejects_negative_tolerances() {
+        let config = Config {
+            residual_tol: -1.0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Residual));
+    }
+}
@@ -0,0 +1,121 @@
+use twine_core::{EquationProblem, Model, Observer};
+
+use crate::{
+    equation::evaluate,
+    stats::{Stats, Tally},
+};
+
+use super::{Action, Error, Event, best::Best, jacobian};
+
+/// Whether the solver should keep going after an observed evaluation.
+pub(crate) enum Outcome<T> {
+    Continue(T),
+    StopEarly,
+}
+
+/// Bundles evaluation and observation for a single Newton-type solve.
+///
+/// This keeps event emission and action handling in one place while leaving the
+/// solver loop to focus on control flow. An observer request to stop always
+/// takes precedence over an evaluation error.
+pub(crate) struct EvalContext<'ctx, M, P, Obs> {
+    model: &'ctx M,
+    problem: &'ctx P,
+    observer: &'ctx mut Obs,
+    tally: Tally,
+}
+
+impl<'ctx, M, P, Obs> EvalContext<'ctx, M, P, Obs>
+where
+    M: Model,
+{
+    /// Creates a new evaluation context.
+    pub(crate) fn new(model: &'ctx M, problem: &'ctx P, observer: &'ctx mut Obs) -> Self {
+        Self {
+            model,
+            problem,
+            observer,
+            tally: Tally::start(),
+        }
+    }
+
+    /// Returns the cost of the solve so far.
+    pub(crate) fn stats(&self) -> Stats {
+        self.tally.stats()
+    }
+
+    /// Evaluates an iterate, updates `best`, and returns its residuals.
+    pub(crate) fn iterate<const N: usize>(
+        &mut self,
+        iter: usize,
+        x: [f64; N],
+        best: &mut Best<M::Input, M::Output, N>,
+    ) -> Result<Outcome<[f64; N]>, Error>
+    where
+        P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+        Obs: for<'evt> Observer<Event<'evt, M, P, N>, Action>,
+    {
+        let result = evaluate(self.model, self.problem, x);
+        self.tally.evaluated(&result);
+        let action = self.observer.observe(&Event::Iterate {
+            iter,
+            x,
+            result: &result,
+        });
+        self.tally.observed(action.as_ref());
+
+        match (action, result) {
+            (Some(Action::StopEarly), Ok(eval)) => {
+                best.update(eval);
+                Ok(Outcome::StopEarly)
+            }
+            (Some(Action::StopEarly), Err(_)) => Ok(Outcome::StopEarly),
+            (None, Ok(eval)) => {
+                let residuals = eval.residuals;
+                best.update(eval);
+                Ok(Outcome::Continue(residuals))
+            }
+            (None, Err(error)) => Err(error.into()),
+        }
+    }
+
+    /// Approximates the Jacobian at `x` by finite differences.
+    ///
+    /// `r` must hold the residuals at `x`.
+    /// Perturbed evaluations are observed but never considered for the best
+    /// solution, since they are not iterates.
+    pub(crate) fn jacobian<const N: usize>(
+        &mut self,
+        x: &[f64; N],
+        r: &[f64; N],
+        difference: jacobian::Difference,
+        fd_step: f64,
+    ) -> Result<Outcome<[[f64; N]; N]>, Error>
+    where
+        P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+        Obs: for<'evt> Observer<Event<'evt, M, P, N>, Action>,
+    {
+        let points = jacobian::perturbations(x, difference, fd_step);
+        let mut residuals = Vec::with_capacity(points.len());
+
+        for point in &points {
+            let result = evaluate(self.model, self.problem, point.x);
+            self.tally.evaluated(&result);
+            let action = self.observer.observe(&Event::Jacobian {
+                column: point.column,
+                x: point.x,
+                result: &result,
+            });
+            self.tally.observed(action.as_ref());
+
+            if let Some(Action::StopEarly) = action {
+                return Ok(Outcome::StopEarly);
+            }
+            residuals.push(result?.residuals);
+        }
+
+        Ok(Outcome::Continue(jacobian::assemble(
+            x, r, difference, &points, &residuals,
+        )))
+    }
+}
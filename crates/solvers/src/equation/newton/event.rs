@@ -0,0 +1,55 @@
+use twine_core::{EquationProblem, Model};
+
+use crate::equation::EvaluateResult;
+
+/// Event emitted by the Newton solver for each evaluation.
+///
+/// [`broyden`](crate::equation::broyden) emits the same events, with
+/// [`Event::Jacobian`] only when it builds a Jacobian by finite differences.
+pub enum Event<'a, M, P, const N: usize>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    /// Evaluation at a Newton iterate.
+    ///
+    /// Iteration 0 is the initial guess.
+    Iterate {
+        /// The iteration that produced this point.
+        iter: usize,
+        /// The x value that was evaluated.
+        x: [f64; N],
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, N>,
+    },
+    /// Perturbed evaluation used to approximate a Jacobian column.
+    Jacobian {
+        /// The Jacobian column (solver variable) being perturbed.
+        column: usize,
+        /// The x value that was evaluated.
+        x: [f64; N],
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, N>,
+    },
+}
+
+impl<'a, M, P, const N: usize> Event<'a, M, P, N>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    /// Returns the evaluated x value.
+    #[must_use]
+    pub fn x(&self) -> [f64; N] {
+        match self {
+            Event::Iterate { x, .. } | Event::Jacobian { x, .. } => *x,
+        }
+    }
+
+    /// Returns the evaluation result.
+    pub fn result(&self) -> &'a EvaluateResult<M, P, N> {
+        match self {
+            Event::Iterate { result, .. } | Event::Jacobian { result, .. } => result,
+        }
+    }
+}
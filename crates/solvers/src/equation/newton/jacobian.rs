@@ -0,0 +1,149 @@
+/// Finite difference scheme used to approximate a Jacobian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difference {
+    /// One perturbed evaluation per column, reusing the residuals at `x`.
+    ///
+    /// First-order accurate and costs `N` evaluations.
+    Forward,
+    /// Two perturbed evaluations per column, one on each side of `x`.
+    ///
+    /// Second-order accurate and costs `2N` evaluations.
+    Central,
+}
+
+/// A perturbed point to evaluate for one Jacobian column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Perturbation<const N: usize> {
+    /// The Jacobian column (solver variable) being perturbed.
+    pub(crate) column: usize,
+    /// The perturbed point.
+    pub(crate) x: [f64; N],
+}
+
+/// Returns the points needed to approximate the Jacobian at `x`.
+///
+/// Perturbations are ordered by column; central differences emit the forward
+/// point before the backward point for each column.
+pub(crate) fn perturbations<const N: usize>(
+    x: &[f64; N],
+    difference: Difference,
+    fd_step: f64,
+) -> Vec<Perturbation<N>> {
+    let mut points = Vec::with_capacity(match difference {
+        Difference::Forward => N,
+        Difference::Central => 2 * N,
+    });
+
+    for column in 0..N {
+        let h = fd_step * x[column].abs().max(1.0);
+
+        let mut forward = *x;
+        forward[column] += h;
+        points.push(Perturbation { column, x: forward });
+
+        if difference == Difference::Central {
+            let mut backward = *x;
+            backward[column] -= h;
+            points.push(Perturbation {
+                column,
+                x: backward,
+            });
+        }
+    }
+
+    points
+}
+
+/// Assembles a Jacobian from residuals evaluated at [`perturbations`].
+///
+/// `residuals` must be in the same order as the perturbations, and `r` must
+/// hold the residuals at the unperturbed `x`. The returned matrix is row-major,
+/// so `jacobian[i][j]` is the derivative of residual `i` with respect to `x[j]`.
+///
+/// The number of residuals `M` may differ from the number of variables `N`,
+/// as in least-squares problems.
+pub(crate) fn assemble<const N: usize, const M: usize>(
+    x: &[f64; N],
+    r: &[f64; M],
+    difference: Difference,
+    perturbations: &[Perturbation<N>],
+    residuals: &[[f64; M]],
+) -> [[f64; N]; M] {
+    debug_assert_eq!(perturbations.len(), residuals.len());
+
+    let mut jacobian = [[0.0; N]; M];
+
+    match difference {
+        Difference::Forward => {
+            for (point, rp) in perturbations.iter().zip(residuals) {
+                let j = point.column;
+                // Use the representable step rather than the requested one.
+                let h = point.x[j] - x[j];
+                for i in 0..M {
+                    jacobian[i][j] = (rp[i] - r[i]) / h;
+                }
+            }
+        }
+        Difference::Central => {
+            for (pair, rs) in perturbations.chunks_exact(2).zip(residuals.chunks_exact(2)) {
+                let j = pair[0].column;
+                let h = pair[0].x[j] - pair[1].x[j];
+                for i in 0..M {
+                    jacobian[i][j] = (rs[0][i] - rs[1][i]) / h;
+                }
+            }
+        }
+    }
+
+    jacobian
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    /// r(x) = [x0² + x1, x0 * x1]
+    fn residuals(x: &[f64; 2]) -> [f64; 2] {
+        [x[0] * x[0] + x[1], x[0] * x[1]]
+    }
+
+    fn jacobian(x: [f64; 2], difference: Difference) -> [[f64; 2]; 2] {
+        let points = perturbations(&x, difference, 1e-6);
+        let rs: Vec<_> = points.iter().map(|p| residuals(&p.x)).collect();
+        assemble(&x, &residuals(&x), difference, &points, &rs)
+    }
+
+    #[test]
+    fn perturbation_counts() {
+        let x = [1.0, 2.0, 3.0];
+        assert_eq!(perturbations(&x, Difference::Forward, 1e-6).len(), 3);
+        assert_eq!(perturbations(&x, Difference::Central, 1e-6).len(), 6);
+    }
+
+    #[test]
+    fn step_scales_with_magnitude() {
+        let points = perturbations(&[1000.0, 0.0], Difference::Forward, 1e-6);
+        assert_relative_eq!(points[0].x[0] - 1000.0, 1e-3, epsilon = 1e-12);
+        assert_relative_eq!(points[1].x[1], 1e-6);
+    }
+
+    #[test]
+    fn forward_difference_approximates_jacobian() {
+        let j = jacobian([1.5, -2.0], Difference::Forward);
+        assert_relative_eq!(j[0][0], 3.0, epsilon = 1e-5);
+        assert_relative_eq!(j[0][1], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(j[1][0], -2.0, epsilon = 1e-5);
+        assert_relative_eq!(j[1][1], 1.5, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn central_difference_approximates_jacobian() {
+        let j = jacobian([1.5, -2.0], Difference::Central);
+        assert_relative_eq!(j[0][0], 3.0, epsilon = 1e-8);
+        assert_relative_eq!(j[0][1], 1.0, epsilon = 1e-8);
+        assert_relative_eq!(j[1][0], -2.0, epsilon = 1e-8);
+        assert_relative_eq!(j[1][1], 1.5, epsilon = 1e-8);
+    }
+}
@@ -0,0 +1,31 @@
+use twine_core::Snapshot;
+
+use crate::Stats;
+
+/// Indicates whether the solver converged or hit the iteration limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Converged according to the configured tolerances.
+    Converged,
+    /// Reached the iteration limit without converging.
+    MaxIters,
+    /// Stopped early due to an observer decision.
+    StoppedByObserver,
+}
+
+/// The result of a Newton solve.
+#[derive(Debug, Clone)]
+pub struct Solution<I, O, const N: usize> {
+    /// Final solver status.
+    pub status: Status,
+    /// Best estimate of the root.
+    pub x: [f64; N],
+    /// Residuals at the reported root estimate.
+    pub residuals: [f64; N],
+    /// Snapshot at the reported root estimate.
+    pub snapshot: Snapshot<I, O>,
+    /// Iteration count when the solver finished.
+    pub iters: usize,
+    /// Model evaluations and time spent, including Jacobian evaluations.
+    pub stats: Stats,
+}
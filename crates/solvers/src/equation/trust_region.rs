@@ -0,0 +1,516 @@
+//! Globalized Newton's method for square systems of equations.
+//!
+//! # Algorithm
+//!
+//! Each iteration approximates the Jacobian by finite differences, as in
+//! [`newton`], and proposes a step toward the root of the linearization
+//! `r + J·p`. A trial step is accepted only if it reduces the merit function
+//! `½‖r‖²` by at least [`sufficient_decrease`](Config::sufficient_decrease)
+//! times the reduction the linearization predicts. Otherwise it is replaced by
+//! a shorter one, depending on the [`Strategy`]:
+//!
+//! - [`Strategy::LineSearch`] backtracks along the Newton step.
+//! - [`Strategy::Dogleg`] keeps steps within a trust radius, moving from the
+//!   steepest-descent step toward the Newton step. The radius shrinks after
+//!   poor steps and grows after good ones.
+//!
+//! Far from the root this trades Newton's speed for steady progress; near the
+//! root full Newton steps are accepted and convergence is quadratic.
+//!
+//! # When to Use
+//!
+//! This solver is appropriate when:
+//! - The system is square (`N` residuals in `N` unknowns)
+//! - The initial guess may be poor, so undamped [`newton`] would diverge
+//! - Some steps must be vetoed by domain checks the residuals cannot express
+//!
+//! # Observer Events
+//!
+//! The solver emits one [`Event`] per evaluation:
+//!
+//! - [`Event::Initial`] — evaluation at the initial guess
+//! - [`Event::Trial`] — evaluation at a trial step
+//! - [`Event::Jacobian`] — perturbed evaluation for a Jacobian column
+//!
+//! Observers can return [`Action::StopEarly`] to halt and return the last
+//! accepted iterate, or [`Action::RejectStep`] on a trial to have the solver
+//! try a shorter step instead. Rejecting a failed trial evaluation recovers
+//! from it rather than returning an error.
+//!
+//! [`newton`]: crate::equation::newton
+
+mod action;
+mod config;
+mod error;
+mod eval_context;
+mod event;
+mod solution;
+mod step;
+
+pub use action::Action;
+pub use config::{Config, ConfigError, Strategy};
+pub use error::Error;
+pub use event::Event;
+pub use solution::{Solution, Status};
+
+pub use super::newton::Difference;
+
+use twine_core::{EquationProblem, Model, Observer};
+
+use crate::{Stats, equation::Evaluation, linalg};
+
+use eval_context::{EvalContext, Outcome, Start};
+
+/// Finds a root of a square system of equations using a globalized Newton
+/// method.
+///
+/// # Algorithm
+///
+/// 1. Evaluate the initial guess.
+/// 2. Iterate: approximate the Jacobian by finite differences, then propose
+///    trial steps until one is accepted.
+///
+/// Convergence is reported when either:
+/// - Every residual magnitude of the current iterate is within `config.residual_tol`, or
+/// - An accepted step satisfies `max|Δx| <= x_abs_tol + x_rel_tol * max|x|`.
+///
+/// The solver stalls if `config.max_rejections` consecutive trial steps are
+/// rejected, or if the Jacobian offers no descent direction (dogleg only).
+///
+/// # Observer
+///
+/// The observer receives an [`Event`] for each evaluation and may return
+/// `Action::StopEarly` to stop, or `Action::RejectStep` to reject a trial step.
+///
+/// # Notes
+///
+/// The returned [`Solution`] reflects the last accepted iterate, which has the
+/// smallest residual norm seen since every accepted step reduces it.
+/// Iteration counts correspond to the number of accepted steps.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, the Jacobian is singular under
+/// the line search strategy, or the model or problem returns an error that
+/// the observer does not recover from.
+pub fn solve<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    mut observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    config.validate()?;
+
+    let mut ctx = EvalContext::new(model, problem, &mut observer);
+
+    let mut current = match ctx.initial(x0)? {
+        Start::Continue(eval) => eval,
+        Start::StopEarly(eval) => {
+            return Ok(finish(eval, Status::StoppedByObserver, 0, ctx.stats()));
+        }
+    };
+
+    if linalg::max_abs(&current.residuals) <= config.residual_tol {
+        return Ok(finish(current, Status::Converged, 0, ctx.stats()));
+    }
+
+    let mut radius = config.initial_radius * step::norm(&x0).max(1.0);
+
+    for iter in 1..=config.max_iters {
+        let jacobian = match ctx.jacobian(
+            &current.x,
+            &current.residuals,
+            config.difference,
+            config.fd_step,
+        )? {
+            Outcome::Continue(jacobian) => jacobian,
+            Outcome::RejectStep | Outcome::StopEarly => {
+                return Ok(finish(
+                    current,
+                    Status::StoppedByObserver,
+                    iter - 1,
+                    ctx.stats(),
+                ));
+            }
+        };
+        let newton = linalg::solve(jacobian, current.residuals.map(|v| -v));
+        let current_merit = merit(&current.residuals);
+
+        let mut length = 1.0;
+        let mut rejections = 0;
+
+        let (next, step) = loop {
+            let step = match config.strategy {
+                Strategy::LineSearch => newton.ok_or(Error::SingularJacobian)?.map(|v| length * v),
+                Strategy::Dogleg => {
+                    if let Some(step) = step::dogleg(&jacobian, &current.residuals, newton, radius)
+                    {
+                        step
+                    } else {
+                        return Ok(finish(current, Status::Stalled, iter - 1, ctx.stats()));
+                    }
+                }
+            };
+
+            let x = std::array::from_fn(|i| current.x[i] + step[i]);
+            let (accepted, ratio) = match ctx.trial(iter, x, step)? {
+                Outcome::Continue(eval) => {
+                    let predicted = step::predicted_reduction(&jacobian, &current.residuals, &step);
+                    let actual = current_merit - merit(&eval.residuals);
+                    let is_accepted =
+                        actual > 0.0 && actual >= config.sufficient_decrease * predicted;
+                    (is_accepted.then_some(eval), actual / predicted)
+                }
+                Outcome::RejectStep => (None, f64::NAN),
+                Outcome::StopEarly => {
+                    return Ok(finish(
+                        current,
+                        Status::StoppedByObserver,
+                        iter - 1,
+                        ctx.stats(),
+                    ));
+                }
+            };
+
+            if config.strategy == Strategy::Dogleg {
+                radius = next_radius(radius, &step, ratio, config.step_reduction);
+            }
+
+            if let Some(next) = accepted {
+                break (next, step);
+            }
+
+            rejections += 1;
+            if rejections >= config.max_rejections {
+                return Ok(finish(current, Status::Stalled, iter - 1, ctx.stats()));
+            }
+            length *= config.step_reduction;
+        };
+
+        current = next;
+
+        if linalg::max_abs(&current.residuals) <= config.residual_tol
+            || linalg::is_step_converged(&step, &current.x, config.x_abs_tol, config.x_rel_tol)
+        {
+            return Ok(finish(current, Status::Converged, iter, ctx.stats()));
+        }
+    }
+
+    Ok(finish(
+        current,
+        Status::MaxIters,
+        config.max_iters,
+        ctx.stats(),
+    ))
+}
+
+/// Runs the trust-region solver without observation.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, the Jacobian is singular under
+/// the line search strategy, or the model or problem returns an error during
+/// evaluation.
+pub fn solve_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    solve(model, problem, x0, config, ())
+}
+
+/// Returns the merit function `½‖r‖²`.
+fn merit<const N: usize>(r: &[f64; N]) -> f64 {
+    0.5 * linalg::dot(r, r)
+}
+
+/// Updates the trust radius from the ratio of actual to predicted reduction.
+///
+/// A `NaN` ratio (a rejected or non-finite trial) shrinks the radius.
+fn next_radius<const N: usize>(radius: f64, step: &[f64; N], ratio: f64, reduction: f64) -> f64 {
+    let step_norm = step::norm(step);
+    let is_poor = ratio.is_nan() || ratio < 0.25;
+    let is_good = ratio > 0.75 && step_norm >= 0.99 * radius;
+
+    if is_poor {
+        reduction * step_norm
+    } else if is_good {
+        2.0 * radius
+    } else {
+        radius
+    }
+}
+
+/// Builds the solution from the reported iterate.
+#[allow(clippy::similar_names)]
+fn finish<I, O, const N: usize>(
+    eval: Evaluation<I, O, N>,
+    status: Status,
+    iters: usize,
+    stats: Stats,
+) -> Solution<I, O, N> {
+    Solution {
+        status,
+        x: eval.x,
+        residuals: eval.residuals,
+        snapshot: eval.snapshot,
+        iters,
+        stats,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use thiserror::Error;
+
+    use crate::equation::newton;
+
+    const STRATEGIES: [Strategy; 2] = [Strategy::LineSearch, Strategy::Dogleg];
+
+    /// Passes solver variables straight through as the model output.
+    struct Identity;
+    impl Model for Identity {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(*input)
+        }
+    }
+
+    /// Intersection of the circle `x² + y² = 4` with the curve `y = x³`.
+    struct CircleCubic;
+    impl EquationProblem<2> for CircleCubic {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<Self::Input, Self::Error> {
+            Ok(*x)
+        }
+
+        fn residuals(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<[f64; 2], Self::Error> {
+            let [x, y] = *output;
+            Ok([x * x + y * y - 4.0, y - x * x * x])
+        }
+    }
+
+    /// Model that returns the arctangent of its input.
+    ///
+    /// Undamped Newton diverges on `atan(x) = 0` when `|x0| > 1.39`.
+    struct Arctan;
+    impl Model for Arctan {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(input.atan())
+        }
+    }
+
+    /// Model that squares its input.
+    struct SquareModel;
+    impl Model for SquareModel {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(input * input)
+        }
+    }
+
+    /// Model that takes the square root, failing for negative inputs.
+    struct SqrtModel;
+    #[derive(Debug, Error)]
+    #[error("negative input: {0}")]
+    struct NegativeInput(f64);
+    impl Model for SqrtModel {
+        type Input = f64;
+        type Output = f64;
+        type Error = NegativeInput;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            if *input < 0.0 {
+                Err(NegativeInput(*input))
+            } else {
+                Ok(input.sqrt())
+            }
+        }
+    }
+
+    /// Drives the model output to a target value.
+    struct TargetOutputProblem {
+        target: f64,
+    }
+    impl EquationProblem<1> for TargetOutputProblem {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+            Ok(x[0])
+        }
+
+        fn residuals(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<[f64; 1], Self::Error> {
+            Ok([output - self.target])
+        }
+    }
+
+    fn config(strategy: Strategy) -> Config {
+        Config {
+            strategy,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn solves_two_equation_system() {
+        for strategy in STRATEGIES {
+            let solution = solve_unobserved(&Identity, &CircleCubic, [1.0, 1.0], &config(strategy))
+                .expect("should solve");
+
+            assert_eq!(solution.status, Status::Converged, "{strategy:?}");
+            let [x, y] = solution.x;
+            assert_relative_eq!(x * x + y * y, 4.0, epsilon = 1e-10);
+            assert_relative_eq!(y, x.powi(3), epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn converges_where_newton_diverges() {
+        let problem = TargetOutputProblem { target: 0.0 };
+
+        let newton =
+            newton::solve_unobserved(&Arctan, &problem, [10.0], &newton::Config::default());
+        assert!(!matches!(newton, Ok(ref s) if s.status == newton::Status::Converged));
+
+        for strategy in STRATEGIES {
+            let solution = solve_unobserved(&Arctan, &problem, [10.0], &config(strategy))
+                .expect("should solve");
+
+            assert_eq!(solution.status, Status::Converged, "{strategy:?}");
+            assert_relative_eq!(solution.x[0], 0.0, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn observer_can_reject_steps() {
+        // The Newton step from 1 lands on 5; the observer caps x at 4.
+        for strategy in STRATEGIES {
+            let mut rejected = 0;
+
+            let solution = solve(
+                &SquareModel,
+                &TargetOutputProblem { target: 9.0 },
+                [1.0],
+                &Config {
+                    initial_radius: 10.0,
+                    ..config(strategy)
+                },
+                |event: &Event<'_, _, _, 1>| match event {
+                    Event::Trial { x, .. } if x[0] > 4.0 => {
+                        rejected += 1;
+                        Some(Action::RejectStep)
+                    }
+                    _ => None,
+                },
+            )
+            .expect("should solve");
+
+            assert_eq!(solution.status, Status::Converged, "{strategy:?}");
+            assert_relative_eq!(solution.x[0], 3.0, epsilon = 1e-10);
+            assert!(rejected > 0, "{strategy:?}");
+        }
+    }
+
+    #[test]
+    fn rejecting_failed_trials_recovers() {
+        // The Newton step from 9 lands on -3, outside the model's domain.
+        let problem = TargetOutputProblem { target: 1.0 };
+        let config = config(Strategy::LineSearch);
+
+        let result = solve_unobserved(&SqrtModel, &problem, [9.0], &config);
+        assert!(matches!(result, Err(Error::Model(_))));
+
+        let solution = solve(
+            &SqrtModel,
+            &problem,
+            [9.0],
+            &config,
+            |event: &Event<'_, _, _, 1>| {
+                (matches!(event, Event::Trial { .. }) && event.result().is_err())
+                    .then_some(Action::RejectStep)
+            },
+        )
+        .expect("should solve");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn stalls_at_a_residual_minimum() {
+        // x² = -1 has no root; ½‖r‖² is smallest at x = 0.
+        for strategy in STRATEGIES {
+            let solution = solve_unobserved(
+                &SquareModel,
+                &TargetOutputProblem { target: -1.0 },
+                [1.0],
+                &config(strategy),
+            )
+            .expect("should stall cleanly");
+
+            assert_eq!(solution.status, Status::Stalled, "{strategy:?}");
+            assert_relative_eq!(solution.x[0], 0.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn observer_can_stop_early() {
+        let mut trials = 0;
+
+        let solution = solve(
+            &Identity,
+            &CircleCubic,
+            [1.0, 1.0],
+            &Config::default(),
+            |event: &Event<'_, _, _, 2>| {
+                if let Event::Trial { .. } = event {
+                    trials += 1;
+                }
+                (trials == 2).then_some(Action::StopEarly)
+            },
+        )
+        .expect("should stop cleanly");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.iters, 1);
+    }
+}
@@ -0,0 +1,16 @@
+/// Control actions supported by the trust-region solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Stop the solver early and return the last accepted iterate.
+    StopEarly,
+
+    /// Reject the trial step and try a shorter one.
+    ///
+    /// This action lets domain checks veto a step the residuals alone would
+    /// accept, and recovers from failed trial evaluations. The line search
+    /// shortens the step and the dogleg strategy shrinks the trust radius.
+    ///
+    /// It only applies to [`Event::Trial`](super::Event::Trial) and is
+    /// ignored for other events.
+    RejectStep,
+}
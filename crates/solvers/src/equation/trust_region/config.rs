@@ -0,0 +1,163 @@
+use thiserror::Error;
+
+use crate::equation::newton::Difference;
+
+/// How the solver keeps Newton steps from overshooting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Take the full Newton step, then backtrack along it until the residual
+    /// norm decreases enough.
+    LineSearch,
+    /// Limit each step to a trust radius, blending the Newton step with the
+    /// steepest-descent step when the Newton step does not fit.
+    ///
+    /// Unlike the line search, the dogleg step is defined when the Jacobian
+    /// is singular.
+    Dogleg,
+}
+
+/// Configuration for the trust-region solver.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    pub max_iters: usize,
+    pub x_abs_tol: f64,
+    pub x_rel_tol: f64,
+    pub residual_tol: f64,
+    /// Finite difference scheme used to approximate the Jacobian.
+    pub difference: Difference,
+    /// Relative perturbation size for finite differences.
+    ///
+    /// Each variable is perturbed by `fd_step * max(|x_j|, 1)`.
+    pub fd_step: f64,
+    /// Step globalization strategy.
+    pub strategy: Strategy,
+    /// Fraction of the decrease in `½‖r‖²` predicted by the linearization
+    /// that a step must achieve to be accepted.
+    ///
+    /// Must be in `(0, 1)`.
+    pub sufficient_decrease: f64,
+    /// Factor applied to the step length (line search) or the trust radius
+    /// (dogleg) after a step is rejected.
+    ///
+    /// Must be in `(0, 1)`.
+    pub step_reduction: f64,
+    /// Maximum consecutive rejected steps before the solver stalls.
+    pub max_rejections: usize,
+    /// Initial trust radius, relative to `max(‖x0‖, 1)`.
+    ///
+    /// Only used by [`Strategy::Dogleg`].
+    pub initial_radius: f64,
+}
+
+/// Errors that can occur when validating a trust-region config.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("x_abs_tol must be finite and non-negative")]
+    XAbs,
+
+    #[error("x_rel_tol must be finite and non-negative")]
+    XRel,
+
+    #[error("residual_tol must be finite and non-negative")]
+    Residual,
+
+    #[error("fd_step must be finite and positive")]
+    FdStep,
+
+    #[error("sufficient_decrease must be in (0, 1)")]
+    SufficientDecrease,
+
+    #[error("step_reduction must be in (0, 1)")]
+    StepReduction,
+
+    #[error("max_rejections must be at least 1")]
+    MaxRejections,
+
+    #[error("initial_radius must be finite and positive")]
+    InitialRadius,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_iters: 100,
+            x_abs_tol: 1e-12,
+            x_rel_tol: 1e-12,
+            residual_tol: 1e-12,
+            difference: Difference::Forward,
+            fd_step: f64::EPSILON.sqrt(),
+            strategy: Strategy::Dogleg,
+            sufficient_decrease: 1e-4,
+            step_reduction: 0.5,
+            max_rejections: 30,
+            initial_radius: 1.0,
+        }
+    }
+}
+
+impl Config {
+    /// Validates that all tolerances and step controls are usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any tolerance is negative or non-finite, or if a
+    /// step control parameter is outside its documented range.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !self.x_abs_tol.is_finite() || self.x_abs_tol < 0.0 {
+            return Err(ConfigError::XAbs);
+        }
+        if !self.x_rel_tol.is_finite() || self.x_rel_tol < 0.0 {
+            return Err(ConfigError::XRel);
+        }
+        if !self.residual_tol.is_finite() || self.residual_tol < 0.0 {
+            return Err(ConfigError::Residual);
+        }
+        if !self.fd_step.is_finite() || self.fd_step <= 0.0 {
+            return Err(ConfigError::FdStep);
+        }
+        if !(self.sufficient_decrease > 0.0 && self.sufficient_decrease < 1.0) {
+            return Err(ConfigError::SufficientDecrease);
+        }
+        if !(self.step_reduction > 0.0 && self.step_reduction < 1.0) {
+            return Err(ConfigError::StepReduction);
+        }
+        if self.max_rejections == 0 {
+            return Err(ConfigError::MaxRejections);
+        }
+        if !self.initial_radius.is_finite() || self.initial_radius <= 0.0 {
+            return Err(ConfigError::InitialRadius);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_bad_step_controls() {
+        let config = Config {
+            sufficient_decrease: 1.0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::SufficientDecrease));
+
+        let config = Config {
+            step_reduction: f64::NAN,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::StepReduction));
+
+        let config = Config {
+            max_rejections: 0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::MaxRejections));
+    }
+}
@@ -0,0 +1,154 @@
+use twine_core::{EquationProblem, Model, Observer};
+
+use crate::{
+    equation::{Evaluation, evaluate, newton::jacobian},
+    stats::{Stats, Tally},
+};
+
+use super::{Action, Error, Event};
+
+/// Evaluation type produced for a model and problem.
+pub(super) type ModelEvaluation<M, const N: usize> =
+    Evaluation<<M as Model>::Input, <M as Model>::Output, N>;
+
+/// How the solver should proceed after evaluating the initial guess.
+pub(super) enum Start<T> {
+    Continue(T),
+    StopEarly(T),
+}
+
+/// How the solver should proceed after an observed evaluation.
+pub(super) enum Outcome<T> {
+    Continue(T),
+    RejectStep,
+    StopEarly,
+}
+
+/// Bundles evaluation and observation for a single trust-region solve.
+///
+/// This keeps event emission and action handling in one place while leaving the
+/// solver loop to focus on step control. An observer action always takes
+/// precedence over an evaluation error.
+pub(super) struct EvalContext<'ctx, M, P, Obs> {
+    model: &'ctx M,
+    problem: &'ctx P,
+    observer: &'ctx mut Obs,
+    tally: Tally,
+}
+
+impl<'ctx, M, P, Obs> EvalContext<'ctx, M, P, Obs>
+where
+    M: Model,
+{
+    /// Creates a new evaluation context.
+    pub(super) fn new(model: &'ctx M, problem: &'ctx P, observer: &'ctx mut Obs) -> Self {
+        Self {
+            model,
+            problem,
+            observer,
+            tally: Tally::start(),
+        }
+    }
+
+    /// Returns the cost of the solve so far.
+    pub(super) fn stats(&self) -> Stats {
+        self.tally.stats()
+    }
+
+    /// Evaluates the initial guess.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NoSuccessfulEvaluation` if the observer stops on a
+    /// failed evaluation, since there is no iterate to report.
+    pub(super) fn initial<const N: usize>(
+        &mut self,
+        x: [f64; N],
+    ) -> Result<Start<ModelEvaluation<M, N>>, Error>
+    where
+        P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+        Obs: for<'evt> Observer<Event<'evt, M, P, N>, Action>,
+    {
+        let result = evaluate(self.model, self.problem, x);
+        self.tally.evaluated(&result);
+        let action = self
+            .observer
+            .observe(&Event::Initial { x, result: &result });
+        self.tally.observed(action.as_ref());
+
+        match (action, result) {
+            (Some(Action::StopEarly), Ok(eval)) => Ok(Start::StopEarly(eval)),
+            (Some(Action::StopEarly), Err(_)) => Err(Error::NoSuccessfulEvaluation),
+            (_, Ok(eval)) => Ok(Start::Continue(eval)),
+            (_, Err(error)) => Err(error.into()),
+        }
+    }
+
+    /// Evaluates a trial point reached by `step` from the last accepted iterate.
+    pub(super) fn trial<const N: usize>(
+        &mut self,
+        iter: usize,
+        x: [f64; N],
+        step: [f64; N],
+    ) -> Result<Outcome<ModelEvaluation<M, N>>, Error>
+    where
+        P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+        Obs: for<'evt> Observer<Event<'evt, M, P, N>, Action>,
+    {
+        let result = evaluate(self.model, self.problem, x);
+        self.tally.evaluated(&result);
+        let action = self.observer.observe(&Event::Trial {
+            iter,
+            x,
+            step,
+            result: &result,
+        });
+        self.tally.observed(action.as_ref());
+
+        match (action, result) {
+            (Some(Action::StopEarly), _) => Ok(Outcome::StopEarly),
+            (Some(Action::RejectStep), _) => Ok(Outcome::RejectStep),
+            (None, Ok(eval)) => Ok(Outcome::Continue(eval)),
+            (None, Err(error)) => Err(error.into()),
+        }
+    }
+
+    /// Approximates the Jacobian at `x` by finite differences.
+    ///
+    /// `r` must hold the residuals at `x`.
+    /// Step rejections are ignored, since a perturbed point is not a step.
+    pub(super) fn jacobian<const N: usize>(
+        &mut self,
+        x: &[f64; N],
+        r: &[f64; N],
+        difference: jacobian::Difference,
+        fd_step: f64,
+    ) -> Result<Outcome<[[f64; N]; N]>, Error>
+    where
+        P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+        Obs: for<'evt> Observer<Event<'evt, M, P, N>, Action>,
+    {
+        let points = jacobian::perturbations(x, difference, fd_step);
+        let mut residuals = Vec::with_capacity(points.len());
+
+        for point in &points {
+            let result = evaluate(self.model, self.problem, point.x);
+            self.tally.evaluated(&result);
+            let action = self.observer.observe(&Event::Jacobian {
+                column: point.column,
+                x: point.x,
+                result: &result,
+            });
+            self.tally.observed(action.as_ref());
+
+            if let Some(Action::StopEarly) = action {
+                return Ok(Outcome::StopEarly);
+            }
+            residuals.push(result?.residuals);
+        }
+
+        Ok(Outcome::Continue(jacobian::assemble(
+            x, r, difference, &points, &residuals,
+        )))
+    }
+}
@@ -0,0 +1,64 @@
+use twine_core::{EquationProblem, Model};
+
+use crate::equation::EvaluateResult;
+
+/// Event emitted by the trust-region solver for each evaluation.
+pub enum Event<'a, M, P, const N: usize>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    /// Evaluation at the initial guess.
+    Initial {
+        /// The x value that was evaluated.
+        x: [f64; N],
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, N>,
+    },
+    /// Evaluation at a trial point, which may be accepted or rejected.
+    ///
+    /// Observers can return [`Action::RejectStep`](super::Action::RejectStep)
+    /// to reject it.
+    Trial {
+        /// The iteration this trial would complete if accepted.
+        iter: usize,
+        /// The x value that was evaluated.
+        x: [f64; N],
+        /// The step from the last accepted iterate to `x`.
+        step: [f64; N],
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, N>,
+    },
+    /// Perturbed evaluation used to approximate a Jacobian column.
+    Jacobian {
+        /// The Jacobian column (solver variable) being perturbed.
+        column: usize,
+        /// The x value that was evaluated.
+        x: [f64; N],
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, N>,
+    },
+}
+
+impl<'a, M, P, const N: usize> Event<'a, M, P, N>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    /// Returns the evaluated x value.
+    #[must_use]
+    pub fn x(&self) -> [f64; N] {
+        match self {
+            Event::Initial { x, .. } | Event::Trial { x, .. } | Event::Jacobian { x, .. } => *x,
+        }
+    }
+
+    /// Returns the evaluation result.
+    pub fn result(&self) -> &'a EvaluateResult<M, P, N> {
+        match self {
+            Event::Initial { result, .. }
+            | Event::Trial { result, .. }
+            | Event::Jacobian { result, .. } => result,
+        }
+    }
+}
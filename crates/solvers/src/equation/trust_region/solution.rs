@@ -0,0 +1,37 @@
+use twine_core::Snapshot;
+
+use crate::Stats;
+
+/// Indicates why the solver finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Converged according to the configured tolerances.
+    Converged,
+    /// Reached the iteration limit without converging.
+    MaxIters,
+    /// No acceptable step was found within the rejection limit.
+    ///
+    /// This usually means the residual norm has reached a local minimum that
+    /// is not a root, or observers keep rejecting every step.
+    Stalled,
+    /// Stopped early due to an observer decision.
+    StoppedByObserver,
+}
+
+/// The result of a trust-region solve.
+#[derive(Debug, Clone)]
+pub struct Solution<I, O, const N: usize> {
+    /// Final solver status.
+    pub status: Status,
+    /// Last accepted iterate.
+    pub x: [f64; N],
+    /// Residuals at the last accepted iterate.
+    pub residuals: [f64; N],
+    /// Snapshot at the last accepted iterate.
+    pub snapshot: Snapshot<I, O>,
+    /// Number of accepted steps.
+    pub iters: usize,
+    /// Model evaluations and time spent, including rejected trials and
+    /// Jacobian evaluations.
+    pub stats: Stats,
+}
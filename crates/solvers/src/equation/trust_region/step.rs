@@ -0,0 +1,110 @@
+use crate::linalg;
+
+/// Returns the predicted decrease in `½‖r‖²` for a step under the
+/// linearization `r + J·p`.
+pub(super) fn predicted_reduction<const N: usize>(
+    jacobian: &[[f64; N]; N],
+    r: &[f64; N],
+    step: &[f64; N],
+) -> f64 {
+    let jp = apply(jacobian, step);
+    -(linalg::dot(r, &jp) + 0.5 * linalg::dot(&jp, &jp))
+}
+
+/// Computes the dogleg step within `radius`.
+///
+/// `newton` is the Newton step, or `None` if the Jacobian is singular, in
+/// which case the steepest-descent step is used on its own.
+/// Returns `None` if the gradient of `½‖r‖²` vanishes, since no step can then
+/// reduce the residual norm to first order.
+pub(super) fn dogleg<const N: usize>(
+    jacobian: &[[f64; N]; N],
+    r: &[f64; N],
+    newton: Option<[f64; N]>,
+    radius: f64,
+) -> Option<[f64; N]> {
+    if let Some(newton) = newton
+        && norm(&newton) <= radius
+    {
+        return Some(newton);
+    }
+
+    // Gradient of ½‖r‖² is Jᵀ·r.
+    let gradient: [f64; N] = std::array::from_fn(|j| (0..N).map(|i| jacobian[i][j] * r[i]).sum());
+    let g_norm = norm(&gradient);
+    let jg = apply(jacobian, &gradient);
+    let jg_squared = linalg::dot(&jg, &jg);
+    if g_norm == 0.0 || jg_squared == 0.0 || !jg_squared.is_finite() {
+        return None;
+    }
+
+    // The Cauchy point minimizes the linearization along the gradient.
+    let cauchy_length = g_norm * g_norm / jg_squared;
+    if cauchy_length * g_norm >= radius {
+        return Some(gradient.map(|g| -radius / g_norm * g));
+    }
+    let cauchy = gradient.map(|g| -cauchy_length * g);
+
+    let Some(newton) = newton else {
+        return Some(cauchy);
+    };
+
+    // Walk from the Cauchy point toward the Newton step until the boundary.
+    // Solve ‖cauchy + τ·leg‖ = radius for the positive root τ.
+    let leg: [f64; N] = std::array::from_fn(|i| newton[i] - cauchy[i]);
+    let quadratic = linalg::dot(&leg, &leg);
+    let linear = linalg::dot(&cauchy, &leg);
+    let constant = linalg::dot(&cauchy, &cauchy) - radius * radius;
+    let discriminant = (linear * linear - quadratic * constant).max(0.0);
+    let tau = (-linear + discriminant.sqrt()) / quadratic;
+
+    Some(std::array::from_fn(|i| cauchy[i] + tau * leg[i]))
+}
+
+/// Returns the Euclidean norm of a vector.
+pub(super) fn norm<const N: usize>(v: &[f64; N]) -> f64 {
+    linalg::dot(v, v).sqrt()
+}
+
+/// Returns `J·p`.
+fn apply<const N: usize>(jacobian: &[[f64; N]; N], p: &[f64; N]) -> [f64; N] {
+    jacobian.map(|row| linalg::dot(&row, p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn newton_step_inside_radius_is_taken() {
+        let jacobian = [[2.0, 0.0], [0.0, 1.0]];
+        let r = [2.0, 1.0];
+        let newton = linalg::solve(jacobian, r.map(|v| -v));
+
+        let step = dogleg(&jacobian, &r, newton, 10.0).expect("step");
+
+        assert_relative_eq!(step[0], -1.0);
+        assert_relative_eq!(step[1], -1.0);
+        assert_relative_eq!(predicted_reduction(&jacobian, &r, &step), 2.5);
+    }
+
+    #[test]
+    fn long_steps_end_on_the_boundary() {
+        let jacobian = [[2.0, 0.0], [0.0, 1.0]];
+        let r = [2.0, 1.0];
+        let newton = linalg::solve(jacobian, r.map(|v| -v));
+
+        for radius in [0.1, 1.2] {
+            let step = dogleg(&jacobian, &r, newton, radius).expect("step");
+            assert_relative_eq!(norm(&step), radius, epsilon = 1e-12);
+            assert!(predicted_reduction(&jacobian, &r, &step) > 0.0);
+        }
+
+        // A singular Jacobian still yields a descent step.
+        let singular = [[1.0, 1.0], [1.0, 1.0]];
+        let step = dogleg(&singular, &r, None, 10.0).expect("step");
+        assert!(predicted_reduction(&singular, &r, &step) > 0.0);
+    }
+}
@@ -1,3 +1,11 @@
 pub mod equation;
 pub mod optimization;
+pub mod sweep;
 pub mod transient;
+
+mod linalg;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod stats;
+
+pub use stats::Stats;
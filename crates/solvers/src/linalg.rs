@@ -0,0 +1,137 @@
+//! Small dense linear algebra helpers shared by the solvers.
+//!
+//! Matrices are stored row-major as `[[f64; N]; N]`, which keeps everything on
+//! the stack and lets the const generic `N` from the problem traits flow
+//! through without allocation.
+
+/// Solves `a * x = b` using Gaussian elimination with partial pivoting.
+///
+/// Returns `None` if the matrix is singular (or numerically indistinguishable
+/// from singular) or if the result is not finite.
+pub(crate) fn solve<const N: usize>(mut a: [[f64; N]; N], mut b: [f64; N]) -> Option<[f64; N]> {
+    let scale = a
+        .iter()
+        .flat_map(|row| row.iter())
+        .fold(0.0_f64, |acc, v| acc.max(v.abs()));
+    if scale == 0.0 || !scale.is_finite() {
+        return None;
+    }
+    let pivot_tol = scale * f64::EPSILON * 16.0;
+
+    for col in 0..N {
+        // Choose the row with the largest magnitude in this column.
+        let pivot = (col..N)
+            .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+            .expect("range is non-empty");
+
+        if a[pivot][col].abs() <= pivot_tol {
+            return None;
+        }
+
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let pivot_row = a[col];
+        for row in (col + 1)..N {
+            let factor = a[row][col] / pivot_row[col];
+            if factor == 0.0 {
+                continue;
+            }
+            for (value, pivot_value) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * pivot_value;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    // Back substitution.
+    let mut x = [0.0; N];
+    for row in (0..N).rev() {
+        let tail: f64 = ((row + 1)..N).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - tail) / a[row][row];
+    }
+
+    x.iter().all(|v| v.is_finite()).then_some(x)
+}
+
+/// Returns the largest absolute component (the infinity norm).
+///
+/// Returns NaN if any component is NaN, so that a NaN vector never passes a
+/// tolerance check.
+pub(crate) fn max_abs<const N: usize>(v: &[f64; N]) -> f64 {
+    v.iter()
+        .map(|x| x.abs())
+        .fold(0.0, |acc, x| if x > acc || x.is_nan() { x } else { acc })
+}
+
+/// Returns true if `step` is within `abs_tol + rel_tol * |x|`, both measured
+/// in the infinity norm.
+pub(crate) fn is_step_converged<const N: usize>(
+    step: &[f64; N],
+    x: &[f64; N],
+    abs_tol: f64,
+    rel_tol: f64,
+) -> bool {
+    max_abs(step) <= abs_tol + rel_tol * max_abs(x)
+}
+
+/// Returns the dot product of two vectors.
+pub(crate) fn dot<const N: usize>(a: &[f64; N], b: &[f64; N]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn solves_small_system() {
+        let a = [[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]];
+        let b = [8.0, -11.0, -3.0];
+
+        let x = solve(a, b).expect("non-singular");
+
+        assert_relative_eq!(x[0], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 3.0, epsilon = 1e-12);
+        assert_relative_eq!(x[2], -1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn pivots_on_zero_diagonal() {
+        let a = [[0.0, 1.0], [1.0, 0.0]];
+        let x = solve(a, [3.0, 4.0]).expect("non-singular");
+
+        assert_relative_eq!(x[0], 4.0);
+        assert_relative_eq!(x[1], 3.0);
+    }
+
+    #[test]
+    fn detects_singular_matrix() {
+        let a = [[1.0, 2.0], [2.0, 4.0]];
+        assert!(solve(a, [1.0, 2.0]).is_none());
+        assert!(solve([[0.0; 2]; 2], [1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn max_abs_is_infinity_norm() {
+        assert_relative_eq!(max_abs(&[1.0, -3.0, 2.0]), 3.0);
+        assert_relative_eq!(max_abs::<0>(&[]), 0.0);
+        assert!(max_abs(&[1.0, f64::NAN, 2.0]).is_nan());
+        assert!(max_abs(&[f64::NAN, 5.0]).is_nan());
+    }
+
+    #[test]
+    fn step_convergence_scales_with_x() {
+        assert!(is_step_converged(&[0.5, -1.0], &[10.0, 0.0], 0.0, 0.1));
+        assert!(!is_step_converged(&[0.5, -1.1], &[10.0, 0.0], 0.0, 0.1));
+        assert!(is_step_converged(&[1e-9], &[0.0], 1e-8, 0.1));
+        assert!(!is_step_converged(&[f64::NAN], &[1.0], 1.0, 1.0));
+    }
+
+    #[test]
+    fn dot_product() {
+        assert_relative_eq!(dot(&[1.0, 2.0, 3.0], &[4.0, -5.0, 6.0]), 12.0);
+    }
+}
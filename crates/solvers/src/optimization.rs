@@ -8,11 +8,31 @@
 //!
 //! - [`golden_section`] — derivative-free search over a bracketed interval for
 //!   unimodal functions
+//! - [`brent`] — bracketed search that accelerates golden section steps with
+//!   parabolic interpolation on smooth functions
+//! - [`nelder_mead`] — derivative-free simplex search over several variables
+//! - [`lbfgs`] — quasi-Newton search over several variables for smooth
+//!   objectives, using analytic or finite difference gradients
+//! - [`lbfgsb`] — L-BFGS that keeps every variable within the problem's bounds
+//! - [`levenberg_marquardt`] — damped Gauss–Newton fitting of parameters to a
+//!   [`LeastSquaresProblem`]'s residuals
+//! - [`augmented_lagrangian`] — constrained optimization over several variables,
+//!   solving a sequence of penalized problems with [`lbfgsb`]
 //!
 //! [`OptimizationProblem`]: twine_core::OptimizationProblem
+//! [`LeastSquaresProblem`]: twine_core::LeastSquaresProblem
 
 mod evaluate;
 
 pub use evaluate::{EvalError, EvaluateResult, Evaluation, evaluate};
 
+#[cfg(feature = "parallel")]
+pub use evaluate::evaluate_batch;
+
+pub mod augmented_lagrangian;
+pub mod brent;
 pub mod golden_section;
+pub mod lbfgs;
+pub mod lbfgsb;
+pub mod levenberg_marquardt;
+pub mod nelder_mead;
@@ -0,0 +1,506 @@
+//! Augmented Lagrangian method for constrained multi-variable optimization.
+//!
+//! # Algorithm
+//!
+//! The solver replaces a [`ConstrainedOptimizationProblem`] with a sequence of
+//! bound-constrained problems, each minimized by [`lbfgsb`]. Each inner
+//! objective adds a penalty term per constraint to the objective `f`:
+//!
+//! ```text
+//! equality   c = 0:   λ c + ρ/2 c²
+//! inequality c <= 0:  ρ/2 (max(0, c + λ/ρ)² - (λ/ρ)²)
+//! ```
+//!
+//! After each inner solve the multiplier estimates `λ` are updated from the
+//! constraint values (`λ += ρ c`, kept non-negative for inequalities), and
+//! the next solve starts from the previous solution. The penalty weight `ρ`
+//! grows by [`penalty_growth`](Config::penalty_growth) whenever the
+//! constraint residual does not shrink by
+//! [`violation_reduction`](Config::violation_reduction). Because the
+//! multipliers absorb most of the constraint forces, `ρ` usually stays
+//! moderate, avoiding the ill-conditioning of a pure penalty method.
+//!
+//! [`minimize`] and [`maximize`] leave the variables free. When the problem
+//! also implements [`BoundedProblem`], [`minimize_bounded`] and
+//! [`maximize_bounded`] pass its variable bounds to the inner solver, so the
+//! model is never evaluated outside them. Constraints only need to hold at
+//! the solution and may be violated along the way.
+//!
+//! # When to Use
+//!
+//! Use this solver when constraints depend on model outputs, such as a
+//! minimum pinch temperature or a target outlet pressure. The objective and
+//! constraints should be smooth, since the inner solver is gradient based.
+//! Gradients come from finite differences of the augmented objective; any
+//! [`OptimizationProblem::gradient`] on the problem is not used.
+//!
+//! # Convergence
+//!
+//! The solver converges when, after an inner solve, every equality constraint
+//! is within `constraint_tol` of zero and every inequality constraint is
+//! within `constraint_tol` of feasible and of complementary with its
+//! multiplier. The [`Solution`] reports the constraint values, the largest
+//! violation, and the multiplier estimates whatever the status, so callers
+//! can judge a [`Status::MaxIters`] result.
+//!
+//! # Observer Events
+//!
+//! The solver emits an [`Event::Iterate`] after each inner solve, with the
+//! objective, constraint values, and violation at the inner solution.
+//! Observers can return [`Action::StopEarly`] to halt and return that iterate.
+//!
+//! [`ConstrainedOptimizationProblem`]: twine_core::ConstrainedOptimizationProblem
+//! [`BoundedProblem`]: twine_core::BoundedProblem
+//! [`OptimizationProblem::gradient`]: twine_core::OptimizationProblem::gradient
+//! [`lbfgsb`]: crate::optimization::lbfgsb
+
+mod action;
+mod config;
+mod error;
+mod event;
+mod lagrangian;
+mod search;
+mod solution;
+
+pub use action::Action;
+pub use config::{Config, ConfigError};
+pub use error::Error;
+pub use event::Event;
+pub use solution::{Solution, Status};
+
+use twine_core::{Bound, BoundedProblem, ConstrainedOptimizationProblem, Model, Observer};
+
+/// Finds a minimum of the objective subject to the problem's constraints.
+///
+/// The observer receives an [`Event`] after each inner solve.
+/// See the [module docs](self) for details on convergence and events.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, or if the model or problem
+/// fails during evaluation.
+pub fn minimize<M, P, Obs, const N: usize, const C: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N, C>, Error>
+where
+    M: Model,
+    P: ConstrainedOptimizationProblem<N, C, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, N, C>, Action>,
+{
+    search::search(
+        model,
+        problem,
+        x0,
+        [Bound::UNBOUNDED; N],
+        config,
+        observer,
+        1.0,
+    )
+}
+
+/// Finds a constrained minimum of the objective without observer support.
+///
+/// This is a convenience wrapper around [`minimize`] that uses a no-op observer.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`minimize`].
+pub fn minimize_unobserved<M, P, const N: usize, const C: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N, C>, Error>
+where
+    M: Model,
+    P: ConstrainedOptimizationProblem<N, C, Input = M::Input, Output = M::Output>,
+{
+    minimize(model, problem, x0, config, ())
+}
+
+/// Finds a maximum of the objective subject to the problem's constraints.
+///
+/// The observer receives an [`Event`] after each inner solve.
+/// See the [module docs](self) for details on convergence and events.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, or if the model or problem
+/// fails during evaluation.
+pub fn maximize<M, P, Obs, const N: usize, const C: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N, C>, Error>
+where
+    M: Model,
+    P: ConstrainedOptimizationProblem<N, C, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, N, C>, Action>,
+{
+    search::search(
+        model,
+        problem,
+        x0,
+        [Bound::UNBOUNDED; N],
+        config,
+        observer,
+        -1.0,
+    )
+}
+
+/// Finds a constrained maximum of the objective without observer support.
+///
+/// This is a convenience wrapper around [`maximize`] that uses a no-op observer.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`maximize`].
+pub fn maximize_unobserved<M, P, const N: usize, const C: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N, C>, Error>
+where
+    M: Model,
+    P: ConstrainedOptimizationProblem<N, C, Input = M::Input, Output = M::Output>,
+{
+    maximize(model, problem, x0, config, ())
+}
+
+/// Finds a constrained minimum with the variables kept within the problem's
+/// bounds.
+///
+/// Behaves like [`minimize`], with the inner solves bounded by
+/// [`BoundedProblem::bounds`].
+///
+/// # Errors
+///
+/// Returns an error if the config or bounds are invalid, or if the model or
+/// problem fails during evaluation.
+pub fn minimize_bounded<M, P, Obs, const N: usize, const C: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N, C>, Error>
+where
+    M: Model,
+    P: ConstrainedOptimizationProblem<N, C, Input = M::Input, Output = M::Output>
+        + BoundedProblem<N>,
+    Obs: for<'a> Observer<Event<'a, M, N, C>, Action>,
+{
+    search::search(model, problem, x0, problem.bounds(), config, observer, 1.0)
+}
+
+/// Finds a constrained maximum with the variables kept within the problem's
+/// bounds.
+///
+/// Behaves like [`maximize`], with the inner solves bounded by
+/// [`BoundedProblem::bounds`].
+///
+/// # Errors
+///
+/// Returns an error if the config or bounds are invalid, or if the model or
+/// problem fails during evaluation.
+pub fn maximize_bounded<M, P, Obs, const N: usize, const C: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N, C>, Error>
+where
+    M: Model,
+    P: ConstrainedOptimizationProblem<N, C, Input = M::Input, Output = M::Output>
+        + BoundedProblem<N>,
+    Obs: for<'a> Observer<Event<'a, M, N, C>, Action>,
+{
+    search::search(model, problem, x0, problem.bounds(), config, observer, -1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use twine_core::{Bound, Constraint, OptimizationProblem};
+
+    // --- Test fixtures ---
+
+    /// Passes the point through, so problems read everything from the output.
+    struct Identity;
+
+    impl Model for Identity {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn call(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok(*x)
+        }
+    }
+
+    /// Minimize `x² + y²` subject to `x + y = 1`.
+    ///
+    /// The solution is `(0.5, 0.5)` with multiplier `-1`.
+    struct OnLine;
+
+    impl OptimizationProblem<2> for OnLine {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok(*x)
+        }
+
+        fn objective(&self, _: &[f64; 2], &[x, y]: &[f64; 2]) -> Result<f64, Infallible> {
+            Ok(x * x + y * y)
+        }
+    }
+
+    impl ConstrainedOptimizationProblem<2, 1> for OnLine {
+        fn constraints(
+            &self,
+            _: &[f64; 2],
+            &[x, y]: &[f64; 2],
+        ) -> Result<[Constraint; 1], Infallible> {
+            Ok([Constraint::equal(x + y, 1.0)])
+        }
+    }
+
+    /// Minimize `(x - 2)² + (y - 1)²` subject to `x² <= y` and `x + y <= 2`.
+    ///
+    /// Both constraints are active at the solution `(1, 1)`, with multipliers
+    /// `2/3` each.
+    struct Parabola;
+
+    impl OptimizationProblem<2> for Parabola {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok(*x)
+        }
+
+        fn objective(&self, _: &[f64; 2], &[x, y]: &[f64; 2]) -> Result<f64, Infallible> {
+            Ok((x - 2.0).powi(2) + (y - 1.0).powi(2))
+        }
+    }
+
+    impl ConstrainedOptimizationProblem<2, 2> for Parabola {
+        fn constraints(
+            &self,
+            _: &[f64; 2],
+            &[x, y]: &[f64; 2],
+        ) -> Result<[Constraint; 2], Infallible> {
+            Ok([
+                Constraint::at_most(x * x, y),
+                Constraint::at_most(x + y, 2.0),
+            ])
+        }
+    }
+
+    /// Minimize `(x - 1)² + (y - 1)²` subject to `x >= -5`, which is inactive.
+    struct Inactive;
+
+    impl OptimizationProblem<2> for Inactive {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok(*x)
+        }
+
+        fn objective(&self, _: &[f64; 2], &[x, y]: &[f64; 2]) -> Result<f64, Infallible> {
+            Ok((x - 1.0).powi(2) + (y - 1.0).powi(2))
+        }
+    }
+
+    impl ConstrainedOptimizationProblem<2, 1> for Inactive {
+        fn constraints(
+            &self,
+            _: &[f64; 2],
+            &[x, _]: &[f64; 2],
+        ) -> Result<[Constraint; 1], Infallible> {
+            Ok([Constraint::at_least(x, -5.0)])
+        }
+    }
+
+    /// [`OnLine`] with `x >= 0.8`, which moves the solution to `(0.8, 0.2)`.
+    struct OnLineRight;
+
+    impl OptimizationProblem<2> for OnLineRight {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            OnLine.input(x)
+        }
+
+        fn objective(&self, input: &[f64; 2], output: &[f64; 2]) -> Result<f64, Infallible> {
+            OnLine.objective(input, output)
+        }
+    }
+
+    impl ConstrainedOptimizationProblem<2, 1> for OnLineRight {
+        fn constraints(
+            &self,
+            input: &[f64; 2],
+            output: &[f64; 2],
+        ) -> Result<[Constraint; 1], Infallible> {
+            OnLine.constraints(input, output)
+        }
+    }
+
+    impl BoundedProblem<2> for OnLineRight {
+        fn bounds(&self) -> [Bound; 2] {
+            [Bound::at_least(0.8), Bound::UNBOUNDED]
+        }
+    }
+
+    /// Maximize `x y` subject to `x + y = 2`, with its maximum at `(1, 1)`.
+    struct Product;
+
+    impl OptimizationProblem<2> for Product {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok(*x)
+        }
+
+        fn objective(&self, _: &[f64; 2], &[x, y]: &[f64; 2]) -> Result<f64, Infallible> {
+            Ok(x * y)
+        }
+    }
+
+    impl ConstrainedOptimizationProblem<2, 1> for Product {
+        fn constraints(
+            &self,
+            _: &[f64; 2],
+            &[x, y]: &[f64; 2],
+        ) -> Result<[Constraint; 1], Infallible> {
+            Ok([Constraint::equal(x + y, 2.0)])
+        }
+    }
+
+    // --- Tests ---
+
+    #[test]
+    fn satisfies_equality_constraint() {
+        let solution = minimize_unobserved(&Identity, &OnLine, [2.0, -1.0], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 0.5, epsilon = 1e-5);
+        assert_relative_eq!(solution.x[1], 0.5, epsilon = 1e-5);
+        assert!(solution.violation <= 1e-6);
+        assert_relative_eq!(solution.multipliers[0], -1.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn satisfies_active_inequality_constraints() {
+        let solution = minimize_unobserved(&Identity, &Parabola, [0.0, 0.0], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(solution.x[1], 1.0, epsilon = 1e-5);
+        for multiplier in solution.multipliers {
+            assert_relative_eq!(multiplier, 2.0 / 3.0, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn inactive_constraint_has_zero_multiplier() {
+        let solution = minimize_unobserved(&Identity, &Inactive, [0.0, 0.0], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_eq!(solution.iters, 1);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(solution.violation, 0.0);
+        assert_relative_eq!(solution.multipliers[0], 0.0);
+    }
+
+    #[test]
+    fn bounded_entry_point_respects_variable_bounds() {
+        let free = minimize_unobserved(&Identity, &OnLineRight, [2.0, -1.0], &Config::default())
+            .expect("should converge");
+        let bounded =
+            minimize_bounded(&Identity, &OnLineRight, [2.0, -1.0], &Config::default(), ())
+                .expect("should converge");
+
+        assert_relative_eq!(free.x[0], 0.5, epsilon = 1e-5);
+        assert_eq!(bounded.status, Status::Converged);
+        assert_relative_eq!(bounded.x[0], 0.8, epsilon = 1e-5);
+        assert_relative_eq!(bounded.x[1], 0.2, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn maximizes_subject_to_constraint() {
+        let solution = maximize_unobserved(&Identity, &Product, [0.0, 0.5], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(solution.objective, 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn observer_sees_violation_decrease_and_can_stop() {
+        let mut violations = Vec::new();
+
+        let solution = minimize(
+            &Identity,
+            &OnLine,
+            [2.0, -1.0],
+            &Config::default(),
+            |event: &Event<'_, Identity, 2, 1>| {
+                let Event::Iterate { violation, .. } = event;
+                violations.push(*violation);
+                (violations.len() == 2).then_some(Action::StopEarly)
+            },
+        )
+        .expect("should stop");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.iters, 2);
+        assert!(violations[1] < violations[0]);
+    }
+
+    #[test]
+    fn max_iters_reports_violation() {
+        let config = Config {
+            max_iters: 1,
+            ..Config::default()
+        };
+
+        let solution =
+            minimize_unobserved(&Identity, &OnLine, [2.0, -1.0], &config).expect("should finish");
+
+        // With a zero multiplier, the penalty alone leaves x + y short of 1.
+        assert_eq!(solution.status, Status::MaxIters);
+        assert!(solution.violation > config.constraint_tol);
+        assert_relative_eq!(
+            solution.violation,
+            solution.constraints[0].violation(),
+            epsilon = 1e-12
+        );
+    }
+}
@@ -0,0 +1,6 @@
+/// Control actions supported by the augmented Lagrangian solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Stop the solver early and return the current iterate.
+    StopEarly,
+}
@@ -0,0 +1,122 @@
+use thiserror::Error;
+
+use crate::optimization::lbfgs;
+
+/// Configuration for the augmented Lagrangian solver.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    /// Maximum number of outer iterations (inner solves).
+    pub max_iters: usize,
+    /// Converge when every constraint violation is within this tolerance.
+    pub constraint_tol: f64,
+    /// Penalty weight used for the first inner solve.
+    pub initial_penalty: f64,
+    /// Factor applied to the penalty when the violation decreases too slowly.
+    pub penalty_growth: f64,
+    /// Required reduction in violation per outer iteration.
+    ///
+    /// The penalty grows whenever the violation is not reduced to at most
+    /// this fraction of its previous value.
+    pub violation_reduction: f64,
+    /// Configuration for the inner bound-constrained L-BFGS solves.
+    pub inner: lbfgs::Config,
+}
+
+/// Errors that can occur when validating an augmented Lagrangian config.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("max_iters must be at least 1")]
+    MaxIters,
+
+    #[error("constraint_tol must be finite and positive")]
+    Constraint,
+
+    #[error("initial_penalty must be finite and positive")]
+    Penalty,
+
+    #[error("penalty_growth must be finite and greater than 1")]
+    PenaltyGrowth,
+
+    #[error("violation_reduction must be in (0, 1)")]
+    Reduction,
+
+    #[error("invalid inner config: {0}")]
+    Inner(#[from] lbfgs::ConfigError),
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_iters: 50,
+            constraint_tol: 1e-6,
+            initial_penalty: 10.0,
+            penalty_growth: 10.0,
+            violation_reduction: 0.25,
+            inner: lbfgs::Config::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Validates the iteration limit, tolerance, penalty parameters, and inner config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `max_iters` is zero, if `constraint_tol` or
+    /// `initial_penalty` is not finite and positive, if `penalty_growth` does
+    /// not exceed 1, if `violation_reduction` is outside `(0, 1)`, or if the
+    /// inner config is invalid.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_iters == 0 {
+            return Err(ConfigError::MaxIters);
+        }
+        if !self.constraint_tol.is_finite() || self.constraint_tol <= 0.0 {
+            return Err(ConfigError::Constraint);
+        }
+        if !self.initial_penalty.is_finite() || self.initial_penalty <= 0.0 {
+            return Err(ConfigError::Penalty);
+        }
+        if !self.penalty_growth.is_finite() || self.penalty_growth <= 1.0 {
+            return Err(ConfigError::PenaltyGrowth);
+        }
+        if !(0.0 < self.violation_reduction && self.violation_reduction < 1.0) {
+            return Err(ConfigError::Reduction);
+        }
+        self.inner.validate()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_penalty_growth_at_most_one() {
+        let config = Config {
+            penalty_growth: 1.0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::PenaltyGrowth));
+    }
+
+    #[test]
+    fn reports_invalid_inner_config() {
+        let config = Config {
+            inner: lbfgs::Config {
+                memory: 0,
+                ..lbfgs::Config::default()
+            },
+            ..Config::default()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::Inner(lbfgs::ConfigError::Memory))
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use std::error::Error as StdError;
+
+use thiserror::Error;
+
+use crate::optimization::lbfgs;
+
+use super::config::ConfigError;
+
+/// Errors that can occur during augmented Lagrangian optimization.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("invalid config: {0}")]
+    InvalidConfig(#[from] ConfigError),
+
+    #[error("bounds must satisfy lower <= upper")]
+    InvalidBounds,
+
+    #[error("problem error")]
+    Problem(#[source] Box<dyn StdError + Send + Sync>),
+
+    #[error("model call failed")]
+    Model(#[source] Box<dyn StdError + Send + Sync>),
+}
+
+impl From<lbfgs::Error> for Error {
+    fn from(err: lbfgs::Error) -> Self {
+        match err {
+            lbfgs::Error::InvalidConfig(e) => Self::InvalidConfig(ConfigError::Inner(e)),
+            lbfgs::Error::InvalidBounds => Self::InvalidBounds,
+            lbfgs::Error::Problem(e) => Self::Problem(e),
+            lbfgs::Error::Model(e) => Self::Model(e),
+            lbfgs::Error::NoSuccessfulEvaluation => {
+                unreachable!("the inner solver is unobserved and cannot stop early")
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use twine_core::{Constraint, Model};
+
+/// Events emitted by the augmented Lagrangian solver.
+///
+/// Objectives in events are always those of the problem, even when
+/// maximizing. Evaluations inside each inner solve are not observed.
+pub enum Event<'a, M, const N: usize, const C: usize>
+where
+    M: Model,
+{
+    /// The result of one inner solve, before the multipliers are updated.
+    Iterate {
+        /// The outer iteration that produced this iterate.
+        iter: usize,
+        /// The iterate.
+        x: [f64; N],
+        /// Objective value at the iterate.
+        objective: f64,
+        /// Constraint values at the iterate.
+        constraints: [Constraint; C],
+        /// The largest constraint violation at the iterate.
+        violation: f64,
+        /// Multiplier estimates used for this inner solve.
+        multipliers: [f64; C],
+        /// Penalty weight used for this inner solve.
+        penalty: f64,
+        /// The model input at the iterate.
+        input: &'a M::Input,
+        /// The model output at the iterate.
+        output: &'a M::Output,
+    },
+}
@@ -0,0 +1,117 @@
+use twine_core::{
+    Bound, BoundedProblem, ConstrainedOptimizationProblem, Constraint, OptimizationProblem,
+};
+
+/// The augmented Lagrangian of a constrained problem, posed as a
+/// bound-constrained problem for the inner solver.
+///
+/// Its objective is `sign * f` plus a penalty term for each constraint built
+/// from the current multiplier estimates and penalty weight. Its bounds are
+/// the variable bounds passed to the solver.
+pub(super) struct Lagrangian<'a, P, const N: usize, const C: usize> {
+    pub(super) problem: &'a P,
+    pub(super) bounds: [Bound; N],
+    pub(super) multipliers: [f64; C],
+    pub(super) penalty: f64,
+    pub(super) sign: f64,
+}
+
+impl<P, const N: usize, const C: usize> OptimizationProblem<N> for Lagrangian<'_, P, N, C>
+where
+    P: ConstrainedOptimizationProblem<N, C>,
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn input(&self, x: &[f64; N]) -> Result<Self::Input, Self::Error> {
+        self.problem.input(x)
+    }
+
+    fn objective(&self, input: &Self::Input, output: &Self::Output) -> Result<f64, Self::Error> {
+        let objective = self.problem.objective(input, output)?;
+        let constraints = self.problem.constraints(input, output)?;
+
+        Ok(constraints
+            .iter()
+            .zip(self.multipliers)
+            .map(|(constraint, multiplier)| term(constraint, multiplier, self.penalty))
+            .fold(self.sign * objective, |sum, term| sum + term))
+    }
+}
+
+impl<P, const N: usize, const C: usize> BoundedProblem<N> for Lagrangian<'_, P, N, C>
+where
+    P: ConstrainedOptimizationProblem<N, C>,
+{
+    fn bounds(&self) -> [Bound; N] {
+        self.bounds
+    }
+}
+
+/// Returns the penalty term for one constraint.
+///
+/// Equalities contribute `λ c + ρ/2 c²`. Inequalities contribute
+/// `ρ/2 (max(0, c + λ/ρ)² - (λ/ρ)²)`, which is smooth and constant once the
+/// constraint is comfortably inactive.
+fn term(constraint: &Constraint, multiplier: f64, penalty: f64) -> f64 {
+    match *constraint {
+        Constraint::Equality(c) => multiplier * c + 0.5 * penalty * c * c,
+        Constraint::Inequality(c) => {
+            let shift = multiplier / penalty;
+            0.5 * penalty * ((c + shift).max(0.0).powi(2) - shift * shift)
+        }
+    }
+}
+
+/// Returns the updated multiplier estimate for one constraint.
+///
+/// Inequality multipliers are kept non-negative.
+pub(super) fn update(constraint: &Constraint, multiplier: f64, penalty: f64) -> f64 {
+    match *constraint {
+        Constraint::Equality(c) => multiplier + penalty * c,
+        Constraint::Inequality(c) => (multiplier + penalty * c).max(0.0),
+    }
+}
+
+/// Returns how far one constraint is from satisfying the KKT conditions.
+///
+/// This is the violation for equalities. For inequalities it also measures
+/// complementarity: an inactive constraint (`c < 0`) with a positive
+/// multiplier is not yet converged.
+pub(super) fn residual(constraint: &Constraint, multiplier: f64, penalty: f64) -> f64 {
+    match *constraint {
+        Constraint::Equality(c) => c.abs(),
+        Constraint::Inequality(c) => {
+            // Equivalent to |max(c, -λ/ρ)|, but keeps a NaN value.
+            let floor = -multiplier / penalty;
+            if c < floor { -floor } else { c.abs() }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn inactive_inequality_has_no_effect() {
+        let inactive = Constraint::Inequality(-2.0);
+        assert_relative_eq!(term(&inactive, 0.0, 10.0), 0.0);
+        assert_relative_eq!(update(&inactive, 0.5, 10.0), 0.0);
+        assert_relative_eq!(residual(&inactive, 0.0, 10.0), 0.0);
+
+        // A positive multiplier on an inactive constraint is not converged.
+        assert_relative_eq!(residual(&inactive, 5.0, 10.0), 0.5);
+    }
+
+    #[test]
+    fn equality_multiplier_moves_with_violation() {
+        let violated = Constraint::Equality(0.1);
+        assert_relative_eq!(term(&violated, 2.0, 10.0), 0.25);
+        assert_relative_eq!(update(&violated, 2.0, 10.0), 3.0);
+        assert_relative_eq!(residual(&violated, 2.0, 10.0), 0.1);
+    }
+}
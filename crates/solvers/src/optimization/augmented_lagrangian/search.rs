@@ -0,0 +1,124 @@
+use twine_core::{Bound, ConstrainedOptimizationProblem, Model, Observer};
+
+use crate::{optimization::lbfgsb, stats::Tally};
+
+use super::{
+    Action, Config, Error, Event, Solution, Status,
+    lagrangian::{self, Lagrangian},
+};
+
+/// Core augmented Lagrangian implementation.
+///
+/// The objective is multiplied by `sign` inside the Lagrangian, so the inner
+/// solver always minimizes: `1.0` minimizes the objective and `-1.0`
+/// maximizes it. The `bounds` are handed to the inner solver.
+pub(super) fn search<M, P, Obs, const N: usize, const C: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    bounds: [Bound; N],
+    config: &Config,
+    mut observer: Obs,
+    sign: f64,
+) -> Result<Solution<M::Input, M::Output, N, C>, Error>
+where
+    M: Model,
+    P: ConstrainedOptimizationProblem<N, C, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, N, C>, Action>,
+{
+    config.validate()?;
+    let tally = Tally::start();
+
+    let mut x = x0;
+    let mut multipliers = [0.0; C];
+    let mut penalty = config.initial_penalty;
+    let mut previous_residual = f64::INFINITY;
+
+    for iter in 1..=config.max_iters {
+        let lagrangian = Lagrangian {
+            problem,
+            bounds,
+            multipliers,
+            penalty,
+            sign,
+        };
+        let inner = lbfgsb::minimize_unobserved(model, &lagrangian, x, &config.inner)?;
+        tally.include(&inner.stats);
+        x = inner.x;
+
+        let snapshot = inner.snapshot;
+        let (objective, constraints) = problem
+            .objective(&snapshot.input, &snapshot.output)
+            .and_then(|objective| {
+                let constraints = problem.constraints(&snapshot.input, &snapshot.output)?;
+                Ok((objective, constraints))
+            })
+            .map_err(|err| Error::Problem(Box::new(err)))?;
+
+        let violation = largest(constraints.map(|constraint| constraint.violation()));
+        let residuals: [f64; C] =
+            std::array::from_fn(|i| lagrangian::residual(&constraints[i], multipliers[i], penalty));
+        let residual = largest(residuals);
+
+        let action = observer.observe(&Event::Iterate {
+            iter,
+            x,
+            objective,
+            constraints,
+            violation,
+            multipliers,
+            penalty,
+            input: &snapshot.input,
+            output: &snapshot.output,
+        });
+        tally.observed(action.as_ref());
+
+        let status = if let Some(Action::StopEarly) = action {
+            Some(Status::StoppedByObserver)
+        } else if residual <= config.constraint_tol {
+            Some(Status::Converged)
+        } else if iter == config.max_iters {
+            Some(Status::MaxIters)
+        } else {
+            None
+        };
+
+        // The first-order update is the best multiplier estimate at x, so it
+        // is reported even when finishing.
+        multipliers =
+            std::array::from_fn(|i| lagrangian::update(&constraints[i], multipliers[i], penalty));
+
+        if let Some(status) = status {
+            return Ok(Solution {
+                status,
+                x,
+                objective,
+                constraints,
+                violation,
+                multipliers,
+                snapshot,
+                iters: iter,
+                stats: tally.stats(),
+            });
+        }
+
+        let is_reduced = residual <= config.violation_reduction * previous_residual;
+        if !is_reduced {
+            penalty *= config.penalty_growth;
+        }
+        previous_residual = residual;
+    }
+
+    unreachable!("config validation requires at least one iteration")
+}
+
+/// Returns the largest value, or `NaN` if any value is `NaN`.
+fn largest<const C: usize>(values: [f64; C]) -> f64 {
+    values.into_iter().fold(0.0, |largest, value| {
+        if value.is_nan() || value > largest {
+            value
+        } else {
+            largest
+        }
+    })
+}
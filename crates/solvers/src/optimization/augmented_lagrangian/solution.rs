@@ -0,0 +1,56 @@
+use twine_core::{Constraint, Snapshot};
+
+use crate::Stats;
+
+/// Indicates why the solver finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Converged with every constraint satisfied within `constraint_tol`.
+    Converged,
+
+    /// Reached the iteration limit without converging.
+    ///
+    /// Check [`Solution::violation`] to see how far the reported x is from
+    /// satisfying the constraints.
+    MaxIters,
+
+    /// Stopped early due to an observer decision.
+    StoppedByObserver,
+}
+
+/// The result of an augmented Lagrangian optimization.
+#[derive(Debug, Clone)]
+pub struct Solution<I, O, const N: usize, const C: usize> {
+    /// Final solver status.
+    pub status: Status,
+
+    /// The final iterate.
+    pub x: [f64; N],
+
+    /// Objective value at the reported x.
+    pub objective: f64,
+
+    /// Constraint values at the reported x.
+    pub constraints: [Constraint; C],
+
+    /// The largest constraint violation at the reported x.
+    ///
+    /// Zero when every constraint is satisfied.
+    pub violation: f64,
+
+    /// Lagrange multiplier estimates for the constraints.
+    ///
+    /// Multipliers of inequality constraints are never negative, and are zero
+    /// for constraints that are inactive at the solution. Their sign follows
+    /// the minimized objective, which is the negated objective when maximizing.
+    pub multipliers: [f64; C],
+
+    /// Snapshot at the reported x.
+    pub snapshot: Snapshot<I, O>,
+
+    /// Outer iteration count when the solver finished.
+    pub iters: usize,
+
+    /// Model evaluations and time spent across every inner solve.
+    pub stats: Stats,
+}
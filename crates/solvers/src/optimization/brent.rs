@@ -0,0 +1,520 @@
+//! Brent's method for single-variable optimization.
+//!
+//! # Algorithm
+//!
+//! Brent's method keeps a bracket around the optimum, like golden section
+//! search, but chooses each new point by fitting a parabola through the three
+//! best points and jumping to its vertex. The parabolic step is only taken when
+//! it lands inside the bracket and is shrinking fast enough; otherwise the
+//! solver falls back to a golden section step. On smooth objectives this
+//! converges superlinearly, and it is never much slower than golden section
+//! search on difficult ones.
+//!
+//! # Shared Types
+//!
+//! Brent's method is a drop-in alternative to [`golden_section`]: it takes the
+//! same `[f64; 2]` bracket and shares its [`Config`], [`Action`], [`Event`],
+//! [`Point`], [`Solution`], and error types, so observers written for golden
+//! section search work unchanged.
+//!
+//! # Observer Events
+//!
+//! The solver evaluates one point inside the bracket to start, then emits one
+//! [`Event`] per evaluation after that. Each event's `other` is the best point
+//! found so far. The starting point emits no event, since there is no `other`
+//! yet.
+//!
+//! If the starting point fails, the solver evaluates the mirror-image golden
+//! section point and emits a failure event for the start with that point as
+//! `other`, so the observer can recover or stop as for any other failure. If
+//! both points fail, there is no point to report, so the error is returned
+//! without an event.
+//!
+//! Observers can return [`Action::StopEarly`] to halt and return the best point,
+//! or [`Action::AssumeWorse`] to treat the point as worse than the best. A point
+//! assumed worse still shrinks the bracket but is never used for a parabolic
+//! fit or reported as the best.
+//!
+//! [`golden_section`]: crate::optimization::golden_section
+
+mod state;
+
+pub use super::golden_section::{
+    Action, Config, ConfigError, Error, Event, Point, Solution, Status,
+};
+
+use twine_core::{Model, Observer, OptimizationProblem};
+
+use crate::stats::Tally;
+
+use super::{
+    evaluate::evaluate,
+    golden_section::search::{EvalOutcome, eval_and_observe},
+};
+
+use state::{GOLDEN, State};
+
+/// Finds the minimum of the objective using Brent's method.
+///
+/// The observer receives an [`Event`] for each evaluation after the first.
+/// See the [module docs](self) for details on event timing and observer actions.
+///
+/// # Errors
+///
+/// Returns an error if the model or problem fails during evaluation
+/// and the observer does not return [`Action::AssumeWorse`] to recover.
+pub fn minimize<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    bracket: [f64; 2],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<1, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P>, Action>,
+{
+    search(model, problem, bracket, config, observer, |v| v)
+}
+
+/// Finds the minimum of the objective without observer support.
+///
+/// This is a convenience wrapper around [`minimize`] that uses a no-op observer.
+///
+/// # Errors
+///
+/// Returns an error if the model or problem fails during evaluation.
+pub fn minimize_unobserved<M, P>(
+    model: &M,
+    problem: &P,
+    bracket: [f64; 2],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    minimize(model, problem, bracket, config, ())
+}
+
+/// Finds the maximum of the objective using Brent's method.
+///
+/// The observer receives an [`Event`] for each evaluation after the first.
+/// See the [module docs](self) for details on event timing and observer actions.
+///
+/// # Errors
+///
+/// Returns an error if the model or problem fails during evaluation
+/// and the observer does not return [`Action::AssumeWorse`] to recover.
+pub fn maximize<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    bracket: [f64; 2],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<1, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P>, Action>,
+{
+    search(model, problem, bracket, config, observer, |v| -v)
+}
+
+/// Finds the maximum of the objective without observer support.
+///
+/// This is a convenience wrapper around [`maximize`] that uses a no-op observer.
+///
+/// # Errors
+///
+/// Returns an error if the model or problem fails during evaluation.
+pub fn maximize_unobserved<M, P>(
+    model: &M,
+    problem: &P,
+    bracket: [f64; 2],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    maximize(model, problem, bracket, config, ())
+}
+
+/// Core Brent search implementation.
+///
+/// The `transform` function is applied to objective values before comparison,
+/// allowing the same algorithm to handle both minimization (identity) and
+/// maximization (negation).
+fn search<M, P, Obs, F>(
+    model: &M,
+    problem: &P,
+    bracket: [f64; 2],
+    config: &Config,
+    mut observer: Obs,
+    transform: F,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<1, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P>, Action>,
+    F: Fn(f64) -> f64,
+{
+    let [a, b] = bracket;
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+
+    // Start at the golden section point of the bracket.
+    let x0 = left + GOLDEN * (right - left);
+    let tally = Tally::start();
+    let result = evaluate(model, problem, [x0]);
+    tally.evaluated(&result);
+    let mut state = match result {
+        Ok(eval) => {
+            let first = Point::from(&eval);
+            State::new(
+                [left, right],
+                first,
+                transform(first.objective),
+                eval.snapshot,
+            )
+        }
+        Err(err) => {
+            // Fall back to the mirror-image point, which gives the failure
+            // event a real `other` and the search somewhere to start.
+            let x1 = right - GOLDEN * (right - left);
+            let result = evaluate(model, problem, [x1]);
+            tally.evaluated(&result);
+            let Ok(eval) = result else {
+                return Err(err.into());
+            };
+
+            let first = Point::from(&eval);
+            let action = Event::emit_failure(x0, first, &err, &mut observer);
+            tally.observed(action.as_ref());
+            let mut state = State::new(
+                [left, right],
+                first,
+                transform(first.objective),
+                eval.snapshot,
+            );
+            match action {
+                Some(Action::StopEarly) => {
+                    return Ok(state.into_solution(Status::StoppedByObserver, 0, tally.stats()));
+                }
+                Some(Action::AssumeWorse) => state.update(x0, f64::INFINITY, None),
+                None => return Err(err.into()),
+            }
+            state
+        }
+    };
+
+    for iter in 1..=config.max_iters() {
+        let Some(x) = state.propose(config.x_abs_tol(), config.x_rel_tol()) else {
+            return Ok(state.into_solution(Status::Converged, iter - 1, tally.stats()));
+        };
+
+        match eval_and_observe(model, problem, x, state.best(), &mut observer, &tally)? {
+            EvalOutcome::Continue { point, snapshot } => {
+                state.update(x, transform(point.objective), Some((point, snapshot)));
+            }
+            EvalOutcome::AssumeWorse => state.update(x, f64::INFINITY, None),
+            EvalOutcome::StopEarly => {
+                return Ok(state.into_solution(Status::StoppedByObserver, iter, tally.stats()));
+            }
+        }
+    }
+
+    Ok(state.into_solution(Status::MaxIters, config.max_iters(), tally.stats()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use thiserror::Error;
+
+    use crate::optimization::golden_section;
+
+    /// A simple polynomial: f(x) = x³ - 3x.
+    /// Local min at x = 1 (value = -2), local max at x = -1 (value = 2).
+    struct Cubic;
+
+    impl Model for Cubic {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, x: &f64) -> Result<f64, Self::Error> {
+            Ok(x.powi(3) - 3.0 * x)
+        }
+    }
+
+    /// Objective: just use the model output as the objective.
+    struct ObjectiveOutput;
+
+    impl OptimizationProblem<1> for ObjectiveOutput {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+            Ok(x[0])
+        }
+
+        fn objective(&self, _input: &f64, output: &f64) -> Result<f64, Self::Error> {
+            Ok(*output)
+        }
+    }
+
+    #[derive(Debug, Error)]
+    #[error("model fails above {0}")]
+    struct Unstable(f64);
+
+    /// f(x) = (x - 1)², but the model fails above a threshold.
+    struct FailsAbove {
+        threshold: f64,
+    }
+
+    impl Model for FailsAbove {
+        type Input = f64;
+        type Output = f64;
+        type Error = Unstable;
+
+        fn call(&self, x: &f64) -> Result<f64, Self::Error> {
+            if *x > self.threshold {
+                Err(Unstable(self.threshold))
+            } else {
+                Ok((x - 1.0).powi(2))
+            }
+        }
+    }
+
+    /// f(x) = (x - 1)², but the model fails below a threshold.
+    struct FailsBelow {
+        threshold: f64,
+    }
+
+    impl Model for FailsBelow {
+        type Input = f64;
+        type Output = f64;
+        type Error = Unstable;
+
+        fn call(&self, x: &f64) -> Result<f64, Self::Error> {
+            if *x < self.threshold {
+                Err(Unstable(self.threshold))
+            } else {
+                Ok((x - 1.0).powi(2))
+            }
+        }
+    }
+
+    #[test]
+    fn minimizes_cubic() {
+        let solution =
+            minimize_unobserved(&Cubic, &ObjectiveOutput, [-2.0, 2.0], &Config::default())
+                .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x, 1.0, epsilon = 1e-8);
+        assert_relative_eq!(solution.objective, -2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn maximizes_cubic() {
+        let solution =
+            maximize_unobserved(&Cubic, &ObjectiveOutput, [-2.0, 2.0], &Config::default())
+                .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x, -1.0, epsilon = 1e-8);
+        assert_relative_eq!(solution.objective, 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn reversed_bracket_is_accepted() {
+        let solution =
+            minimize_unobserved(&Cubic, &ObjectiveOutput, [2.0, -2.0], &Config::default())
+                .expect("should converge");
+
+        assert_relative_eq!(solution.x, 1.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn needs_fewer_iterations_than_golden_section() {
+        let config = Config::new(200, 1e-10, 0.0).unwrap();
+
+        let brent = minimize_unobserved(&Cubic, &ObjectiveOutput, [0.0, 3.0], &config)
+            .expect("should converge");
+        let golden =
+            golden_section::minimize_unobserved(&Cubic, &ObjectiveOutput, [0.0, 3.0], &config)
+                .expect("should converge");
+
+        assert_eq!(brent.status, Status::Converged);
+        assert_eq!(golden.status, Status::Converged);
+        assert!(
+            2 * brent.iters < golden.iters,
+            "brent = {}, golden = {}",
+            brent.iters,
+            golden.iters
+        );
+    }
+
+    #[test]
+    fn other_is_the_best_point_so_far() {
+        let mut best = f64::INFINITY;
+        let observer = |event: &Event<'_, _, _>| {
+            let other = event.other();
+            assert!(other.objective <= best);
+            best = other.objective;
+            if let Event::Evaluated { point, .. } = event {
+                best = best.min(point.objective);
+            }
+            None
+        };
+
+        minimize(
+            &Cubic,
+            &ObjectiveOutput,
+            [-2.0, 2.0],
+            &Config::default(),
+            observer,
+        )
+        .expect("should converge");
+    }
+
+    #[test]
+    fn observer_can_stop_early() {
+        let mut evaluations = 0;
+        let observer = |_: &Event<'_, _, _>| {
+            evaluations += 1;
+            (evaluations == 3).then_some(Action::StopEarly)
+        };
+
+        let solution = minimize(
+            &Cubic,
+            &ObjectiveOutput,
+            [-2.0, 2.0],
+            &Config::default(),
+            observer,
+        )
+        .expect("should stop cleanly");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.iters, 3);
+    }
+
+    #[test]
+    fn assume_worse_recovers_from_failures() {
+        let model = FailsAbove { threshold: 2.0 };
+        let observer = |event: &Event<'_, _, _>| {
+            matches!(event, Event::ModelFailed { .. }).then_some(Action::AssumeWorse)
+        };
+
+        let solution = minimize(
+            &model,
+            &ObjectiveOutput,
+            [0.0, 4.0],
+            &Config::default(),
+            observer,
+        )
+        .expect("should recover");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x, 1.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn unrecovered_failure_is_an_error() {
+        let model = FailsAbove { threshold: 2.0 };
+
+        let result = minimize_unobserved(&model, &ObjectiveOutput, [0.0, 4.0], &Config::default());
+
+        assert!(matches!(result, Err(Error::Model(_))));
+    }
+
+    #[test]
+    fn failed_start_is_reported_against_fallback_point() {
+        // The start at 1.53 fails, and the fallback at 2.47 succeeds.
+        let model = FailsBelow { threshold: 2.0 };
+        let mut failures = Vec::new();
+        let observer = |event: &Event<'_, _, _>| {
+            if let Event::ModelFailed { x, other, .. } = event {
+                failures.push((*x, other.x));
+            }
+            Some(Action::StopEarly)
+        };
+
+        let solution = minimize(
+            &model,
+            &ObjectiveOutput,
+            [0.0, 4.0],
+            &Config::default(),
+            observer,
+        )
+        .expect("should stop cleanly");
+
+        assert_eq!(failures.len(), 1);
+        assert_relative_eq!(failures[0].0, 4.0 * GOLDEN);
+        assert_relative_eq!(failures[0].1, 4.0 - 4.0 * GOLDEN);
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.iters, 0);
+        assert_relative_eq!(solution.x, 4.0 - 4.0 * GOLDEN);
+    }
+
+    #[test]
+    fn assume_worse_recovers_from_failed_start() {
+        let model = FailsBelow { threshold: 2.0 };
+        let observer = |event: &Event<'_, _, _>| {
+            matches!(event, Event::ModelFailed { .. }).then_some(Action::AssumeWorse)
+        };
+
+        let solution = minimize(
+            &model,
+            &ObjectiveOutput,
+            [0.0, 4.0],
+            &Config::default(),
+            observer,
+        )
+        .expect("should recover");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x, 2.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn failed_start_and_fallback_is_an_error_without_event() {
+        let model = FailsAbove { threshold: -1.0 };
+        let mut notified = false;
+        let observer = |_: &Event<'_, _, _>| {
+            notified = true;
+            None
+        };
+
+        let result = minimize(
+            &model,
+            &ObjectiveOutput,
+            [0.0, 4.0],
+            &Config::default(),
+            observer,
+        );
+
+        assert!(matches!(result, Err(Error::Model(_))));
+        assert!(!notified);
+    }
+
+    #[test]
+    fn zero_iters_returns_starting_point() {
+        let config = Config::new(0, 1e-12, 1e-12).unwrap();
+
+        let solution = minimize_unobserved(&Cubic, &ObjectiveOutput, [0.0, 1.0], &config)
+            .expect("should return start");
+
+        assert_eq!(solution.status, Status::MaxIters);
+        assert_eq!(solution.iters, 0);
+        assert_relative_eq!(solution.x, GOLDEN);
+    }
+}
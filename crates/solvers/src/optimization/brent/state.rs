@@ -0,0 +1,261 @@
+use twine_core::Snapshot;
+
+use crate::{
+    Stats,
+    optimization::golden_section::{Point, Solution, Status},
+};
+
+/// The golden section fraction: (3 - √5) / 2 = 1 - φ⁻¹.
+pub(super) const GOLDEN: f64 = 0.381_966_011_250_105_1;
+
+/// A previously evaluated point, scored so that lower is better.
+#[derive(Debug, Clone, Copy)]
+struct Scored {
+    x: f64,
+    score: f64,
+}
+
+/// Iteration state for Brent's method.
+///
+/// Follows the classic bookkeeping from Brent's `localmin`:
+/// - `[a, b]` is the bracket known to contain the optimum,
+/// - `best` is the best point so far,
+/// - `w` is the second best and `v` the previous value of `w`,
+/// - `d` and `e` are the last two step sizes, used to reject parabolic steps
+///   that are not shrinking fast enough.
+///
+/// Scores are transformed objectives (negated when maximizing), so the state
+/// always minimizes. A point the observer marks as worse has an infinite
+/// score, which keeps it out of the best tracking and out of parabolic fits.
+pub(super) struct State<I, O> {
+    a: f64,
+    b: f64,
+    best: Point,
+    best_score: f64,
+    snapshot: Snapshot<I, O>,
+    w: Scored,
+    v: Scored,
+    d: f64,
+    e: f64,
+}
+
+impl<I, O> State<I, O> {
+    /// Creates the state from the bracket and the first evaluated point.
+    pub(super) fn new(
+        bracket: [f64; 2],
+        best: Point,
+        score: f64,
+        snapshot: Snapshot<I, O>,
+    ) -> Self {
+        let [a, b] = bracket;
+        let first = Scored { x: best.x, score };
+        Self {
+            a,
+            b,
+            best,
+            best_score: score,
+            snapshot,
+            w: first,
+            v: first,
+            d: 0.0,
+            e: 0.0,
+        }
+    }
+
+    /// Returns the best point so far.
+    pub(super) fn best(&self) -> Point {
+        self.best
+    }
+
+    /// Chooses the next point to evaluate, or returns `None` if converged.
+    ///
+    /// A parabola through `best`, `w`, and `v` is tried first; its vertex is
+    /// used when it lies inside the bracket and the step is less than half the
+    /// step before last. Otherwise a golden section step is taken into the
+    /// larger side of the bracket. Variable names follow the standard
+    /// presentation of the algorithm.
+    #[allow(clippy::many_single_char_names)]
+    pub(super) fn propose(&mut self, x_abs_tol: f64, x_rel_tol: f64) -> Option<f64> {
+        let (a, b) = (self.a, self.b);
+        let (x, fx) = (self.best.x, self.best_score);
+        let (w, v) = (self.w, self.v);
+
+        let mid = 0.5 * (a + b);
+        let tol = 2.0 * f64::EPSILON * x.abs() + 0.5 * (x_abs_tol + x_rel_tol * x.abs());
+        let tol2 = 2.0 * tol;
+
+        if (x - mid).abs() <= tol2 - 0.5 * (b - a) {
+            return None;
+        }
+
+        let mut parabolic = false;
+        if self.e.abs() > tol {
+            let r = (x - w.x) * (fx - v.score);
+            let q = (x - v.x) * (fx - w.score);
+            let mut p = (x - v.x) * q - (x - w.x) * r;
+            let mut q = 2.0 * (q - r);
+            if q > 0.0 {
+                p = -p;
+            } else {
+                q = -q;
+            }
+
+            let e = self.e;
+            self.e = self.d;
+
+            // Non-finite fits fail every comparison and fall back to golden.
+            if p.abs() < (0.5 * q * e).abs() && p > q * (a - x) && p < q * (b - x) {
+                self.d = p / q;
+                let u = x + self.d;
+                if u - a < tol2 || b - u < tol2 {
+                    self.d = tol.copysign(mid - x);
+                }
+                parabolic = true;
+            }
+        }
+
+        if !parabolic {
+            self.e = if x < mid { b - x } else { a - x };
+            self.d = GOLDEN * self.e;
+        }
+
+        // Never evaluate closer than `tol` to the best point.
+        Some(if self.d.abs() >= tol {
+            x + self.d
+        } else {
+            x + tol.copysign(self.d)
+        })
+    }
+
+    /// Incorporates the point `u` with its score.
+    ///
+    /// `eval` carries the point and snapshot of a successful evaluation and is
+    /// `None` when the observer assumed the point worse.
+    #[allow(clippy::float_cmp)]
+    pub(super) fn update(&mut self, u: f64, score: f64, eval: Option<(Point, Snapshot<I, O>)>) {
+        let x = self.best.x;
+
+        if let Some((point, snapshot)) = eval
+            && score <= self.best_score
+        {
+            if u < x {
+                self.b = x;
+            } else {
+                self.a = x;
+            }
+            self.v = self.w;
+            self.w = Scored {
+                x,
+                score: self.best_score,
+            };
+            self.best = point;
+            self.best_score = score;
+            self.snapshot = snapshot;
+            return;
+        }
+
+        if u < x {
+            self.a = u;
+        } else {
+            self.b = u;
+        }
+
+        let scored = Scored { x: u, score };
+        if score <= self.w.score || self.w.x == x {
+            self.v = self.w;
+            self.w = scored;
+        } else if score <= self.v.score || self.v.x == x || self.v.x == self.w.x {
+            self.v = scored;
+        }
+    }
+
+    #[allow(clippy::similar_names)]
+    pub(super) fn into_solution(
+        self,
+        status: Status,
+        iters: usize,
+        stats: Stats,
+    ) -> Solution<I, O> {
+        Solution {
+            status,
+            x: self.best.x,
+            objective: self.best.objective,
+            snapshot: self.snapshot,
+            iters,
+            stats,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn state_at(x: f64, score: f64) -> State<(), ()> {
+        State::new(
+            [0.0, 1.0],
+            Point::new(x, score),
+            score,
+            Snapshot::new((), ()),
+        )
+    }
+
+    #[test]
+    fn first_step_is_golden() {
+        let x = GOLDEN;
+        let mut state = state_at(x, 0.0);
+
+        let u = state.propose(1e-12, 1e-12).expect("not converged");
+
+        // From x in the left part, step into the larger right side.
+        assert_relative_eq!(u, x + GOLDEN * (1.0 - x));
+    }
+
+    #[test]
+    fn parabolic_step_finds_vertex_of_quadratic() {
+        // f(x) = (x - 0.3)², sampled at three points.
+        let f = |x: f64| (x - 0.3) * (x - 0.3);
+        let mut state = state_at(0.4, f(0.4));
+        state.update(
+            0.6,
+            f(0.6),
+            Some((Point::new(0.6, f(0.6)), Snapshot::new((), ()))),
+        );
+        state.update(
+            0.5,
+            f(0.5),
+            Some((Point::new(0.5, f(0.5)), Snapshot::new((), ()))),
+        );
+
+        // Allow parabolic steps: pretend the previous steps were large.
+        state.d = 0.5;
+        state.e = 0.5;
+
+        let u = state.propose(1e-12, 1e-12).expect("not converged");
+        assert_relative_eq!(u, 0.3, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn worse_point_shrinks_bracket_without_replacing_best() {
+        let mut state = state_at(0.4, 1.0);
+
+        state.update(0.7, f64::INFINITY, None);
+
+        assert_relative_eq!(state.b, 0.7);
+        assert_relative_eq!(state.best().x, 0.4);
+    }
+
+    #[test]
+    fn converges_when_bracket_is_tight() {
+        let mut state = State::new(
+            [1.0 - 1e-13, 1.0 + 1e-13],
+            Point::new(1.0, 0.0),
+            0.0,
+            Snapshot::new((), ()),
+        );
+
+        assert!(state.propose(1e-12, 0.0).is_none());
+    }
+}
@@ -30,6 +30,13 @@ pub type EvaluateResult<M, P, const N: usize> = Result<
     EvalError<<M as Model>::Error, <P as OptimizationProblem<N>>::Error>,
 >;
 
+/// A function that evaluates several points at once, such as `evaluate_batch`.
+///
+/// Solvers that start from independent points take an optional batch function
+/// so their parallel entry points can share the serial implementation.
+pub(crate) type Batch<M, P, const N: usize> =
+    fn(&M, &P, &[[f64; N]]) -> Vec<EvaluateResult<M, P, N>>;
+
 /// Evaluates the model in the context of an optimization problem.
 ///
 /// This function maps `x` to model input, calls the model, then computes
@@ -59,3 +66,23 @@ where
         snapshot: Snapshot::new(input, output),
     })
 }
+
+/// Evaluates the model at each `x` in `xs` across threads.
+///
+/// Results are always in the same order as `xs`.
+#[cfg(feature = "parallel")]
+pub fn evaluate_batch<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    xs: &[[f64; N]],
+) -> Vec<EvaluateResult<M, P, N>>
+where
+    M: Model + Sync,
+    M::Input: Send,
+    M::Output: Send,
+    M::Error: Send,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output> + Sync,
+    P::Error: Send,
+{
+    crate::parallel::map(xs, |&x| evaluate(model, problem, x))
+}
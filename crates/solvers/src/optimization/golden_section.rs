@@ -37,6 +37,13 @@
 //! Observers can return [`Action::StopEarly`] to halt immediately, or
 //! [`Action::AssumeWorse`] to treat the point as worse than `other` (useful for
 //! error recovery or steering the search away from a region).
+//!
+//! # Parallel Evaluation
+//!
+//! The two initial points are independent. With the `parallel` feature,
+//! `minimize_parallel` and `maximize_parallel` evaluate them on separate
+//! threads, which helps when model calls are expensive. They emit the same
+//! events and return the same solution as [`minimize`] and [`maximize`].
 
 mod action;
 mod bracket;
@@ -45,7 +52,7 @@ mod error;
 mod event;
 mod init;
 mod point;
-mod search;
+pub(crate) mod search;
 mod solution;
 mod state;
 
@@ -84,7 +91,7 @@ where
     P: OptimizationProblem<1, Input = M::Input, Output = M::Output>,
     Obs: for<'a> Observer<Event<'a, M, P>, Action>,
 {
-    search(model, problem, bracket, config, observer, |v| v)
+    search(model, problem, bracket, config, observer, |v| v, None)
 }
 
 /// Finds the minimum of the objective without observer support.
@@ -128,7 +135,7 @@ where
     P: OptimizationProblem<1, Input = M::Input, Output = M::Output>,
     Obs: for<'a> Observer<Event<'a, M, P>, Action>,
 {
-    search(model, problem, bracket, config, observer, |v| -v)
+    search(model, problem, bracket, config, observer, |v| -v, None)
 }
 
 /// Finds the maximum of the objective without observer support.
@@ -150,3 +157,77 @@ where
 {
     maximize(model, problem, bracket, config, ())
 }
+
+/// Finds the minimum of the objective, evaluating the two initial points on
+/// separate threads.
+///
+/// Otherwise identical to [`minimize`].
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`minimize`].
+#[cfg(feature = "parallel")]
+pub fn minimize_parallel<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    bracket: [f64; 2],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model + Sync,
+    M::Input: Send,
+    M::Output: Send,
+    M::Error: Send,
+    P: OptimizationProblem<1, Input = M::Input, Output = M::Output> + Sync,
+    P::Error: Send,
+    Obs: for<'a> Observer<Event<'a, M, P>, Action>,
+{
+    let batch = crate::optimization::evaluate_batch;
+    search(
+        model,
+        problem,
+        bracket,
+        config,
+        observer,
+        |v| v,
+        Some(batch),
+    )
+}
+
+/// Finds the maximum of the objective, evaluating the two initial points on
+/// separate threads.
+///
+/// Otherwise identical to [`maximize`].
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`maximize`].
+#[cfg(feature = "parallel")]
+pub fn maximize_parallel<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    bracket: [f64; 2],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model + Sync,
+    M::Input: Send,
+    M::Output: Send,
+    M::Error: Send,
+    P: OptimizationProblem<1, Input = M::Input, Output = M::Output> + Sync,
+    P::Error: Send,
+    Obs: for<'a> Observer<Event<'a, M, P>, Action>,
+{
+    let batch = crate::optimization::evaluate_batch;
+    search(
+        model,
+        problem,
+        bracket,
+        config,
+        observer,
+        |v| -v,
+        Some(batch),
+    )
+}
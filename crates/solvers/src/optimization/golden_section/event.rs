@@ -11,6 +11,9 @@ use super::{Action, Point};
 /// best: the point the solver would keep if it had to choose now. Observers
 /// can compare against `other` to decide whether to stop early or steer the
 /// search with [`Action::AssumeWorse`].
+///
+/// [`brent`](crate::optimization::brent) emits the same events, with `other`
+/// likewise being the current best.
 pub enum Event<'a, M, P>
 where
     M: Model,
@@ -81,7 +84,7 @@ where
     }
 
     /// Emits a failure event and returns the observer's action.
-    pub(super) fn emit_failure<Obs>(
+    pub(crate) fn emit_failure<Obs>(
         x: f64,
         other: Point,
         error: &EvalError<M::Error, P::Error>,
@@ -1,6 +1,9 @@
 use twine_core::{Model, Observer, OptimizationProblem};
 
-use crate::optimization::evaluate::{EvalError, Evaluation, evaluate};
+use crate::{
+    optimization::evaluate::{Batch, EvalError, Evaluation, evaluate},
+    stats::Tally,
+};
 
 use super::{
     Action, Error, Event, Point, Solution, bracket::GoldenBracket, solution::Status, state::State,
@@ -14,6 +17,9 @@ pub(super) enum InitResult<I, O> {
 #[allow(clippy::too_many_lines)]
 /// Initialize state by evaluating both interior points.
 ///
+/// The two points are evaluated one after the other, or together with
+/// `batch` when one is given.
+///
 /// Only the second point (or failure) triggers an observer event.
 /// This is intentional: we need a valid `other` point for the event.
 ///
@@ -26,6 +32,8 @@ pub(super) fn init<M, P, Obs, F>(
     bracket: &GoldenBracket,
     observer: &mut Obs,
     transform: &F,
+    tally: &Tally,
+    batch: Option<Batch<M, P, 1>>,
 ) -> Result<InitResult<M::Input, M::Output>, Error>
 where
     M: Model,
@@ -42,8 +50,26 @@ where
         },
     }
 
-    let left = evaluate(model, problem, [bracket.inner_left]);
-    let right = evaluate(model, problem, [bracket.inner_right]);
+    let (left, right) = match batch {
+        Some(batch) => {
+            let mut results = batch(
+                model,
+                problem,
+                &[[bracket.inner_left], [bracket.inner_right]],
+            )
+            .into_iter();
+            let (Some(left), Some(right)) = (results.next(), results.next()) else {
+                unreachable!("batch evaluation returns one result per point");
+            };
+            (left, right)
+        }
+        None => (
+            evaluate(model, problem, [bracket.inner_left]),
+            evaluate(model, problem, [bracket.inner_right]),
+        ),
+    };
+    tally.evaluated(&left);
+    tally.evaluated(&right);
 
     let outcome = match (left, right) {
         (Err(left_err), Err(_)) => {
@@ -51,7 +77,9 @@ where
             // possible (AssumeWorse needs one valid point, StopEarly needs a
             // snapshot). Use synthetic `other` since both failed.
             let synthetic_other = Point::new(bracket.inner_right, f64::NAN);
-            Event::emit_failure(bracket.inner_left, synthetic_other, &left_err, observer);
+            let action =
+                Event::emit_failure(bracket.inner_left, synthetic_other, &left_err, observer);
+            tally.observed(action.as_ref());
             return Err(left_err.into());
         }
         (Ok(l), Ok(r)) => Outcome::BothOk(l, r),
@@ -77,13 +105,16 @@ where
                 output: &right_eval.snapshot.output,
                 other: left_pt,
             };
-            match observer.observe(&event) {
+            let action = observer.observe(&event);
+            tally.observed(action.as_ref());
+            match action {
                 Some(Action::StopEarly) => Ok(InitResult::StopEarly(Solution {
                     status: Status::StoppedByObserver,
                     x: left_pt.x,
                     objective: left_pt.objective,
                     snapshot: left_eval.snapshot,
                     iters: 0,
+                    stats: tally.stats(),
                 })),
                 Some(Action::AssumeWorse) => {
                     let worse = Point::new(right_pt.x, transform(f64::INFINITY));
@@ -116,6 +147,7 @@ where
         } => {
             let ok_pt = Point::from(&ok_eval);
             let action = Event::emit_failure(failed_x, ok_pt, &err, observer);
+            tally.observed(action.as_ref());
             match action {
                 Some(Action::StopEarly) => Ok(InitResult::StopEarly(Solution {
                     status: Status::StoppedByObserver,
@@ -123,6 +155,7 @@ where
                     objective: ok_pt.objective,
                     snapshot: ok_eval.snapshot,
                     iters: 0,
+                    stats: tally.stats(),
                 })),
                 Some(Action::AssumeWorse) => {
                     let worse = Point::new(failed_x, transform(f64::INFINITY));
@@ -192,8 +225,16 @@ mod tests {
         let problem = ObjectiveIsOutput;
         let bracket = GoldenBracket::new([0.0, 10.0]);
 
-        let result =
-            init(&model, &problem, &bracket, &mut (), &identity_transform).expect("should succeed");
+        let result = init(
+            &model,
+            &problem,
+            &bracket,
+            &mut (),
+            &identity_transform,
+            &Tally::start(),
+            None,
+        )
+        .expect("should succeed");
 
         let state = match result {
             InitResult::Continue(s) => s,
@@ -220,6 +261,8 @@ mod tests {
             &bracket,
             &mut observer,
             &identity_transform,
+            &Tally::start(),
+            None,
         )
         .expect("should succeed");
 
@@ -240,6 +283,8 @@ mod tests {
             &bracket,
             &mut observer,
             &identity_transform,
+            &Tally::start(),
+            None,
         )
         .expect("should succeed");
 
@@ -288,7 +333,15 @@ mod tests {
         let problem = ObjectiveIsOutput;
         let bracket = GoldenBracket::new([0.0, 10.0]);
 
-        let result = init(&model, &problem, &bracket, &mut (), &identity_transform);
+        let result = init(
+            &model,
+            &problem,
+            &bracket,
+            &mut (),
+            &identity_transform,
+            &Tally::start(),
+            None,
+        );
 
         assert!(result.is_err());
     }
@@ -313,6 +366,8 @@ mod tests {
             &bracket,
             &mut observer,
             &identity_transform,
+            &Tally::start(),
+            None,
         )
         .expect("should recover");
 
@@ -346,6 +401,8 @@ mod tests {
             &bracket,
             &mut observer,
             &identity_transform,
+            &Tally::start(),
+            None,
         )
         .expect("should succeed");
 
@@ -360,7 +417,15 @@ mod tests {
         let problem = ObjectiveIsOutput;
         let bracket = GoldenBracket::new([0.0, 10.0]);
 
-        let result = init(&model, &problem, &bracket, &mut (), &identity_transform);
+        let result = init(
+            &model,
+            &problem,
+            &bracket,
+            &mut (),
+            &identity_transform,
+            &Tally::start(),
+            None,
+        );
 
         assert!(result.is_err());
     }
@@ -385,6 +450,8 @@ mod tests {
             &bracket,
             &mut observer,
             &identity_transform,
+            &Tally::start(),
+            None,
         );
         assert!(notified, "observer should be notified when both fail");
     }
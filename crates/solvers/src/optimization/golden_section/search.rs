@@ -1,6 +1,9 @@
 use twine_core::{Model, Observer, OptimizationProblem, Snapshot};
 
-use crate::optimization::evaluate::evaluate;
+use crate::{
+    optimization::evaluate::{Batch, evaluate},
+    stats::Tally,
+};
 
 use super::{
     Action, Config, Error, Event, Point, Solution,
@@ -15,6 +18,8 @@ use super::{
 /// The `objective_transform` function is applied to objective values before
 /// comparison, allowing the same algorithm to handle both minimization
 /// (transform = identity) and maximization (transform = negation).
+///
+/// The interior points are evaluated with `batch` when one is given.
 pub(super) fn search<M, P, Obs, F>(
     model: &M,
     problem: &P,
@@ -22,6 +27,7 @@ pub(super) fn search<M, P, Obs, F>(
     config: &Config,
     mut observer: Obs,
     transform: F,
+    batch: Option<Batch<M, P, 1>>,
 ) -> Result<Solution<M::Input, M::Output>, Error>
 where
     M: Model,
@@ -30,15 +36,24 @@ where
     F: Fn(f64) -> f64,
 {
     let bracket = GoldenBracket::new(bracket);
+    let tally = Tally::start();
 
-    let mut state = match init(model, problem, &bracket, &mut observer, &transform)? {
+    let mut state = match init(
+        model,
+        problem,
+        &bracket,
+        &mut observer,
+        &transform,
+        &tally,
+        batch,
+    )? {
         InitResult::Continue(state) => state,
         InitResult::StopEarly(solution) => return Ok(solution),
     };
 
     for iter in 1..=config.max_iters() {
         if state.is_converged(config) {
-            return Ok(state.into_solution(Status::Converged, iter - 1));
+            return Ok(state.into_solution(Status::Converged, iter - 1, tally.stats()));
         }
 
         let direction = state.next_action(&transform);
@@ -47,13 +62,13 @@ where
             ShrinkDirection::ShrinkRight(x) => (x, state.left()),
         };
 
-        let outcome = eval_and_observe(model, problem, eval_x, other, &mut observer)?;
+        let outcome = eval_and_observe(model, problem, eval_x, other, &mut observer, &tally)?;
 
         let (point, snapshot) = match outcome {
             EvalOutcome::Continue { point, snapshot } => (point, Some(snapshot)),
             EvalOutcome::AssumeWorse => (Point::new(eval_x, transform(f64::INFINITY)), None),
             EvalOutcome::StopEarly => {
-                return Ok(state.into_solution(Status::StoppedByObserver, iter));
+                return Ok(state.into_solution(Status::StoppedByObserver, iter, tally.stats()));
             }
         };
 
@@ -63,14 +78,14 @@ where
         }
     }
 
-    Ok(state.into_solution(Status::MaxIters, config.max_iters()))
+    Ok(state.into_solution(Status::MaxIters, config.max_iters(), tally.stats()))
 }
 
 // ============================================================================
 // Eval + observe helper
 // ============================================================================
 
-enum EvalOutcome<I, O> {
+pub(crate) enum EvalOutcome<I, O> {
     Continue {
         point: Point,
         snapshot: Snapshot<I, O>,
@@ -80,19 +95,25 @@ enum EvalOutcome<I, O> {
 }
 
 /// Evaluate at `x`, emit event, and handle observer action.
-fn eval_and_observe<M, P, Obs>(
+///
+/// The evaluation and any observer action are recorded in `tally`.
+pub(crate) fn eval_and_observe<M, P, Obs>(
     model: &M,
     problem: &P,
     x: f64,
     other: Point,
     observer: &mut Obs,
+    tally: &Tally,
 ) -> Result<EvalOutcome<M::Input, M::Output>, Error>
 where
     M: Model,
     P: OptimizationProblem<1, Input = M::Input, Output = M::Output>,
     Obs: for<'a> Observer<Event<'a, M, P>, Action>,
 {
-    match evaluate(model, problem, [x]) {
+    let result = evaluate(model, problem, [x]);
+    tally.evaluated(&result);
+
+    match result {
         Ok(eval) => {
             let point = Point::from(&eval);
             let event = Event::Evaluated {
@@ -101,7 +122,9 @@ where
                 output: &eval.snapshot.output,
                 other,
             };
-            match observer.observe(&event) {
+            let action = observer.observe(&event);
+            tally.observed(action.as_ref());
+            match action {
                 Some(Action::StopEarly) => Ok(EvalOutcome::StopEarly),
                 Some(Action::AssumeWorse) => Ok(EvalOutcome::AssumeWorse),
                 None => Ok(EvalOutcome::Continue {
@@ -112,6 +135,7 @@ where
         }
         Err(e) => {
             let action = Event::emit_failure(x, other, &e, observer);
+            tally.observed(action.as_ref());
             match action {
                 Some(Action::StopEarly) => Ok(EvalOutcome::StopEarly),
                 Some(Action::AssumeWorse) => Ok(EvalOutcome::AssumeWorse),
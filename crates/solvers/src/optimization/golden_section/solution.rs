@@ -1,5 +1,7 @@
 use twine_core::Snapshot;
 
+use crate::Stats;
+
 /// Indicates whether the solver converged or hit the iteration limit.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -30,4 +32,7 @@ pub struct Solution<I, O> {
 
     /// Iteration count when the solver finished.
     pub iters: usize,
+
+    /// Model evaluations and time spent, including initialization.
+    pub stats: Stats,
 }
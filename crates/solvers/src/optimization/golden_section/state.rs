@@ -1,5 +1,7 @@
 use twine_core::Snapshot;
 
+use crate::Stats;
+
 use super::bracket::GoldenBracket;
 use super::solution::Status;
 use super::{Config, Point, Solution};
@@ -106,13 +108,20 @@ impl<I, O> State<I, O> {
         gap <= config.x_abs_tol() + config.x_rel_tol() * x_ref
     }
 
-    pub(super) fn into_solution(self, status: Status, iters: usize) -> Solution<I, O> {
+    #[allow(clippy::similar_names)]
+    pub(super) fn into_solution(
+        self,
+        status: Status,
+        iters: usize,
+        stats: Stats,
+    ) -> Solution<I, O> {
         Solution {
             status,
             x: self.best_point.x,
             objective: self.best_point.objective,
             snapshot: self.best_snapshot,
             iters,
+            stats,
         }
     }
 }
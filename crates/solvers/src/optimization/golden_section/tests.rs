@@ -64,6 +64,23 @@ fn maximizes_cubic() {
     assert_relative_eq!(solution.x, -1.0, epsilon = 1e-8);
 }
 
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_matches_serial() {
+    use super::{maximize, maximize_parallel};
+
+    let config = Config::default();
+
+    let serial =
+        maximize(&Cubic, &ObjectiveOutput, [-2.0, 2.0], &config, ()).expect("should converge");
+    let parallel = maximize_parallel(&Cubic, &ObjectiveOutput, [-2.0, 2.0], &config, ())
+        .expect("should converge");
+
+    assert_eq!(parallel.x.to_bits(), serial.x.to_bits());
+    assert_eq!(parallel.iters, serial.iters);
+    assert_eq!(parallel.stats.evaluations, serial.stats.evaluations);
+}
+
 /// Identity model: f(x) = x.
 struct Identity;
 
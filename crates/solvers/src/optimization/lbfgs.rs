@@ -0,0 +1,436 @@
+//! Limited-memory BFGS (L-BFGS) for smooth multi-variable optimization.
+//!
+//! # Algorithm
+//!
+//! Each iteration moves along a quasi-Newton direction `d = -H g`, where `g`
+//! is the objective gradient and `H` approximates the inverse Hessian from the
+//! last [`memory`](Config::memory) steps and gradient changes. The step length
+//! comes from a line search satisfying the (weak) Wolfe conditions:
+//!
+//! ```text
+//! f(x + t d) <= f(x) + c1 t g·d     (sufficient decrease)
+//! g(x + t d)·d >= c2 g·d             (curvature)
+//! ```
+//!
+//! The curvature condition keeps `H` positive definite, so every direction is
+//! a descent direction. With an empty memory the direction is steepest
+//! descent; if a line search fails with a non-empty memory, the memory is
+//! cleared and the iteration retried from steepest descent.
+//!
+//! # Gradients
+//!
+//! Gradients come from [`OptimizationProblem::gradient`] when the problem
+//! implements it. Otherwise they are approximated by finite differences
+//! through [`evaluate`](crate::optimization::evaluate), costing `N` (forward)
+//! or `2N` (central) extra evaluations per gradient.
+//!
+//! # When to Use
+//!
+//! L-BFGS is appropriate when:
+//! - The objective is smooth (continuously differentiable)
+//! - A reasonable initial guess is available
+//!
+//! It converges superlinearly near a minimum and scales to many variables. For
+//! noisy or non-smooth objectives, prefer [`nelder_mead`](super::nelder_mead).
+//! This solver does not support bounds; use [`lbfgsb`](super::lbfgsb) with a
+//! [`BoundedProblem`] when the model must stay within them.
+//!
+//! # Convergence
+//!
+//! The solver converges when either:
+//! - Every gradient component magnitude is within `gradient_tol`, or
+//! - The step satisfies `max|Δx| <= x_abs_tol + x_rel_tol * max|x|`.
+//!
+//! # Observer Events
+//!
+//! The solver emits an [`Event`] for each evaluation and each accepted iterate:
+//!
+//! - [`Event::Trial`] — evaluation along the search direction (iteration 0 is
+//!   the initial guess)
+//! - [`Event::Gradient`] — perturbed evaluation for a finite difference
+//!   gradient component
+//! - [`Event::Iterate`] — an accepted iterate with its gradient; use
+//!   [`Event::gradient_norm`] to detect stagnation
+//!
+//! Observers can return [`Action::StopEarly`] to halt and return the current
+//! iterate.
+//!
+//! [`OptimizationProblem::gradient`]: twine_core::OptimizationProblem::gradient
+//! [`BoundedProblem`]: twine_core::BoundedProblem
+
+mod action;
+mod config;
+mod error;
+mod event;
+mod gradient;
+mod memory;
+pub(crate) mod search;
+mod solution;
+
+pub use action::Action;
+pub use config::{Config, ConfigError};
+pub use error::Error;
+pub use event::Event;
+pub use solution::{Solution, Status};
+
+pub use crate::equation::newton::Difference;
+
+use twine_core::{Bound, Model, Observer, OptimizationProblem};
+
+/// Finds a minimum of the objective using L-BFGS.
+///
+/// The observer receives an [`Event`] for each evaluation and accepted iterate.
+/// See the [module docs](self) for details on convergence and events.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, or if the model or problem
+/// fails during evaluation.
+pub fn minimize<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    search::search(
+        model,
+        problem,
+        x0,
+        &[Bound::UNBOUNDED; N],
+        config,
+        observer,
+        1.0,
+    )
+}
+
+/// Finds a minimum of the objective without observer support.
+///
+/// This is a convenience wrapper around [`minimize`] that uses a no-op observer.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, or if the model or problem
+/// fails during evaluation.
+pub fn minimize_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    minimize(model, problem, x0, config, ())
+}
+
+/// Finds a maximum of the objective using L-BFGS.
+///
+/// The observer receives an [`Event`] for each evaluation and accepted iterate.
+/// See the [module docs](self) for details on convergence and events.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, or if the model or problem
+/// fails during evaluation.
+pub fn maximize<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    search::search(
+        model,
+        problem,
+        x0,
+        &[Bound::UNBOUNDED; N],
+        config,
+        observer,
+        -1.0,
+    )
+}
+
+/// Finds a maximum of the objective without observer support.
+///
+/// This is a convenience wrapper around [`maximize`] that uses a no-op observer.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, or if the model or problem
+/// fails during evaluation.
+pub fn maximize_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    maximize(model, problem, x0, config, ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use thiserror::Error;
+
+    // --- Test fixtures ---
+
+    /// Rosenbrock's function, with its minimum of 0 at `(1, 1)`.
+    struct Rosenbrock;
+
+    impl Model for Rosenbrock {
+        type Input = [f64; 2];
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, &[x, y]: &[f64; 2]) -> Result<f64, Infallible> {
+            Ok((1.0 - x).powi(2) + 100.0 * (y - x * x).powi(2))
+        }
+    }
+
+    /// An ill-conditioned quadratic `Σ w_i (x_i - 1)²` in five variables.
+    struct Valley;
+
+    const VALLEY_WEIGHTS: [f64; 5] = [1.0, 3.0, 10.0, 30.0, 100.0];
+
+    impl Model for Valley {
+        type Input = [f64; 5];
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, x: &[f64; 5]) -> Result<f64, Infallible> {
+            Ok(x.iter()
+                .zip(VALLEY_WEIGHTS)
+                .map(|(x, w)| w * (x - 1.0).powi(2))
+                .sum())
+        }
+    }
+
+    /// Maps solver variables directly to model inputs.
+    struct Direct;
+
+    impl<const N: usize> OptimizationProblem<N> for Direct {
+        type Input = [f64; N];
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; N]) -> Result<[f64; N], Infallible> {
+            Ok(*x)
+        }
+
+        fn objective(&self, _: &[f64; N], output: &f64) -> Result<f64, Infallible> {
+            Ok(*output)
+        }
+    }
+
+    /// Rosenbrock with an analytic gradient.
+    struct WithGradient;
+
+    impl OptimizationProblem<2> for WithGradient {
+        type Input = [f64; 2];
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok(*x)
+        }
+
+        fn objective(&self, _: &[f64; 2], output: &f64) -> Result<f64, Infallible> {
+            Ok(*output)
+        }
+
+        fn gradient(
+            &self,
+            &[x, y]: &[f64; 2],
+            _: &[f64; 2],
+            _: &f64,
+        ) -> Result<Option<[f64; 2]>, Infallible> {
+            Ok(Some([
+                -2.0 * (1.0 - x) - 400.0 * x * (y - x * x),
+                200.0 * (y - x * x),
+            ]))
+        }
+    }
+
+    #[derive(Debug, Error)]
+    #[error("negative input")]
+    struct NegativeInput;
+
+    /// `(x + 2)²`, failing for negative x, so the minimum is outside the domain.
+    struct PositiveOnly;
+
+    impl Model for PositiveOnly {
+        type Input = [f64; 1];
+        type Output = f64;
+        type Error = NegativeInput;
+
+        fn call(&self, &[x]: &[f64; 1]) -> Result<f64, NegativeInput> {
+            if x < 0.0 {
+                return Err(NegativeInput);
+            }
+            Ok((x + 2.0).powi(2))
+        }
+    }
+
+    // --- Tests ---
+
+    #[test]
+    fn minimizes_rosenbrock_with_finite_differences() {
+        let solution = minimize_unobserved(&Rosenbrock, &Direct, [-1.2, 1.0], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(solution.x[1], 1.0, epsilon = 1e-5);
+        assert!(solution.iters < 100, "iters = {}", solution.iters);
+    }
+
+    #[test]
+    fn uses_analytic_gradient_when_provided() {
+        let mut gradient_events = 0;
+
+        let solution = minimize(
+            &Rosenbrock,
+            &WithGradient,
+            [-1.2, 1.0],
+            &Config::default(),
+            |event: &Event<'_, Rosenbrock, WithGradient, 2>| {
+                if let Event::Gradient { .. } = event {
+                    gradient_events += 1;
+                }
+                None
+            },
+        )
+        .expect("should converge");
+
+        assert_eq!(gradient_events, 0);
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-6);
+        assert!(solution.gradient.is_some_and(|g| g[0].abs() <= 1e-6));
+    }
+
+    #[test]
+    fn minimizes_ill_conditioned_quadratic() {
+        let solution = minimize_unobserved(&Valley, &Direct, [0.0; 5], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        for x in solution.x {
+            assert_relative_eq!(x, 1.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn maximizes_negated_objective() {
+        struct Negated;
+
+        impl OptimizationProblem<2> for Negated {
+            type Input = [f64; 2];
+            type Output = f64;
+            type Error = Infallible;
+
+            fn input(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+                Ok(*x)
+            }
+
+            fn objective(&self, _: &[f64; 2], output: &f64) -> Result<f64, Infallible> {
+                Ok(-output)
+            }
+        }
+
+        let solution = maximize_unobserved(&Rosenbrock, &Negated, [-1.2, 1.0], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-5);
+        assert!(solution.objective <= 0.0);
+    }
+
+    #[test]
+    fn iterate_events_report_decreasing_objective() {
+        let mut iterates = Vec::new();
+
+        minimize(
+            &Rosenbrock,
+            &Direct,
+            [-1.2, 1.0],
+            &Config::default(),
+            |event: &Event<'_, Rosenbrock, Direct, 2>| {
+                if let Event::Iterate { objective, .. } = event {
+                    iterates.push((*objective, event.gradient_norm().expect("iterate")));
+                }
+                None
+            },
+        )
+        .expect("should converge");
+
+        assert!(iterates.windows(2).all(|pair| pair[1].0 <= pair[0].0));
+        assert!(iterates.last().is_some_and(|(_, norm)| *norm <= 1e-6));
+    }
+
+    #[test]
+    fn observer_can_stop_on_stagnation() {
+        let solution = minimize(
+            &Rosenbrock,
+            &Direct,
+            [-1.2, 1.0],
+            &Config::default(),
+            |event: &Event<'_, Rosenbrock, Direct, 2>| {
+                event
+                    .gradient_norm()
+                    .is_some_and(|norm| norm < 1.0)
+                    .then_some(Action::StopEarly)
+            },
+        )
+        .expect("should stop");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert!(
+            solution
+                .gradient
+                .is_some_and(|g| g.iter().all(|v| v.abs() < 1.0))
+        );
+    }
+
+    #[test]
+    fn model_error_is_returned() {
+        // The search heads toward x = -2 and soon evaluates a negative x.
+        let result = minimize_unobserved(&PositiveOnly, &Direct, [1.0], &Config::default());
+
+        assert!(matches!(result, Err(Error::Model(_))));
+    }
+
+    #[test]
+    fn max_iters_reports_current_iterate() {
+        let config = Config {
+            max_iters: 2,
+            ..Config::default()
+        };
+
+        let solution =
+            minimize_unobserved(&Rosenbrock, &Direct, [-1.2, 1.0], &config).expect("should finish");
+
+        assert_eq!(solution.status, Status::MaxIters);
+        assert_eq!(solution.iters, 2);
+        assert!(solution.objective < Rosenbrock.call(&[-1.2, 1.0]).unwrap());
+    }
+}
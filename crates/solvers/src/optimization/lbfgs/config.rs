@@ -0,0 +1,139 @@
+use thiserror::Error;
+
+use crate::equation::newton::Difference;
+
+/// Configuration for the L-BFGS solver.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    /// Maximum number of line searches, including retries from steepest
+    /// descent.
+    pub max_iters: usize,
+    /// Converge when every projected gradient component magnitude is within this tolerance.
+    pub gradient_tol: f64,
+    /// Absolute tolerance on the step size (infinity norm).
+    pub x_abs_tol: f64,
+    /// Relative tolerance on the step size, scaled by `max|x|`.
+    pub x_rel_tol: f64,
+    /// Number of recent steps used to approximate the inverse Hessian.
+    ///
+    /// A memory of 1 approaches steepest descent with scaling; values of
+    /// 5 to 20 are typical.
+    pub memory: usize,
+    /// Sufficient decrease parameter `c1` of the Wolfe conditions.
+    pub wolfe_c1: f64,
+    /// Curvature parameter `c2` of the Wolfe conditions.
+    pub wolfe_c2: f64,
+    /// Maximum trial points per line search.
+    pub max_line_search_iters: usize,
+    /// Finite difference scheme used when the problem provides no gradient.
+    pub difference: Difference,
+    /// Relative perturbation size for finite differences.
+    ///
+    /// Each variable is perturbed by `fd_step * max(|x_j|, 1)`.
+    pub fd_step: f64,
+}
+
+/// Errors that can occur when validating an L-BFGS config.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("gradient_tol must be finite and non-negative")]
+    Gradient,
+
+    #[error("x_abs_tol must be finite and non-negative")]
+    XAbs,
+
+    #[error("x_rel_tol must be finite and non-negative")]
+    XRel,
+
+    #[error("memory must be at least 1")]
+    Memory,
+
+    #[error("Wolfe parameters must satisfy 0 < wolfe_c1 < wolfe_c2 < 1")]
+    Wolfe,
+
+    #[error("max_line_search_iters must be at least 1")]
+    LineSearchIters,
+
+    #[error("fd_step must be finite and positive")]
+    FdStep,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_iters: 200,
+            gradient_tol: 1e-6,
+            x_abs_tol: 1e-12,
+            x_rel_tol: 1e-12,
+            memory: 10,
+            wolfe_c1: 1e-4,
+            wolfe_c2: 0.9,
+            max_line_search_iters: 30,
+            difference: Difference::Central,
+            fd_step: f64::EPSILON.cbrt(),
+        }
+    }
+}
+
+impl Config {
+    /// Validates the tolerances, line search parameters, and finite difference step.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any tolerance is negative or non-finite, if the
+    /// memory or line search iteration limit is zero, if the Wolfe parameters
+    /// are out of order, or if `fd_step` is not finite and positive.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !self.gradient_tol.is_finite() || self.gradient_tol < 0.0 {
+            return Err(ConfigError::Gradient);
+        }
+        if !self.x_abs_tol.is_finite() || self.x_abs_tol < 0.0 {
+            return Err(ConfigError::XAbs);
+        }
+        if !self.x_rel_tol.is_finite() || self.x_rel_tol < 0.0 {
+            return Err(ConfigError::XRel);
+        }
+        if self.memory == 0 {
+            return Err(ConfigError::Memory);
+        }
+        if !(0.0 < self.wolfe_c1 && self.wolfe_c1 < self.wolfe_c2 && self.wolfe_c2 < 1.0) {
+            return Err(ConfigError::Wolfe);
+        }
+        if self.max_line_search_iters == 0 {
+            return Err(ConfigError::LineSearchIters);
+        }
+        if !self.fd_step.is_finite() || self.fd_step <= 0.0 {
+            return Err(ConfigError::FdStep);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_misordered_wolfe_parameters() {
+        let config = Config {
+            wolfe_c1: 0.5,
+            wolfe_c2: 0.1,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Wolfe));
+    }
+
+    #[test]
+    fn rejects_zero_memory() {
+        let config = Config {
+            memory: 0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Memory));
+    }
+}
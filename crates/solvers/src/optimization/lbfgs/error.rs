@@ -0,0 +1,39 @@
+use std::error::Error as StdError;
+
+use thiserror::Error;
+
+use crate::optimization::EvalError;
+
+use super::config::ConfigError;
+
+/// Errors that can occur during L-BFGS optimization.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("invalid config: {0}")]
+    InvalidConfig(#[from] ConfigError),
+
+    #[error("bounds must satisfy lower <= upper")]
+    InvalidBounds,
+
+    #[error("no successful evaluations")]
+    NoSuccessfulEvaluation,
+
+    #[error("problem error")]
+    Problem(#[source] Box<dyn StdError + Send + Sync>),
+
+    #[error("model call failed")]
+    Model(#[source] Box<dyn StdError + Send + Sync>),
+}
+
+impl<ME, PE> From<EvalError<ME, PE>> for Error
+where
+    ME: StdError + Send + Sync + 'static,
+    PE: StdError + Send + Sync + 'static,
+{
+    fn from(err: EvalError<ME, PE>) -> Self {
+        match err {
+            EvalError::Model(e) => Self::Model(Box::new(e)),
+            EvalError::Problem(e) => Self::Problem(Box::new(e)),
+        }
+    }
+}
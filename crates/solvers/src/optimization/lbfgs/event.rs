@@ -0,0 +1,82 @@
+use twine_core::{Model, OptimizationProblem};
+
+use crate::{linalg, optimization::EvaluateResult};
+
+/// Events emitted by the L-BFGS solver.
+///
+/// Objectives and gradients in events are always those of the problem, even
+/// when maximizing.
+pub enum Event<'a, M, P, const N: usize>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    /// Evaluation of a point along the search direction.
+    ///
+    /// Iteration 0 is the initial guess, evaluated with a step of 0.
+    Trial {
+        /// The iteration whose line search produced this point.
+        iter: usize,
+        /// The step length along the search direction.
+        step: f64,
+        /// The x value that was evaluated.
+        x: [f64; N],
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, N>,
+    },
+    /// Perturbed evaluation used to approximate a gradient component.
+    ///
+    /// Only emitted when the problem provides no analytic gradient.
+    Gradient {
+        /// The gradient component (solver variable) being perturbed.
+        component: usize,
+        /// The x value that was evaluated.
+        x: [f64; N],
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, N>,
+    },
+    /// An accepted iterate, with its gradient.
+    ///
+    /// Iteration 0 is the initial guess.
+    Iterate {
+        /// The iteration that produced this iterate.
+        iter: usize,
+        /// The iterate.
+        x: [f64; N],
+        /// Objective value at the iterate.
+        objective: f64,
+        /// Objective gradient at the iterate.
+        gradient: [f64; N],
+        /// The gradient with components zeroed where a bound blocks descent.
+        ///
+        /// Equal to `gradient` when the problem is unbounded.
+        projected_gradient: [f64; N],
+    },
+}
+
+impl<M, P, const N: usize> Event<'_, M, P, N>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    /// Returns the x value of this event.
+    #[must_use]
+    pub fn x(&self) -> [f64; N] {
+        match self {
+            Event::Trial { x, .. } | Event::Gradient { x, .. } | Event::Iterate { x, .. } => *x,
+        }
+    }
+
+    /// Returns the largest projected gradient component magnitude at an iterate.
+    ///
+    /// Returns `None` for trial and gradient evaluations.
+    #[must_use]
+    pub fn gradient_norm(&self) -> Option<f64> {
+        match self {
+            Event::Iterate {
+                projected_gradient, ..
+            } => Some(linalg::max_abs(projected_gradient)),
+            Event::Trial { .. } | Event::Gradient { .. } => None,
+        }
+    }
+}
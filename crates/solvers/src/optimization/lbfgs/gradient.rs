@@ -0,0 +1,137 @@
+use twine_core::Bound;
+
+use crate::equation::newton::{Difference, jacobian::Perturbation};
+
+/// Returns the points needed to approximate the gradient at `x` without
+/// leaving `bounds`.
+///
+/// Each variable is perturbed by `h = fd_step * max(|x_j|, 1)`. Central
+/// differences fall back to a one-sided difference when only one side lies
+/// within bounds, and forward differences step backward when the forward point
+/// would leave them. A variable with no room on either side gets no
+/// perturbation, and so a zero gradient component.
+///
+/// Perturbations are ordered by column; central differences emit the forward
+/// point before the backward point for each column.
+pub(super) fn perturbations<const N: usize>(
+    x: &[f64; N],
+    bounds: &[Bound; N],
+    difference: Difference,
+    fd_step: f64,
+) -> Vec<Perturbation<N>> {
+    let mut points = Vec::with_capacity(match difference {
+        Difference::Forward => N,
+        Difference::Central => 2 * N,
+    });
+
+    for column in 0..N {
+        let h = fd_step * x[column].abs().max(1.0);
+        let bound = bounds[column];
+
+        let shifted = |delta: f64| {
+            let mut point = *x;
+            point[column] += delta;
+            bound
+                .contains(point[column])
+                .then_some(Perturbation { column, x: point })
+        };
+        let (forward, backward) = (shifted(h), shifted(-h));
+
+        match (difference, forward, backward) {
+            (Difference::Central, Some(forward), Some(backward)) => {
+                points.extend([forward, backward]);
+            }
+            (_, Some(point), _) | (_, None, Some(point)) => points.push(point),
+            (_, None, None) => {}
+        }
+    }
+
+    points
+}
+
+/// Assembles a gradient from objectives evaluated at [`perturbations`].
+///
+/// `objectives` must be in the same order as the perturbations, and `f` must
+/// be the objective at the unperturbed `x`. Columns with two points use a
+/// central difference and columns with one point a one-sided difference.
+pub(super) fn assemble<const N: usize>(
+    x: &[f64; N],
+    f: f64,
+    perturbations: &[Perturbation<N>],
+    objectives: &[f64],
+) -> [f64; N] {
+    debug_assert_eq!(perturbations.len(), objectives.len());
+
+    let mut gradient = [0.0; N];
+    let mut i = 0;
+
+    while i < perturbations.len() {
+        let point = &perturbations[i];
+        let j = point.column;
+
+        // Use the representable steps rather than the requested ones.
+        if let Some(next) = perturbations.get(i + 1).filter(|next| next.column == j) {
+            gradient[j] = (objectives[i] - objectives[i + 1]) / (point.x[j] - next.x[j]);
+            i += 2;
+        } else {
+            gradient[j] = (objectives[i] - f) / (point.x[j] - x[j]);
+            i += 1;
+        }
+    }
+
+    gradient
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    /// f(x) = x0² + 3 x0 x1, with gradient (2 x0 + 3 x1, 3 x0).
+    fn f(x: &[f64; 2]) -> f64 {
+        x[0] * x[0] + 3.0 * x[0] * x[1]
+    }
+
+    fn gradient(x: [f64; 2], bounds: [Bound; 2], difference: Difference, step: f64) -> [f64; 2] {
+        let points = perturbations(&x, &bounds, difference, step);
+        assert!(points.iter().all(|p| bounds[0].contains(p.x[0])));
+        assert!(points.iter().all(|p| bounds[1].contains(p.x[1])));
+
+        let objectives: Vec<_> = points.iter().map(|p| f(&p.x)).collect();
+        assemble(&x, f(&x), &points, &objectives)
+    }
+
+    #[test]
+    fn approximates_gradient() {
+        let unbounded = [Bound::UNBOUNDED; 2];
+        for (difference, step, tol) in [
+            (Difference::Forward, 1e-7, 1e-6),
+            (Difference::Central, 1e-5, 1e-9),
+        ] {
+            let g = gradient([1.0, 2.0], unbounded, difference, step);
+            assert_relative_eq!(g[0], 8.0, epsilon = tol);
+            assert_relative_eq!(g[1], 3.0, epsilon = tol);
+        }
+    }
+
+    #[test]
+    fn stays_within_bounds() {
+        // x0 sits on its upper bound and x1 on its lower bound.
+        let bounds = [Bound::at_most(1.0), Bound::at_least(2.0)];
+        for difference in [Difference::Forward, Difference::Central] {
+            let g = gradient([1.0, 2.0], bounds, difference, 1e-7);
+            assert_relative_eq!(g[0], 8.0, epsilon = 1e-6);
+            assert_relative_eq!(g[1], 3.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn fixed_variable_has_zero_gradient() {
+        let bounds = [Bound::new(1.0, 1.0), Bound::UNBOUNDED];
+        let points = perturbations(&[1.0, 2.0], &bounds, Difference::Central, 1e-5);
+
+        assert!(points.iter().all(|p| p.column == 1));
+        assert_eq!(points.len(), 2);
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+
+use crate::linalg::dot;
+
+/// A stored step `s = x_{k+1} - x_k` and gradient change `y = g_{k+1} - g_k`.
+#[derive(Debug, Clone, Copy)]
+struct Pair<const N: usize> {
+    s: [f64; N],
+    y: [f64; N],
+    rho: f64,
+}
+
+/// The recent step history that implicitly defines the inverse Hessian
+/// approximation.
+#[derive(Debug, Clone)]
+pub(super) struct Memory<const N: usize> {
+    pairs: VecDeque<Pair<N>>,
+    capacity: usize,
+}
+
+impl<const N: usize> Memory<N> {
+    /// Creates an empty memory holding at most `capacity` pairs.
+    pub(super) fn new(capacity: usize) -> Self {
+        Self {
+            pairs: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.pairs.clear();
+    }
+
+    /// Records a step, dropping the oldest pair when full.
+    ///
+    /// Pairs without positive curvature (`s · y <= 0`) would make the
+    /// approximation indefinite, so they are skipped.
+    pub(super) fn push(&mut self, s: [f64; N], y: [f64; N]) {
+        let sy = dot(&s, &y);
+        if !sy.is_finite() || sy <= f64::EPSILON * dot(&y, &y) {
+            return;
+        }
+        if self.pairs.len() == self.capacity {
+            self.pairs.pop_front();
+        }
+        self.pairs.push_back(Pair {
+            s,
+            y,
+            rho: 1.0 / sy,
+        });
+    }
+
+    /// Returns the search direction `-H g` using the two-loop recursion.
+    ///
+    /// The initial inverse Hessian is `γ I` with `γ = s · y / y · y` from the
+    /// newest pair, or the identity when the memory is empty.
+    pub(super) fn direction(&self, gradient: &[f64; N]) -> [f64; N] {
+        let mut q = *gradient;
+        let mut alphas = Vec::with_capacity(self.pairs.len());
+
+        for pair in self.pairs.iter().rev() {
+            let alpha = pair.rho * dot(&pair.s, &q);
+            axpy(&mut q, -alpha, &pair.y);
+            alphas.push(alpha);
+        }
+
+        let gamma = self
+            .pairs
+            .back()
+            .map_or(1.0, |pair| 1.0 / (pair.rho * dot(&pair.y, &pair.y)));
+        let mut r = q.map(|v| gamma * v);
+
+        for (pair, alpha) in self.pairs.iter().zip(alphas.into_iter().rev()) {
+            let beta = pair.rho * dot(&pair.y, &r);
+            axpy(&mut r, alpha - beta, &pair.s);
+        }
+
+        r.map(|v| -v)
+    }
+}
+
+/// Computes `y += a * x` in place.
+fn axpy<const N: usize>(y: &mut [f64; N], a: f64, x: &[f64; N]) {
+    for (yi, xi) in y.iter_mut().zip(x) {
+        *yi += a * xi;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn empty_memory_is_steepest_descent() {
+        let memory = Memory::<2>::new(5);
+        let d = memory.direction(&[1.0, -2.0]);
+        assert_relative_eq!(d[0], -1.0);
+        assert_relative_eq!(d[1], 2.0);
+    }
+
+    #[test]
+    fn recovers_newton_step_on_quadratic() {
+        // f(x) = ½ xᵀ A x with A = diag(1, 10), so y = A s.
+        let a = [1.0, 10.0];
+        let mut memory = Memory::new(5);
+        memory.push([1.0, 0.0], [a[0], 0.0]);
+        memory.push([0.0, 1.0], [0.0, a[1]]);
+
+        // The Newton step from x = (2, 3) goes straight to the origin.
+        let g = [a[0] * 2.0, a[1] * 3.0];
+        let d = memory.direction(&g);
+        assert_relative_eq!(d[0], -2.0, epsilon = 1e-12);
+        assert_relative_eq!(d[1], -3.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn skips_pairs_without_positive_curvature() {
+        let mut memory = Memory::new(5);
+        memory.push([1.0, 0.0], [-1.0, 0.0]);
+        assert!(memory.is_empty());
+    }
+
+    #[test]
+    fn drops_oldest_pair_when_full() {
+        let mut memory = Memory::new(1);
+        memory.push([1.0, 0.0], [1.0, 0.0]);
+        memory.push([0.0, 1.0], [0.0, 4.0]);
+
+        // Only the second pair remains: it captures the curvature of 4 along
+        // x1, and γ = 1/4 scales the gradient along x0.
+        let d = memory.direction(&[4.0, 4.0]);
+        assert_relative_eq!(d[0], -1.0);
+        assert_relative_eq!(d[1], -1.0);
+    }
+}
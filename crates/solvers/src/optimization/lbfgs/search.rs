@@ -0,0 +1,472 @@
+use twine_core::{Bound, Model, Observer, OptimizationProblem};
+
+use crate::{
+    linalg::{self, dot},
+    optimization::{Evaluation, evaluate},
+    stats::{Stats, Tally},
+};
+
+use super::{Action, Config, Error, Event, Solution, Status, gradient, memory::Memory};
+
+/// Core L-BFGS implementation.
+///
+/// Objectives and gradients are multiplied by `sign` before use, so the
+/// search always minimizes: `1.0` minimizes the objective and `-1.0`
+/// maximizes it.
+///
+/// Every evaluated point lies within `bounds`, and `x0` is clamped into them.
+/// Unbounded variables reduce this to plain L-BFGS.
+pub(crate) fn search<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    bounds: &[Bound; N],
+    config: &Config,
+    mut observer: Obs,
+    sign: f64,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    config.validate()?;
+    if !bounds.iter().all(Bound::is_valid) {
+        return Err(Error::InvalidBounds);
+    }
+
+    let mut ctx = EvalContext {
+        model,
+        problem,
+        observer: &mut observer,
+        config,
+        bounds,
+        sign,
+        tally: Tally::start(),
+    };
+
+    // Evaluate the initial guess; stopping here still reports it if it succeeded.
+    let x0 = std::array::from_fn(|j| bounds[j].clamp(x0[j]));
+    let result = evaluate(model, problem, x0);
+    ctx.tally.evaluated(&result);
+    let action = ctx.observer.observe(&Event::Trial {
+        iter: 0,
+        step: 0.0,
+        x: x0,
+        result: &result,
+    });
+    ctx.tally.observed(action.as_ref());
+    let eval = match (action, result) {
+        (Some(Action::StopEarly), Ok(eval)) => {
+            return Ok(Current::new(eval).finish(Status::StoppedByObserver, 0, ctx.tally.stats()));
+        }
+        (Some(Action::StopEarly), Err(_)) => return Err(Error::NoSuccessfulEvaluation),
+        (None, Ok(eval)) => eval,
+        (None, Err(error)) => return Err(error.into()),
+    };
+
+    let mut current = Current::new(eval);
+    match ctx.gradient(&current.eval)? {
+        Outcome::Continue(gradient) => current.gradient = Some(gradient),
+        Outcome::StopEarly => {
+            return Ok(current.finish(Status::StoppedByObserver, 0, ctx.tally.stats()));
+        }
+    }
+    if let Outcome::StopEarly = ctx.iterate(0, &current) {
+        return Ok(current.finish(Status::StoppedByObserver, 0, ctx.tally.stats()));
+    }
+    if ctx.is_gradient_converged(&current) {
+        return Ok(current.finish(Status::Converged, 0, ctx.tally.stats()));
+    }
+
+    let mut memory = Memory::new(config.memory);
+
+    for iter in 1..=config.max_iters {
+        let x = current.eval.x;
+        let g = current.scaled_gradient(sign);
+        let line = ctx.line(iter, &current, &mut memory);
+
+        let next = match ctx.line_search(&line)? {
+            Search::Accepted(next) => next,
+            Search::Failed if !memory.is_empty() => {
+                // Retry from steepest descent before giving up.
+                memory.clear();
+                continue;
+            }
+            Search::Failed => {
+                return Ok(current.finish(Status::LineSearchFailed, iter, ctx.tally.stats()));
+            }
+            Search::StopEarly => {
+                return Ok(current.finish(Status::StoppedByObserver, iter, ctx.tally.stats()));
+            }
+        };
+
+        let step: [f64; N] = std::array::from_fn(|j| next.eval.x[j] - x[j]);
+        let next_g = next.scaled_gradient(sign);
+        memory.push(step, std::array::from_fn(|j| next_g[j] - g[j]));
+
+        current = next;
+        if let Outcome::StopEarly = ctx.iterate(iter, &current) {
+            return Ok(current.finish(Status::StoppedByObserver, iter, ctx.tally.stats()));
+        }
+
+        if ctx.is_gradient_converged(&current)
+            || linalg::is_step_converged(&step, &current.eval.x, config.x_abs_tol, config.x_rel_tol)
+        {
+            return Ok(current.finish(Status::Converged, iter, ctx.tally.stats()));
+        }
+    }
+
+    Ok(current.finish(Status::MaxIters, config.max_iters, ctx.tally.stats()))
+}
+
+/// Returns which variables sit on a bound that blocks descent.
+///
+/// `gradient` must already be sign-adjusted, so descent is along `-gradient`.
+fn blocked<const N: usize>(gradient: &[f64; N], x: &[f64; N], bounds: &[Bound; N]) -> [bool; N] {
+    std::array::from_fn(|j| {
+        (x[j] <= bounds[j].lower && gradient[j] > 0.0)
+            || (x[j] >= bounds[j].upper && gradient[j] < 0.0)
+    })
+}
+
+/// Returns the gradient with blocked components zeroed.
+fn project<const N: usize>(gradient: &[f64; N], x: &[f64; N], bounds: &[Bound; N]) -> [f64; N] {
+    let blocked = blocked(gradient, x, bounds);
+    std::array::from_fn(|j| if blocked[j] { 0.0 } else { gradient[j] })
+}
+
+/// Whether the solver should keep going after an observed evaluation.
+enum Outcome<T> {
+    Continue(T),
+    StopEarly,
+}
+
+/// The result of a line search.
+enum Search<I, O, const N: usize> {
+    Accepted(Current<I, O, N>),
+    Failed,
+    StopEarly,
+}
+
+/// The search line `x + t * direction` for one iteration.
+///
+/// `score` and `slope` are the sign-adjusted objective and directional
+/// derivative at `x`. Each variable reaches its bound at step `limits[j]`
+/// (infinite if it never does), and the line ends at the smallest limit.
+struct Line<const N: usize> {
+    iter: usize,
+    x: [f64; N],
+    score: f64,
+    slope: f64,
+    direction: [f64; N],
+    limits: [f64; N],
+    max_step: f64,
+    initial_step: f64,
+}
+
+impl<const N: usize> Line<N> {
+    /// Returns the point at step `t`, placing variables exactly on any bound
+    /// they have reached.
+    fn at(&self, t: f64, bounds: &[Bound; N]) -> [f64; N] {
+        std::array::from_fn(|j| {
+            let d = self.direction[j];
+            if t < self.limits[j] {
+                bounds[j].clamp(self.x[j] + t * d)
+            } else if d > 0.0 {
+                bounds[j].upper
+            } else {
+                bounds[j].lower
+            }
+        })
+    }
+}
+
+/// An evaluated point and, once computed, its gradient.
+struct Current<I, O, const N: usize> {
+    eval: Evaluation<I, O, N>,
+    gradient: Option<[f64; N]>,
+}
+
+impl<I, O, const N: usize> Current<I, O, N> {
+    fn new(eval: Evaluation<I, O, N>) -> Self {
+        Self {
+            eval,
+            gradient: None,
+        }
+    }
+
+    /// Returns the gradient multiplied by `sign`.
+    fn scaled_gradient(&self, sign: f64) -> [f64; N] {
+        self.gradient
+            .expect("gradient is computed before iterating")
+            .map(|v| sign * v)
+    }
+
+    #[allow(clippy::similar_names)]
+    fn finish(self, status: Status, iters: usize, stats: Stats) -> Solution<I, O, N> {
+        Solution {
+            status,
+            x: self.eval.x,
+            objective: self.eval.objective,
+            gradient: self.gradient,
+            snapshot: self.eval.snapshot,
+            iters,
+            stats,
+        }
+    }
+}
+
+/// Bundles evaluation and observation for a single L-BFGS search.
+struct EvalContext<'ctx, M, P, Obs, const N: usize> {
+    model: &'ctx M,
+    problem: &'ctx P,
+    observer: &'ctx mut Obs,
+    config: &'ctx Config,
+    bounds: &'ctx [Bound; N],
+    sign: f64,
+    tally: Tally,
+}
+
+impl<M, P, Obs, const N: usize> EvalContext<'_, M, P, Obs, N>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'evt> Observer<Event<'evt, M, P, N>, Action>,
+{
+    fn is_gradient_converged(&self, current: &Current<M::Input, M::Output, N>) -> bool {
+        let g = current.scaled_gradient(self.sign);
+        let projected = project(&g, &current.eval.x, self.bounds);
+        linalg::max_abs(&projected) <= self.config.gradient_tol
+    }
+
+    /// Chooses the search line from `current`.
+    ///
+    /// The quasi-Newton direction is computed from the projected gradient,
+    /// then blocked variables and components that would leave a bound are
+    /// dropped. If that is not a descent direction, the memory is cleared and
+    /// the projected steepest descent direction is used instead.
+    fn line(
+        &self,
+        iter: usize,
+        current: &Current<M::Input, M::Output, N>,
+        memory: &mut Memory<N>,
+    ) -> Line<N> {
+        let x = current.eval.x;
+        let g = current.scaled_gradient(self.sign);
+        let blocked = blocked(&g, &x, self.bounds);
+        let free: [f64; N] = std::array::from_fn(|j| if blocked[j] { 0.0 } else { g[j] });
+
+        let feasible = |d: [f64; N]| -> [f64; N] {
+            std::array::from_fn(|j| {
+                let leaving = (x[j] <= self.bounds[j].lower && d[j] < 0.0)
+                    || (x[j] >= self.bounds[j].upper && d[j] > 0.0);
+                if blocked[j] || leaving { 0.0 } else { d[j] }
+            })
+        };
+
+        let mut direction = feasible(memory.direction(&free));
+        let is_descent = dot(&g, &direction) < 0.0;
+        if !is_descent {
+            memory.clear();
+            direction = feasible(free.map(|v| -v));
+        }
+
+        let limits: [f64; N] = std::array::from_fn(|j| {
+            let d = direction[j];
+            if d > 0.0 {
+                (self.bounds[j].upper - x[j]) / d
+            } else if d < 0.0 {
+                (self.bounds[j].lower - x[j]) / d
+            } else {
+                f64::INFINITY
+            }
+        });
+        let max_step = limits.iter().copied().fold(f64::INFINITY, f64::min);
+
+        // Without curvature information, start with a unit-length step.
+        let initial_step = if memory.is_empty() {
+            (1.0 / dot(&direction, &direction).sqrt()).min(1.0)
+        } else {
+            1.0
+        };
+
+        Line {
+            iter,
+            x,
+            score: self.sign * current.eval.objective,
+            slope: dot(&g, &direction),
+            direction,
+            limits,
+            max_step,
+            initial_step: initial_step.min(max_step),
+        }
+    }
+
+    /// Finds a step along `line` satisfying the weak Wolfe conditions.
+    ///
+    /// Uses bisection on a bracket `[lo, hi]`: the step doubles until it fails
+    /// sufficient decrease or reaches the end of the line, then halves between
+    /// the last step that was too short (fails curvature) and the first that
+    /// was too long. A step at the end of the line is accepted on sufficient
+    /// decrease alone, since a bound stops the search from going further.
+    /// If the trial budget runs out, the last point with sufficient decrease is
+    /// accepted even though it fails the curvature condition.
+    fn line_search(&mut self, line: &Line<N>) -> Result<Search<M::Input, M::Output, N>, Error> {
+        let Config {
+            wolfe_c1: c1,
+            wolfe_c2: c2,
+            ..
+        } = *self.config;
+
+        let (mut lo, mut hi, mut t) = (0.0, f64::INFINITY, line.initial_step);
+        let mut fallback = None;
+
+        for _ in 0..self.config.max_line_search_iters {
+            let x = line.at(t, self.bounds);
+
+            let result = evaluate(self.model, self.problem, x);
+
+            self.tally.evaluated(&result);
+            let action = self.observer.observe(&Event::Trial {
+                iter: line.iter,
+                step: t,
+                x,
+                result: &result,
+            });
+            self.tally.observed(action.as_ref());
+            if let Some(Action::StopEarly) = action {
+                return Ok(Search::StopEarly);
+            }
+            let eval = result?;
+
+            // A NaN objective fails sufficient decrease, shortening the step.
+            let sufficient_decrease =
+                self.sign * eval.objective <= line.score + c1 * t * line.slope;
+            if sufficient_decrease {
+                let mut next = Current::new(eval);
+                match self.gradient(&next.eval)? {
+                    Outcome::Continue(gradient) => next.gradient = Some(gradient),
+                    Outcome::StopEarly => return Ok(Search::StopEarly),
+                }
+
+                let slope = dot(&next.scaled_gradient(self.sign), &line.direction);
+                if slope >= c2 * line.slope || t >= line.max_step {
+                    return Ok(Search::Accepted(next));
+                }
+                lo = t;
+                fallback = Some(next);
+            } else {
+                hi = t;
+            }
+
+            t = if hi.is_finite() {
+                0.5 * (lo + hi)
+            } else {
+                (2.0 * t).min(line.max_step)
+            };
+        }
+
+        Ok(fallback.map_or(Search::Failed, Search::Accepted))
+    }
+
+    /// Returns the raw objective gradient at an evaluated point.
+    ///
+    /// Uses the problem's analytic gradient when it provides one, and finite
+    /// differences within bounds otherwise.
+    fn gradient(
+        &mut self,
+        eval: &Evaluation<M::Input, M::Output, N>,
+    ) -> Result<Outcome<[f64; N]>, Error> {
+        let analytic = self
+            .problem
+            .gradient(&eval.x, &eval.snapshot.input, &eval.snapshot.output)
+            .map_err(|err| Error::Problem(Box::new(err)))?;
+        if let Some(gradient) = analytic {
+            return Ok(Outcome::Continue(gradient));
+        }
+
+        let points = gradient::perturbations(
+            &eval.x,
+            self.bounds,
+            self.config.difference,
+            self.config.fd_step,
+        );
+        let mut objectives = Vec::with_capacity(points.len());
+
+        for point in &points {
+            let result = evaluate(self.model, self.problem, point.x);
+            self.tally.evaluated(&result);
+            let action = self.observer.observe(&Event::Gradient {
+                component: point.column,
+                x: point.x,
+                result: &result,
+            });
+            self.tally.observed(action.as_ref());
+
+            if let Some(Action::StopEarly) = action {
+                return Ok(Outcome::StopEarly);
+            }
+            objectives.push(result?.objective);
+        }
+
+        Ok(Outcome::Continue(gradient::assemble(
+            &eval.x,
+            eval.objective,
+            &points,
+            &objectives,
+        )))
+    }
+
+    /// Emits the event for an accepted iterate.
+    fn iterate(&mut self, iter: usize, current: &Current<M::Input, M::Output, N>) -> Outcome<()> {
+        let gradient = current
+            .gradient
+            .expect("gradient is computed before iterating");
+        let g = gradient.map(|v| self.sign * v);
+        let blocked = blocked(&g, &current.eval.x, self.bounds);
+
+        let action = self.observer.observe(&Event::Iterate {
+            iter,
+            x: current.eval.x,
+            objective: current.eval.objective,
+            gradient,
+            projected_gradient: std::array::from_fn(|j| if blocked[j] { 0.0 } else { gradient[j] }),
+        });
+
+        self.tally.observed(action.as_ref());
+
+        match action {
+            Some(Action::StopEarly) => Outcome::StopEarly,
+            None => Outcome::Continue(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn project_zeroes_blocked_components() {
+        let bounds = [
+            Bound::at_least(0.0),
+            Bound::at_most(1.0),
+            Bound::new(0.0, 1.0),
+        ];
+
+        // x0 on its lower bound wants to decrease, x1 on its upper bound wants
+        // to increase, and x2 is interior.
+        let projected = project(&[2.0, -3.0, 4.0], &[0.0, 1.0, 0.5], &bounds);
+        assert_relative_eq!(projected[0], 0.0);
+        assert_relative_eq!(projected[1], 0.0);
+        assert_relative_eq!(projected[2], 4.0);
+
+        // Pointing back into the domain is not blocked.
+        let projected = project(&[-2.0, 3.0, 4.0], &[0.0, 1.0, 0.5], &bounds);
+        assert_relative_eq!(projected[0], -2.0);
+        assert_relative_eq!(projected[1], 3.0);
+    }
+}
@@ -0,0 +1,50 @@
+use twine_core::Snapshot;
+
+use crate::Stats;
+
+/// Indicates why the solver finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Converged according to the configured tolerances.
+    Converged,
+
+    /// Reached the iteration limit without converging.
+    MaxIters,
+
+    /// The line search found no decrease along the steepest descent direction.
+    ///
+    /// This usually means the iterate is already as good as the objective's
+    /// precision (or the finite difference gradient's accuracy) allows.
+    LineSearchFailed,
+
+    /// Stopped early due to an observer decision.
+    StoppedByObserver,
+}
+
+/// The result of an L-BFGS optimization.
+#[derive(Debug, Clone)]
+pub struct Solution<I, O, const N: usize> {
+    /// Final solver status.
+    pub status: Status,
+
+    /// The final iterate.
+    pub x: [f64; N],
+
+    /// Objective value at the reported x.
+    pub objective: f64,
+
+    /// Objective gradient at the reported x.
+    ///
+    /// `None` if the solver stopped before the gradient was computed.
+    pub gradient: Option<[f64; N]>,
+
+    /// Snapshot at the reported x.
+    pub snapshot: Snapshot<I, O>,
+
+    /// Iteration count when the solver finished.
+    pub iters: usize,
+
+    /// Model evaluations and time spent, including line search and gradient
+    /// evaluations.
+    pub stats: Stats,
+}
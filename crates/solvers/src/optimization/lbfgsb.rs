@@ -0,0 +1,331 @@
+//! Bound-constrained L-BFGS for smooth multi-variable optimization.
+//!
+//! # Algorithm
+//!
+//! This is [`lbfgs`] with each variable kept within the limits returned by
+//! [`BoundedProblem::bounds`]. The initial guess is clamped into the
+//! bounds, and every point the solver evaluates lies within them:
+//!
+//! - Components of the search direction that would push a variable past a
+//!   bound it already sits on are dropped, and the line search never steps
+//!   past the nearest bound along the direction.
+//! - Finite difference gradients use one-sided differences at a bound, so
+//!   perturbations stay inside too.
+//!
+//! Variables are released from a bound as soon as the gradient points back
+//! into the feasible region, so active constraints are identified as the
+//! search proceeds.
+//!
+//! # Shared Types
+//!
+//! The bounded solver shares its [`Config`], [`Action`], [`Event`],
+//! [`Solution`], and error types with [`lbfgs`], so observers written for one
+//! work unchanged with the other. Bounds that are `NaN` or have `lower > upper`
+//! are rejected with [`Error::InvalidBounds`].
+//!
+//! # Convergence
+//!
+//! The solver converges when either:
+//! - Every projected gradient component magnitude is within `gradient_tol`,
+//!   where components blocked by an active bound are treated as zero, or
+//! - The step satisfies `max|Δx| <= x_abs_tol + x_rel_tol * max|x|`.
+//!
+//! [`Event::Iterate`] reports both the raw and the projected gradient, and
+//! [`Event::gradient_norm`] uses the projected one.
+//!
+//! [`lbfgs`]: crate::optimization::lbfgs
+//! [`BoundedProblem::bounds`]: twine_core::BoundedProblem::bounds
+
+pub use super::lbfgs::{Action, Config, ConfigError, Difference, Error, Event, Solution, Status};
+
+use twine_core::{BoundedProblem, Model, Observer};
+
+use super::lbfgs::search::search;
+
+/// Finds a minimum of the objective within the problem's bounds.
+///
+/// The observer receives an [`Event`] for each evaluation and accepted iterate.
+/// See the [module docs](self) for details on bounds and convergence.
+///
+/// # Errors
+///
+/// Returns an error if the config or bounds are invalid, or if the model or
+/// problem fails during evaluation.
+pub fn minimize<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: BoundedProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    search(model, problem, x0, &problem.bounds(), config, observer, 1.0)
+}
+
+/// Finds a minimum of the objective within bounds without observer support.
+///
+/// This is a convenience wrapper around [`minimize`] that uses a no-op observer.
+///
+/// # Errors
+///
+/// Returns an error if the config or bounds are invalid, or if the model or
+/// problem fails during evaluation.
+pub fn minimize_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: BoundedProblem<N, Input = M::Input, Output = M::Output>,
+{
+    minimize(model, problem, x0, config, ())
+}
+
+/// Finds a maximum of the objective within the problem's bounds.
+///
+/// The observer receives an [`Event`] for each evaluation and accepted iterate.
+/// See the [module docs](self) for details on bounds and convergence.
+///
+/// # Errors
+///
+/// Returns an error if the config or bounds are invalid, or if the model or
+/// problem fails during evaluation.
+pub fn maximize<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: BoundedProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    search(
+        model,
+        problem,
+        x0,
+        &problem.bounds(),
+        config,
+        observer,
+        -1.0,
+    )
+}
+
+/// Finds a maximum of the objective within bounds without observer support.
+///
+/// This is a convenience wrapper around [`maximize`] that uses a no-op observer.
+///
+/// # Errors
+///
+/// Returns an error if the config or bounds are invalid, or if the model or
+/// problem fails during evaluation.
+pub fn maximize_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: BoundedProblem<N, Input = M::Input, Output = M::Output>,
+{
+    maximize(model, problem, x0, config, ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use thiserror::Error;
+    use twine_core::{Bound, OptimizationProblem};
+
+    // --- Test fixtures ---
+
+    #[derive(Debug, Error)]
+    #[error("input outside the valid domain")]
+    struct OutOfDomain;
+
+    /// `Σ (x_i - target_i)²`, failing for any input outside `[0, 1]`.
+    struct UnitBox {
+        target: [f64; 2],
+    }
+
+    impl Model for UnitBox {
+        type Input = [f64; 2];
+        type Output = f64;
+        type Error = OutOfDomain;
+
+        fn call(&self, x: &[f64; 2]) -> Result<f64, OutOfDomain> {
+            if x.iter().any(|v| !(0.0..=1.0).contains(v)) {
+                return Err(OutOfDomain);
+            }
+            Ok(x.iter()
+                .zip(self.target)
+                .map(|(x, t)| (x - t).powi(2))
+                .sum())
+        }
+    }
+
+    /// Rosenbrock's function, with its minimum of 0 at `(1, 1)`.
+    struct Rosenbrock;
+
+    impl Model for Rosenbrock {
+        type Input = [f64; 2];
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, &[x, y]: &[f64; 2]) -> Result<f64, Infallible> {
+            Ok((1.0 - x).powi(2) + 100.0 * (y - x * x).powi(2))
+        }
+    }
+
+    /// Maps solver variables directly to model inputs within fixed bounds.
+    struct Bounded([Bound; 2]);
+
+    impl OptimizationProblem<2> for Bounded {
+        type Input = [f64; 2];
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok(*x)
+        }
+
+        fn objective(&self, _: &[f64; 2], output: &f64) -> Result<f64, Infallible> {
+            Ok(*output)
+        }
+    }
+
+    impl BoundedProblem<2> for Bounded {
+        fn bounds(&self) -> [Bound; 2] {
+            self.0
+        }
+    }
+
+    const UNIT: [Bound; 2] = [Bound {
+        lower: 0.0,
+        upper: 1.0,
+    }; 2];
+
+    // --- Tests ---
+
+    #[test]
+    fn stops_on_bound_when_minimum_lies_outside() {
+        let model = UnitBox {
+            target: [1.5, 0.25],
+        };
+        let solution = minimize_unobserved(&model, &Bounded(UNIT), [0.5, 0.5], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0);
+        assert_relative_eq!(solution.x[1], 0.25, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn never_evaluates_outside_bounds() {
+        let mut evaluated = Vec::new();
+
+        // The unconstrained minimum is far outside, in both directions.
+        let model = UnitBox {
+            target: [-3.0, 4.0],
+        };
+        let solution = minimize(
+            &model,
+            &Bounded(UNIT),
+            [0.9, 0.1],
+            &Config::default(),
+            |event: &Event<'_, UnitBox, Bounded, 2>| {
+                evaluated.push(event.x());
+                None
+            },
+        )
+        .expect("model should never fail");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 0.0);
+        assert_relative_eq!(solution.x[1], 1.0);
+        assert!(evaluated.iter().flatten().all(|v| (0.0..=1.0).contains(v)));
+    }
+
+    #[test]
+    fn minimizes_rosenbrock_with_inactive_bounds() {
+        let bounds = [Bound::new(-2.0, 2.0), Bound::at_most(3.0)];
+        let solution = minimize_unobserved(
+            &Rosenbrock,
+            &Bounded(bounds),
+            [-1.2, 1.0],
+            &Config::default(),
+        )
+        .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(solution.x[1], 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn minimizes_rosenbrock_on_active_bound() {
+        // With x <= 0.5, the minimum is near (0.5, 0.25) on the bound.
+        let bounds = [Bound::at_most(0.5), Bound::UNBOUNDED];
+        let solution = minimize_unobserved(
+            &Rosenbrock,
+            &Bounded(bounds),
+            [-1.2, 1.0],
+            &Config::default(),
+        )
+        .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 0.5);
+        assert_relative_eq!(solution.x[1], 0.25, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn fixed_variable_does_not_move() {
+        let bounds = [Bound::new(0.75, 0.75), Bound::new(0.0, 1.0)];
+        let model = UnitBox { target: [0.0, 0.4] };
+        let solution =
+            minimize_unobserved(&model, &Bounded(bounds), [0.5, 0.5], &Config::default())
+                .expect("should converge");
+
+        assert_relative_eq!(solution.x[0], 0.75);
+        assert_relative_eq!(solution.x[1], 0.4, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn maximizes_within_bounds() {
+        // The distance from the target is largest at the far corner.
+        let model = UnitBox { target: [0.2, 0.7] };
+        let solution = maximize_unobserved(&model, &Bounded(UNIT), [0.5, 0.5], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0);
+        assert_relative_eq!(solution.x[1], 0.0);
+    }
+
+    #[test]
+    fn rejects_invalid_bounds() {
+        let bounds = [Bound::new(1.0, 0.0), Bound::UNBOUNDED];
+        let result = minimize_unobserved(
+            &Rosenbrock,
+            &Bounded(bounds),
+            [0.5, 0.5],
+            &Config::default(),
+        );
+
+        assert!(matches!(result, Err(Error::InvalidBounds)));
+    }
+}
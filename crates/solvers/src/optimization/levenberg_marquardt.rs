@@ -0,0 +1,515 @@
+//! Levenberg–Marquardt for nonlinear least-squares problems.
+//!
+//! # Algorithm
+//!
+//! Each iteration linearizes the residuals around the current iterate and
+//! solves the damped normal equations for a step:
+//!
+//! ```text
+//! (JᵀJ + λ D) Δx = -Jᵀr
+//! ```
+//!
+//! where `J` is the Jacobian of the residuals `r` and `D` is the diagonal of
+//! `JᵀJ`. A small damping `λ` gives a Gauss–Newton step, which converges
+//! quickly near the solution; a large `λ` gives a short gradient descent step,
+//! which is robust far from it.
+//!
+//! A trial step is accepted if it reduces the sum of squared residuals. The
+//! damping then shrinks according to how well the linear model predicted the
+//! reduction. A rejected step grows the damping and is retried from the same
+//! iterate. A trial whose evaluation fails is rejected the same way, so the
+//! solver backs off from regions where the model is undefined.
+//!
+//! The Jacobian is approximated by finite differences, costing `N` (forward)
+//! or `2N` (central) extra evaluations per accepted step.
+//!
+//! # When to Use
+//!
+//! Use this solver to fit `N` parameters to `R` residuals, such as calibrating
+//! a model against measurements. It also handles square systems (`R = N`)
+//! where [`newton`] diverges from a poor initial guess, at the cost of a
+//! slower approach.
+//!
+//! # Convergence
+//!
+//! The solver converges when any of the following holds:
+//! - Every residual magnitude is within `residual_tol` (an exact fit)
+//! - Every component of the cost gradient `2 Jᵀr` is within `gradient_tol`
+//! - A trial step satisfies `max|Δx| <= x_abs_tol + x_rel_tol * max|x|`
+//!
+//! # Covariance
+//!
+//! The [`Solution`] includes the final Jacobian and, when there are more
+//! residuals than variables, the parameter covariance estimate
+//! `s² (JᵀJ)⁻¹` with `s² = cost / (R - N)`. Standard errors of the fitted
+//! parameters are the square roots of its diagonal.
+//!
+//! # Observer Events
+//!
+//! The solver emits one [`Event`] per evaluation:
+//!
+//! - [`Event::Trial`] — evaluation of a trial step (iteration 0 is the initial
+//!   guess)
+//! - [`Event::Jacobian`] — perturbed evaluation for a Jacobian column
+//!
+//! Observers can return [`Action::StopEarly`] to halt and return the current
+//! iterate.
+//!
+//! [`newton`]: crate::equation::newton
+
+mod action;
+mod config;
+mod error;
+mod eval_context;
+mod evaluate;
+mod event;
+mod normal;
+mod solution;
+
+pub use action::Action;
+pub use config::{Config, ConfigError};
+pub use error::Error;
+pub use evaluate::{EvaluateResult, Evaluation};
+pub use event::Event;
+pub use solution::{Solution, Status};
+
+pub use crate::equation::newton::Difference;
+
+use twine_core::{LeastSquaresProblem, Model, Observer};
+
+use crate::stats::Tally;
+
+use eval_context::{EvalContext, Outcome};
+use evaluate::evaluate;
+use normal::Normal;
+
+/// Finds the parameters that minimize the sum of squared residuals.
+///
+/// The observer receives an [`Event`] for each evaluation.
+/// See the [module docs](self) for details on convergence and events.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, or if the model or problem
+/// fails at the initial guess or while approximating a Jacobian.
+pub fn minimize<M, P, Obs, const N: usize, const R: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+    mut observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N, R>, Error>
+where
+    M: Model,
+    P: LeastSquaresProblem<N, R, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N, R>, Action>,
+{
+    config.validate()?;
+    let tally = Tally::start();
+
+    // Evaluate the initial guess; stopping here still reports it if it succeeded.
+    let result = evaluate(model, problem, x0);
+    tally.evaluated(&result);
+    let action = observer.observe(&Event::Trial {
+        iter: 0,
+        damping: 0.0,
+        x: x0,
+        result: &result,
+    });
+    tally.observed(action.as_ref());
+    let eval = match (action, result) {
+        (Some(Action::StopEarly), Ok(eval)) => {
+            return Ok(Current::new(eval).finish(Status::StoppedByObserver, 0, &tally));
+        }
+        (Some(Action::StopEarly), Err(_)) => return Err(Error::NoSuccessfulEvaluation),
+        (None, Ok(eval)) => eval,
+        (None, Err(error)) => return Err(error.into()),
+    };
+
+    let mut ctx = EvalContext {
+        model,
+        problem,
+        observer: &mut observer,
+        tally: &tally,
+    };
+
+    let mut current = Current::new(eval);
+    let Some(jacobian) = ctx.jacobian(&current.eval, config.difference, config.fd_step)? else {
+        return Ok(current.finish(Status::StoppedByObserver, 0, &tally));
+    };
+    let mut normal = current.set_jacobian(jacobian);
+    if current.is_converged(&normal, config) {
+        return Ok(current.finish(Status::Converged, 0, &tally));
+    }
+
+    let scale = normal.max_diagonal();
+    let mut damping = if scale > 0.0 {
+        config.initial_damping * scale
+    } else {
+        config.initial_damping
+    };
+    let mut growth = 2.0;
+
+    for iter in 1..=config.max_iters {
+        let Some(step) = normal.step(damping) else {
+            damping *= growth;
+            growth *= 2.0;
+            continue;
+        };
+
+        let x = std::array::from_fn(|j| current.eval.x[j] + step[j]);
+        let (trial, ratio) = match ctx.trial(iter, damping, x) {
+            Outcome::Continue(trial) => {
+                // A NaN cost gives a NaN ratio, which rejects the step.
+                let ratio =
+                    (current.eval.cost() - trial.cost()) / normal.predicted_reduction(&step);
+                (Some(trial), ratio)
+            }
+            Outcome::RejectStep => (None, f64::NAN),
+            Outcome::StopEarly => {
+                return Ok(current.finish(Status::StoppedByObserver, iter, &tally));
+            }
+        };
+
+        let Some(trial) = trial.filter(|_| ratio > 0.0) else {
+            damping *= growth;
+            growth *= 2.0;
+
+            // Even a damped step no longer moves x, so no progress is possible.
+            if crate::linalg::is_step_converged(
+                &step,
+                &current.eval.x,
+                config.x_abs_tol,
+                config.x_rel_tol,
+            ) {
+                return Ok(current.finish(Status::Converged, iter, &tally));
+            }
+            continue;
+        };
+
+        damping *= (1.0_f64 / 3.0).max(1.0 - (2.0 * ratio - 1.0).powi(3));
+        growth = 2.0;
+
+        current = Current::new(trial);
+        let Some(jacobian) = ctx.jacobian(&current.eval, config.difference, config.fd_step)? else {
+            return Ok(current.finish(Status::StoppedByObserver, iter, &tally));
+        };
+        normal = current.set_jacobian(jacobian);
+
+        if current.is_converged(&normal, config)
+            || crate::linalg::is_step_converged(
+                &step,
+                &current.eval.x,
+                config.x_abs_tol,
+                config.x_rel_tol,
+            )
+        {
+            return Ok(current.finish(Status::Converged, iter, &tally));
+        }
+    }
+
+    Ok(current.finish(Status::MaxIters, config.max_iters, &tally))
+}
+
+/// Finds the parameters that minimize the sum of squared residuals without
+/// observer support.
+///
+/// This is a convenience wrapper around [`minimize`] that uses a no-op observer.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, or if the model or problem
+/// fails at the initial guess or while approximating a Jacobian.
+pub fn minimize_unobserved<M, P, const N: usize, const R: usize>(
+    model: &M,
+    problem: &P,
+    x0: [f64; N],
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N, R>, Error>
+where
+    M: Model,
+    P: LeastSquaresProblem<N, R, Input = M::Input, Output = M::Output>,
+{
+    minimize(model, problem, x0, config, ())
+}
+
+/// The current iterate and, once computed, its Jacobian.
+struct Current<I, O, const N: usize, const R: usize> {
+    eval: Evaluation<I, O, N, R>,
+    jacobian: Option<[[f64; N]; R]>,
+}
+
+impl<I, O, const N: usize, const R: usize> Current<I, O, N, R> {
+    fn new(eval: Evaluation<I, O, N, R>) -> Self {
+        Self {
+            eval,
+            jacobian: None,
+        }
+    }
+
+    /// Stores the Jacobian and returns the normal equations it defines.
+    fn set_jacobian(&mut self, jacobian: [[f64; N]; R]) -> Normal<N> {
+        self.jacobian = Some(jacobian);
+        Normal::new(&jacobian, &self.eval.residuals)
+    }
+
+    fn is_converged(&self, normal: &Normal<N>, config: &Config) -> bool {
+        crate::linalg::max_abs(&self.eval.residuals) <= config.residual_tol
+            || 2.0 * crate::linalg::max_abs(&normal.jtr) <= config.gradient_tol
+    }
+
+    fn finish(self, status: Status, iters: usize, tally: &Tally) -> Solution<I, O, N, R> {
+        let cost = self.eval.cost();
+        let covariance = self.jacobian.and_then(|jacobian| {
+            Normal::new(&jacobian, &self.eval.residuals).covariance::<R>(cost)
+        });
+
+        Solution {
+            status,
+            x: self.eval.x,
+            residuals: self.eval.residuals,
+            cost,
+            jacobian: self.jacobian,
+            covariance,
+            snapshot: self.eval.snapshot,
+            iters,
+            stats: tally.stats(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+
+    // --- Test fixtures ---
+
+    /// Passes the parameters through, so problems compute residuals directly.
+    struct Identity;
+
+    impl Model for Identity {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn call(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok(*x)
+        }
+    }
+
+    /// Fits `y = a exp(-b t)` to samples.
+    struct Decay {
+        samples: [(f64, f64); 6],
+    }
+
+    impl Decay {
+        fn exact(a: f64, b: f64) -> Self {
+            let mut samples = [(0.0, 0.0); 6];
+            let mut t = 0.0;
+            for sample in &mut samples {
+                *sample = (t, a * (-b * t).exp());
+                t += 1.0;
+            }
+            Self { samples }
+        }
+    }
+
+    impl LeastSquaresProblem<2, 6> for Decay {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok(*x)
+        }
+
+        fn residuals(&self, _: &[f64; 2], &[a, b]: &[f64; 2]) -> Result<[f64; 6], Infallible> {
+            Ok(self.samples.map(|(t, y)| a * (-b * t).exp() - y))
+        }
+    }
+
+    /// Fits the line `y = a + b t` to noisy samples at `t = 0, 1, 2, 3, 4`.
+    ///
+    /// The least-squares fit is `a = 1.04`, `b = 1.0` with a residual sum of
+    /// squares of `0.072`.
+    struct Line;
+
+    const LINE_SAMPLES: [(f64, f64); 5] =
+        [(0.0, 1.1), (1.0, 1.9), (2.0, 3.2), (3.0, 3.9), (4.0, 5.1)];
+
+    impl LeastSquaresProblem<2, 5> for Line {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok(*x)
+        }
+
+        fn residuals(&self, _: &[f64; 2], &[a, b]: &[f64; 2]) -> Result<[f64; 5], Infallible> {
+            Ok(LINE_SAMPLES.map(|(t, y)| a + b * t - y))
+        }
+    }
+
+    /// Rosenbrock's function as residuals `[10 (y - x²), 1 - x]`.
+    struct Rosenbrock;
+
+    impl LeastSquaresProblem<2, 2> for Rosenbrock {
+        type Input = [f64; 2];
+        type Output = [f64; 2];
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok(*x)
+        }
+
+        fn residuals(&self, _: &[f64; 2], &[x, y]: &[f64; 2]) -> Result<[f64; 2], Infallible> {
+            Ok([10.0 * (y - x * x), 1.0 - x])
+        }
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("logarithm of a non-positive number")]
+    struct DomainError;
+
+    /// Natural logarithm, which fails for non-positive inputs.
+    struct Log;
+
+    impl Model for Log {
+        type Input = f64;
+        type Output = f64;
+        type Error = DomainError;
+
+        fn call(&self, &x: &f64) -> Result<f64, DomainError> {
+            if x <= 0.0 {
+                return Err(DomainError);
+            }
+            Ok(x.ln())
+        }
+    }
+
+    /// Drives the model output to zero, so the root is `x = 1` for [`Log`].
+    struct ZeroOutput;
+
+    impl LeastSquaresProblem<1, 1> for ZeroOutput {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 1]) -> Result<f64, Infallible> {
+            Ok(x[0])
+        }
+
+        fn residuals(&self, _: &f64, output: &f64) -> Result<[f64; 1], Infallible> {
+            Ok([*output])
+        }
+    }
+
+    // --- Tests ---
+
+    #[test]
+    fn fits_exact_exponential_decay() {
+        let problem = Decay::exact(2.0, 0.5);
+        let solution = minimize_unobserved(&Identity, &problem, [1.0, 1.0], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 2.0, epsilon = 1e-8);
+        assert_relative_eq!(solution.x[1], 0.5, epsilon = 1e-8);
+        assert!(solution.cost < 1e-16);
+    }
+
+    #[test]
+    fn reports_jacobian_and_covariance_of_line_fit() {
+        let solution = minimize_unobserved(&Identity, &Line, [0.0, 0.0], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.04, epsilon = 1e-8);
+        assert_relative_eq!(solution.x[1], 1.0, epsilon = 1e-8);
+        assert_relative_eq!(solution.cost, 0.072, epsilon = 1e-10);
+
+        let jacobian = solution.jacobian.expect("computed");
+        assert_relative_eq!(jacobian[3][0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(jacobian[3][1], 3.0, epsilon = 1e-6);
+
+        // s² = 0.072 / 3, var(b) = s² / 10, var(a) = s² (1/5 + 4/10).
+        let covariance = solution.covariance.expect("overdetermined");
+        assert_relative_eq!(covariance[1][1], 0.0024, epsilon = 1e-8);
+        assert_relative_eq!(covariance[0][0], 0.0144, epsilon = 1e-8);
+        assert_relative_eq!(covariance[0][1], covariance[1][0], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn solves_rosenbrock_from_poor_guess() {
+        let solution = minimize_unobserved(&Identity, &Rosenbrock, [-1.2, 1.0], &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-8);
+        assert_relative_eq!(solution.x[1], 1.0, epsilon = 1e-8);
+        assert!(solution.covariance.is_none());
+    }
+
+    #[test]
+    fn observer_can_stop_early() {
+        let mut trials = 0;
+
+        let solution = minimize(
+            &Identity,
+            &Rosenbrock,
+            [-1.2, 1.0],
+            &Config::default(),
+            |event: &Event<'_, Identity, Rosenbrock, 2, 2>| {
+                if let Event::Trial { .. } = event {
+                    trials += 1;
+                }
+                (trials == 3).then_some(Action::StopEarly)
+            },
+        )
+        .expect("should stop");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.iters, 2);
+        assert!(solution.jacobian.is_some());
+    }
+
+    #[test]
+    fn failed_trial_is_rejected_and_retried() {
+        // The first Gauss–Newton step from x = 3 lands near x = -0.3, where the
+        // logarithm is undefined.
+        let solution = minimize_unobserved(&Log, &ZeroOutput, [3.0], &Config::default())
+            .expect("should recover from the failed trial");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-8);
+        assert!(solution.stats.failures >= 1);
+    }
+
+    #[test]
+    fn failed_initial_guess_is_an_error() {
+        let result = minimize_unobserved(&Log, &ZeroOutput, [-1.0], &Config::default());
+
+        assert!(matches!(result, Err(Error::Model(_))));
+    }
+
+    #[test]
+    fn max_iters_reports_current_iterate() {
+        let config = Config {
+            max_iters: 2,
+            ..Config::default()
+        };
+
+        let solution = minimize_unobserved(&Identity, &Rosenbrock, [-1.2, 1.0], &config)
+            .expect("should finish");
+
+        assert_eq!(solution.status, Status::MaxIters);
+        assert_eq!(solution.iters, 2);
+        // The initial cost is 24.2, and only improving steps are accepted.
+        assert!(solution.cost <= 24.2);
+    }
+}
@@ -0,0 +1,6 @@
+/// Control actions supported by the Levenberg–Marquardt solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Stop the solver early and return the current iterate.
+    StopEarly,
+}
@@ -0,0 +1,114 @@
+use thiserror::Error;
+
+use crate::equation::newton::Difference;
+
+/// Configuration for the Levenberg–Marquardt solver.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    /// Maximum number of trial steps, accepted or rejected.
+    pub max_iters: usize,
+    pub x_abs_tol: f64,
+    pub x_rel_tol: f64,
+    /// Converge when every residual magnitude is within this tolerance.
+    pub residual_tol: f64,
+    /// Converge when every component of the cost gradient `2 Jᵀr` is within
+    /// this tolerance in magnitude.
+    pub gradient_tol: f64,
+    /// Initial damping, relative to the largest diagonal entry of `JᵀJ`.
+    ///
+    /// Small values start close to Gauss–Newton; larger values start closer
+    /// to gradient descent.
+    pub initial_damping: f64,
+    /// Finite difference scheme used to approximate the Jacobian.
+    pub difference: Difference,
+    /// Relative perturbation size for finite differences.
+    ///
+    /// Each variable is perturbed by `fd_step * max(|x_j|, 1)`.
+    pub fd_step: f64,
+}
+
+/// Errors that can occur when validating a Levenberg–Marquardt config.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("x_abs_tol must be finite and non-negative")]
+    XAbs,
+
+    #[error("x_rel_tol must be finite and non-negative")]
+    XRel,
+
+    #[error("residual_tol must be finite and non-negative")]
+    Residual,
+
+    #[error("gradient_tol must be finite and non-negative")]
+    Gradient,
+
+    #[error("initial_damping must be finite and positive")]
+    Damping,
+
+    #[error("fd_step must be finite and positive")]
+    FdStep,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_iters: 200,
+            x_abs_tol: 1e-12,
+            x_rel_tol: 1e-12,
+            residual_tol: 1e-12,
+            gradient_tol: 1e-10,
+            initial_damping: 1e-3,
+            difference: Difference::Forward,
+            fd_step: f64::EPSILON.sqrt(),
+        }
+    }
+}
+
+impl Config {
+    /// Validates the tolerances, initial damping, and finite difference step.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any tolerance is negative or non-finite,
+    /// or if `initial_damping` or `fd_step` is not finite and positive.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !self.x_abs_tol.is_finite() || self.x_abs_tol < 0.0 {
+            return Err(ConfigError::XAbs);
+        }
+        if !self.x_rel_tol.is_finite() || self.x_rel_tol < 0.0 {
+            return Err(ConfigError::XRel);
+        }
+        if !self.residual_tol.is_finite() || self.residual_tol < 0.0 {
+            return Err(ConfigError::Residual);
+        }
+        if !self.gradient_tol.is_finite() || self.gradient_tol < 0.0 {
+            return Err(ConfigError::Gradient);
+        }
+        if !self.initial_damping.is_finite() || self.initial_damping <= 0.0 {
+            return Err(ConfigError::Damping);
+        }
+        if !self.fd_step.is_finite() || self.fd_step <= 0.0 {
+            return Err(ConfigError::FdStep);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_non_positive_damping() {
+        let config = Config {
+            initial_damping: 0.0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Damping));
+    }
+}
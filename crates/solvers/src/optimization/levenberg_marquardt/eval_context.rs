@@ -0,0 +1,111 @@
+use twine_core::{LeastSquaresProblem, Model, Observer};
+
+use crate::{
+    equation::newton::{Difference, jacobian},
+    stats::Tally,
+};
+
+use super::{
+    Action, Error, Event,
+    evaluate::{Evaluation, evaluate},
+};
+
+/// A successful evaluation of model `M`.
+type ModelEvaluation<M, const N: usize, const R: usize> =
+    Evaluation<<M as Model>::Input, <M as Model>::Output, N, R>;
+
+/// How the solver should proceed after an observed evaluation.
+pub(super) enum Outcome<T> {
+    Continue(T),
+    RejectStep,
+    StopEarly,
+}
+
+/// Bundles evaluation and observation for a single Levenberg–Marquardt solve.
+///
+/// An observer request to stop always takes precedence over an evaluation
+/// error.
+pub(super) struct EvalContext<'ctx, M, P, Obs> {
+    pub(super) model: &'ctx M,
+    pub(super) problem: &'ctx P,
+    pub(super) observer: &'ctx mut Obs,
+    pub(super) tally: &'ctx Tally,
+}
+
+impl<M, P, Obs> EvalContext<'_, M, P, Obs>
+where
+    M: Model,
+{
+    /// Evaluates a trial point.
+    ///
+    /// A failed evaluation rejects the step rather than ending the solve, so
+    /// the solver can retry with more damping.
+    pub(super) fn trial<const N: usize, const R: usize>(
+        &mut self,
+        iter: usize,
+        damping: f64,
+        x: [f64; N],
+    ) -> Outcome<ModelEvaluation<M, N, R>>
+    where
+        P: LeastSquaresProblem<N, R, Input = M::Input, Output = M::Output>,
+        Obs: for<'evt> Observer<Event<'evt, M, P, N, R>, Action>,
+    {
+        let result = evaluate(self.model, self.problem, x);
+        self.tally.evaluated(&result);
+        let action = self.observer.observe(&Event::Trial {
+            iter,
+            damping,
+            x,
+            result: &result,
+        });
+        self.tally.observed(action.as_ref());
+
+        match (action, result) {
+            (Some(Action::StopEarly), _) => Outcome::StopEarly,
+            (None, Ok(eval)) => Outcome::Continue(eval),
+            (None, Err(_)) => Outcome::RejectStep,
+        }
+    }
+
+    /// Approximates the Jacobian at an evaluated point by finite differences.
+    ///
+    /// Returns `None` if the observer stops early. A perturbed point is not a
+    /// step, so there is nothing to reject.
+    pub(super) fn jacobian<const N: usize, const R: usize>(
+        &mut self,
+        eval: &ModelEvaluation<M, N, R>,
+        difference: Difference,
+        fd_step: f64,
+    ) -> Result<Option<[[f64; N]; R]>, Error>
+    where
+        P: LeastSquaresProblem<N, R, Input = M::Input, Output = M::Output>,
+        Obs: for<'evt> Observer<Event<'evt, M, P, N, R>, Action>,
+    {
+        let points = jacobian::perturbations(&eval.x, difference, fd_step);
+        let mut residuals = Vec::with_capacity(points.len());
+
+        for point in &points {
+            let result = evaluate(self.model, self.problem, point.x);
+            self.tally.evaluated(&result);
+            let action = self.observer.observe(&Event::Jacobian {
+                column: point.column,
+                x: point.x,
+                result: &result,
+            });
+            self.tally.observed(action.as_ref());
+
+            if let Some(Action::StopEarly) = action {
+                return Ok(None);
+            }
+            residuals.push(result?.residuals);
+        }
+
+        Ok(Some(jacobian::assemble(
+            &eval.x,
+            &eval.residuals,
+            difference,
+            &points,
+            &residuals,
+        )))
+    }
+}
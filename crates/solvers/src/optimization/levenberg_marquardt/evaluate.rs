@@ -0,0 +1,51 @@
+use twine_core::{LeastSquaresProblem, Model, Snapshot};
+
+use crate::optimization::EvalError;
+
+/// The result of evaluating a least-squares problem at a given `x`.
+#[derive(Debug, Clone)]
+pub struct Evaluation<I, O, const N: usize, const R: usize> {
+    pub x: [f64; N],
+    pub residuals: [f64; R],
+    pub snapshot: Snapshot<I, O>,
+}
+
+impl<I, O, const N: usize, const R: usize> Evaluation<I, O, N, R> {
+    /// Returns the sum of squared residuals.
+    #[must_use]
+    pub fn cost(&self) -> f64 {
+        self.residuals.iter().map(|r| r * r).sum()
+    }
+}
+
+/// Type alias for the result of evaluating a least-squares problem.
+pub type EvaluateResult<M, P, const N: usize, const R: usize> = Result<
+    Evaluation<<M as Model>::Input, <M as Model>::Output, N, R>,
+    EvalError<<M as Model>::Error, <P as LeastSquaresProblem<N, R>>::Error>,
+>;
+
+/// Evaluates the model in the context of a least-squares problem.
+///
+/// This function maps `x` to model input, calls the model, then computes
+/// residuals from the input and output.
+pub(super) fn evaluate<M, P, const N: usize, const R: usize>(
+    model: &M,
+    problem: &P,
+    x: [f64; N],
+) -> EvaluateResult<M, P, N, R>
+where
+    M: Model,
+    P: LeastSquaresProblem<N, R, Input = M::Input, Output = M::Output>,
+{
+    let input = problem.input(&x).map_err(EvalError::Problem)?;
+    let output = model.call(&input).map_err(EvalError::Model)?;
+    let residuals = problem
+        .residuals(&input, &output)
+        .map_err(EvalError::Problem)?;
+
+    Ok(Evaluation {
+        x,
+        residuals,
+        snapshot: Snapshot::new(input, output),
+    })
+}
@@ -0,0 +1,54 @@
+use twine_core::{LeastSquaresProblem, Model};
+
+use super::EvaluateResult;
+
+/// Events emitted by the Levenberg–Marquardt solver.
+pub enum Event<'a, M, P, const N: usize, const R: usize>
+where
+    M: Model,
+    P: LeastSquaresProblem<N, R, Input = M::Input, Output = M::Output>,
+{
+    /// Evaluation of a trial step.
+    ///
+    /// Iteration 0 is the initial guess, evaluated with no damping.
+    Trial {
+        /// The iteration that produced this point.
+        iter: usize,
+        /// The damping parameter used for the step.
+        damping: f64,
+        /// The x value that was evaluated.
+        x: [f64; N],
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, N, R>,
+    },
+    /// Perturbed evaluation used to approximate a Jacobian column.
+    Jacobian {
+        /// The Jacobian column (solver variable) being perturbed.
+        column: usize,
+        /// The x value that was evaluated.
+        x: [f64; N],
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, N, R>,
+    },
+}
+
+impl<'a, M, P, const N: usize, const R: usize> Event<'a, M, P, N, R>
+where
+    M: Model,
+    P: LeastSquaresProblem<N, R, Input = M::Input, Output = M::Output>,
+{
+    /// Returns the evaluated x value.
+    #[must_use]
+    pub fn x(&self) -> [f64; N] {
+        match self {
+            Event::Trial { x, .. } | Event::Jacobian { x, .. } => *x,
+        }
+    }
+
+    /// Returns the evaluation result.
+    pub fn result(&self) -> &'a EvaluateResult<M, P, N, R> {
+        match self {
+            Event::Trial { result, .. } | Event::Jacobian { result, .. } => result,
+        }
+    }
+}
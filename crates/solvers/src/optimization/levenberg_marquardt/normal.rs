@@ -0,0 +1,117 @@
+use crate::linalg;
+
+/// The normal equations `JᵀJ` and `Jᵀr` of a linearized least-squares problem.
+pub(super) struct Normal<const N: usize> {
+    /// The Gauss–Newton approximation of the cost Hessian, `JᵀJ`.
+    pub(super) jtj: [[f64; N]; N],
+    /// Half the cost gradient, `Jᵀr`.
+    pub(super) jtr: [f64; N],
+}
+
+impl<const N: usize> Normal<N> {
+    /// Forms the normal equations from a row-major Jacobian and residuals.
+    pub(super) fn new<const R: usize>(jacobian: &[[f64; N]; R], residuals: &[f64; R]) -> Self {
+        let mut jtj = [[0.0; N]; N];
+        let mut jtr = [0.0; N];
+
+        for (row, r) in jacobian.iter().zip(residuals) {
+            for j in 0..N {
+                jtr[j] += row[j] * r;
+                for k in 0..N {
+                    jtj[j][k] += row[j] * row[k];
+                }
+            }
+        }
+
+        Self { jtj, jtr }
+    }
+
+    /// Returns the largest diagonal entry of `JᵀJ`.
+    pub(super) fn max_diagonal(&self) -> f64 {
+        (0..N).fold(0.0, |max, j| f64::max(max, self.jtj[j][j]))
+    }
+
+    /// Solves the damped system `(JᵀJ + λ D) δ = -Jᵀr` for the step `δ`.
+    ///
+    /// `D` is the diagonal of `JᵀJ`, floored so that variables the residuals
+    /// do not depend on still get damped. This makes the step invariant to
+    /// the scaling of each variable.
+    ///
+    /// Returns `None` if the system is singular.
+    pub(super) fn step(&self, damping: f64) -> Option<[f64; N]> {
+        let floor = f64::EPSILON * self.max_diagonal().max(1.0);
+
+        let mut a = self.jtj;
+        for (j, row) in a.iter_mut().enumerate() {
+            row[j] += damping * self.jtj[j][j].max(floor);
+        }
+
+        linalg::solve(a, self.jtr.map(|v| -v))
+    }
+
+    /// Returns the cost reduction predicted by the linear model for `step`.
+    ///
+    /// The cost is the sum of squared residuals, so the prediction is
+    /// `-(2 δ·Jᵀr + δᵀJᵀJδ)`.
+    pub(super) fn predicted_reduction(&self, step: &[f64; N]) -> f64 {
+        let quadratic: f64 = (0..N)
+            .map(|j| step[j] * linalg::dot(&self.jtj[j], step))
+            .sum();
+        -(2.0 * linalg::dot(step, &self.jtr) + quadratic)
+    }
+
+    /// Returns the parameter covariance estimate `s² (JᵀJ)⁻¹`.
+    ///
+    /// Returns `None` if `JᵀJ` is singular or `R <= N`.
+    pub(super) fn covariance<const R: usize>(&self, cost: f64) -> Option<[[f64; N]; N]> {
+        let dof = R.checked_sub(N).filter(|&dof| dof > 0)?;
+        let variance = cost / f64::from(u32::try_from(dof).ok()?);
+
+        let mut covariance = [[0.0; N]; N];
+        for j in 0..N {
+            let mut unit = [0.0; N];
+            unit[j] = 1.0;
+            let column = linalg::solve(self.jtj, unit)?;
+            for (row, value) in covariance.iter_mut().zip(column) {
+                row[j] = variance * value;
+            }
+        }
+
+        Some(covariance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn covariance_of_line_fit() {
+        // Fitting y = a + b t at t = 0, 1, 2 gives JᵀJ = [[3, 3], [3, 5]].
+        let jacobian = [[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]];
+        let normal = Normal::new(&jacobian, &[0.0; 3]);
+
+        // With cost 2 and one degree of freedom, s² = 2.
+        let covariance = normal.covariance::<3>(2.0).expect("full rank");
+        assert_relative_eq!(covariance[0][0], 2.0 * 5.0 / 6.0, epsilon = 1e-12);
+        assert_relative_eq!(covariance[0][1], -1.0, epsilon = 1e-12);
+        assert_relative_eq!(covariance[1][1], 1.0, epsilon = 1e-12);
+
+        assert!(normal.covariance::<2>(2.0).is_none());
+    }
+
+    #[test]
+    fn undamped_step_is_gauss_newton() {
+        // Residuals r = J x - b are linear, so one Gauss–Newton step solves
+        // the problem exactly from x = 0, where r = -b.
+        let jacobian = [[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]];
+        let normal = Normal::new(&jacobian, &[-1.0, -2.0, -3.0]);
+
+        let step = normal.step(0.0).expect("full rank");
+        assert_relative_eq!(step[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(step[1], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(normal.predicted_reduction(&step), 14.0, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,56 @@
+use twine_core::Snapshot;
+
+use crate::Stats;
+
+/// Indicates why the solver finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Converged according to the configured tolerances.
+    Converged,
+
+    /// Reached the iteration limit without converging.
+    MaxIters,
+
+    /// Stopped early due to an observer decision.
+    StoppedByObserver,
+}
+
+/// The result of a Levenberg–Marquardt optimization.
+#[derive(Debug, Clone)]
+pub struct Solution<I, O, const N: usize, const R: usize> {
+    /// Final solver status.
+    pub status: Status,
+
+    /// The final iterate.
+    pub x: [f64; N],
+
+    /// Residuals at the reported x.
+    pub residuals: [f64; R],
+
+    /// Sum of squared residuals at the reported x.
+    pub cost: f64,
+
+    /// Jacobian of the residuals at the reported x, row-major.
+    ///
+    /// `jacobian[i][j]` is the derivative of residual `i` with respect to
+    /// `x[j]`. `None` if the solver stopped before it was computed.
+    pub jacobian: Option<[[f64; N]; R]>,
+
+    /// Estimated covariance of the reported x.
+    ///
+    /// Computed as `s² (JᵀJ)⁻¹`, where `s² = cost / (R - N)` estimates the
+    /// residual variance. This assumes independent residuals with equal
+    /// variance and a model that is nearly linear near the solution.
+    /// `None` if the Jacobian is unavailable or rank deficient, or if there
+    /// are no more residuals than variables.
+    pub covariance: Option<[[f64; N]; N]>,
+
+    /// Snapshot at the reported x.
+    pub snapshot: Snapshot<I, O>,
+
+    /// Iteration count when the solver finished.
+    pub iters: usize,
+
+    /// Model evaluations and time spent, including Jacobian evaluations.
+    pub stats: Stats,
+}
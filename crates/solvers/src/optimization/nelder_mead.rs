@@ -0,0 +1,547 @@
+//! Nelder–Mead simplex method for multi-variable optimization.
+//!
+//! # Algorithm
+//!
+//! The solver keeps a simplex of `N + 1` vertices and, each iteration, tries to
+//! replace the worst vertex with a better point on the line through it and
+//! the centroid `c` of the other vertices:
+//!
+//! ```text
+//! reflection:           x_r = c + α (c - x_worst)
+//! expansion:            x_e = c + γ (x_r - c)
+//! outside contraction:  x_c = c + ρ (x_r - c)
+//! inside contraction:   x_c = c + ρ (x_worst - c)
+//! ```
+//!
+//! If no candidate is accepted, every vertex shrinks toward the best one,
+//! `x_i = x_best + σ (x_i - x_best)`. The coefficients `α`, `γ`, `ρ`, and `σ`
+//! are set in [`Config`], and the starting simplex by [`InitialSimplex`].
+//!
+//! # When to Use
+//!
+//! Nelder–Mead is appropriate when:
+//! - The objective has a few variables (roughly `N <= 10`)
+//! - Derivatives are unavailable or the objective is noisy or non-smooth
+//!
+//! It needs no gradient and is robust, but converges slowly and can stall on
+//! higher-dimensional or badly scaled problems. For single-variable problems,
+//! prefer [`brent`](super::brent).
+//!
+//! # Convergence
+//!
+//! The search converges when both:
+//! - The [simplex size](Simplex::size) is at most `x_abs_tol + x_rel_tol * max|x_best|`, and
+//! - The [objective spread](Simplex::spread) is at most `objective_tol`.
+//!
+//! # Observer Events
+//!
+//! The solver emits one [`Event`] per evaluation:
+//!
+//! - [`Event::Initial`] — evaluation of a vertex of the initial simplex
+//! - [`Event::Trial`] — evaluation of a trial point, along with the [`Step`]
+//!   that proposed it and the current [`Simplex`] (whose first vertex is the
+//!   best so far)
+//!
+//! Observers can return [`Action::StopEarly`] to halt and return the best point,
+//! or [`Action::AssumeWorse`] to treat the point as worse than every vertex.
+//!
+//! # Parallel Evaluation
+//!
+//! The vertices of the initial simplex are independent. With the `parallel`
+//! feature, `minimize_parallel` and `maximize_parallel` evaluate them on
+//! separate threads and then emit their events in vertex order. The serial
+//! entry points evaluate and observe one vertex at a time, so an observer can
+//! stop before the rest are evaluated.
+
+mod action;
+mod config;
+mod error;
+mod event;
+mod search;
+mod simplex;
+mod solution;
+
+pub use action::Action;
+pub use config::{Config, ConfigError};
+pub use error::Error;
+pub use event::{Event, Step};
+pub use simplex::{InitialSimplex, Simplex, Vertex};
+pub use solution::{Solution, Status};
+
+use twine_core::{Model, Observer, OptimizationProblem};
+
+/// Finds the minimum of the objective using the Nelder–Mead method.
+///
+/// The observer receives an [`Event`] for each evaluation.
+/// See the [module docs](self) for details on convergence and observer actions.
+///
+/// # Errors
+///
+/// Returns an error if the config or initial simplex is invalid, or if the
+/// model or problem fails during evaluation and the observer does not return
+/// [`Action::AssumeWorse`] to recover.
+pub fn minimize<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: &InitialSimplex<N>,
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    search::search(model, problem, initial, config, observer, |v| v, None)
+}
+
+/// Finds the minimum of the objective without observer support.
+///
+/// This is a convenience wrapper around [`minimize`] that uses a no-op observer.
+///
+/// # Errors
+///
+/// Returns an error if the config or initial simplex is invalid, or if the
+/// model or problem fails during evaluation.
+pub fn minimize_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: &InitialSimplex<N>,
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    minimize(model, problem, initial, config, ())
+}
+
+/// Finds the maximum of the objective using the Nelder–Mead method.
+///
+/// The observer receives an [`Event`] for each evaluation.
+/// See the [module docs](self) for details on convergence and observer actions.
+///
+/// # Errors
+///
+/// Returns an error if the config or initial simplex is invalid, or if the
+/// model or problem fails during evaluation and the observer does not return
+/// [`Action::AssumeWorse`] to recover.
+pub fn maximize<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: &InitialSimplex<N>,
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    search::search(model, problem, initial, config, observer, |v| -v, None)
+}
+
+/// Finds the maximum of the objective without observer support.
+///
+/// This is a convenience wrapper around [`maximize`] that uses a no-op observer.
+///
+/// # Errors
+///
+/// Returns an error if the config or initial simplex is invalid, or if the
+/// model or problem fails during evaluation.
+pub fn maximize_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: &InitialSimplex<N>,
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    maximize(model, problem, initial, config, ())
+}
+
+/// Finds the minimum of the objective, evaluating the initial simplex on
+/// separate threads.
+///
+/// Otherwise identical to [`minimize`].
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`minimize`].
+#[cfg(feature = "parallel")]
+pub fn minimize_parallel<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: &InitialSimplex<N>,
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model + Sync,
+    M::Input: Send,
+    M::Output: Send,
+    M::Error: Send,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output> + Sync,
+    P::Error: Send,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    let batch = crate::optimization::evaluate_batch;
+    search::search(
+        model,
+        problem,
+        initial,
+        config,
+        observer,
+        |v| v,
+        Some(batch),
+    )
+}
+
+/// Finds the maximum of the objective, evaluating the initial simplex on
+/// separate threads.
+///
+/// Otherwise identical to [`maximize`].
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`maximize`].
+#[cfg(feature = "parallel")]
+pub fn maximize_parallel<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: &InitialSimplex<N>,
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model + Sync,
+    M::Input: Send,
+    M::Output: Send,
+    M::Error: Send,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output> + Sync,
+    P::Error: Send,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+{
+    let batch = crate::optimization::evaluate_batch;
+    search::search(
+        model,
+        problem,
+        initial,
+        config,
+        observer,
+        |v| -v,
+        Some(batch),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{cell::Cell, convert::Infallible};
+
+    use approx::assert_relative_eq;
+    use thiserror::Error;
+
+    // --- Test fixtures ---
+
+    /// Rosenbrock's function, with its minimum of 0 at `(1, 1)`.
+    struct Rosenbrock;
+
+    impl Model for Rosenbrock {
+        type Input = [f64; 2];
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, &[x, y]: &[f64; 2]) -> Result<f64, Infallible> {
+            Ok((1.0 - x).powi(2) + 100.0 * (y - x * x).powi(2))
+        }
+    }
+
+    /// A downward paraboloid with its maximum of 5 at `(2, -1, 0.5)`.
+    struct Dome;
+
+    impl Model for Dome {
+        type Input = [f64; 3];
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, &[x, y, z]: &[f64; 3]) -> Result<f64, Infallible> {
+            Ok(5.0 - (x - 2.0).powi(2) - 2.0 * (y + 1.0).powi(2) - 3.0 * (z - 0.5).powi(2))
+        }
+    }
+
+    #[derive(Debug, Error)]
+    #[error("outside the valid domain")]
+    struct DomainError;
+
+    /// `(x - 1)² + (y - 2)²`, but only defined for `x <= 1.5`.
+    struct Bounded;
+
+    impl Model for Bounded {
+        type Input = [f64; 2];
+        type Output = f64;
+        type Error = DomainError;
+
+        fn call(&self, &[x, y]: &[f64; 2]) -> Result<f64, DomainError> {
+            if x > 1.5 {
+                return Err(DomainError);
+            }
+            Ok((x - 1.0).powi(2) + (y - 2.0).powi(2))
+        }
+    }
+
+    /// Rosenbrock's function that counts its calls, so it is not `Sync`.
+    #[derive(Default)]
+    struct CountingRosenbrock {
+        calls: Cell<usize>,
+    }
+
+    impl Model for CountingRosenbrock {
+        type Input = [f64; 2];
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, x: &[f64; 2]) -> Result<f64, Infallible> {
+            self.calls.set(self.calls.get() + 1);
+            Rosenbrock.call(x)
+        }
+    }
+
+    /// Maps solver variables directly to model inputs.
+    struct Direct;
+
+    impl<const N: usize> OptimizationProblem<N> for Direct {
+        type Input = [f64; N];
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; N]) -> Result<[f64; N], Infallible> {
+            Ok(*x)
+        }
+
+        fn objective(&self, _: &[f64; N], output: &f64) -> Result<f64, Infallible> {
+            Ok(*output)
+        }
+    }
+
+    // --- Tests ---
+
+    #[test]
+    fn minimizes_rosenbrock() {
+        let solution = minimize_unobserved(
+            &Rosenbrock,
+            &Direct,
+            &InitialSimplex::around([-1.2, 1.0]),
+            &Config::default(),
+        )
+        .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(solution.x[1], 1.0, epsilon = 1e-6);
+        assert!(solution.objective < 1e-12);
+        assert_relative_eq!(solution.snapshot.output, solution.objective);
+    }
+
+    #[test]
+    fn maximizes_dome() {
+        let initial = InitialSimplex::Steps {
+            x0: [0.0, 0.0, 0.0],
+            steps: [1.0, 1.0, 1.0],
+        };
+
+        let solution = maximize_unobserved(&Dome, &Direct, &initial, &Config::default())
+            .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 2.0, epsilon = 1e-6);
+        assert_relative_eq!(solution.x[1], -1.0, epsilon = 1e-6);
+        assert_relative_eq!(solution.x[2], 0.5, epsilon = 1e-6);
+        assert_relative_eq!(solution.objective, 5.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn explicit_vertices_are_used_as_given() {
+        let initial = InitialSimplex::Vertices(vec![[3.0, 3.0], [4.0, 3.0], [3.0, 4.0]]);
+        let mut first = Vec::new();
+
+        let solution = minimize(
+            &Rosenbrock,
+            &Direct,
+            &initial,
+            &Config::default(),
+            |event: &Event<'_, Rosenbrock, Direct, 2>| {
+                if let Event::Initial { x, .. } = event {
+                    first.push(*x);
+                }
+                None
+            },
+        )
+        .expect("should converge");
+
+        assert_eq!(first, vec![[3.0, 3.0], [4.0, 3.0], [3.0, 4.0]]);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn trial_events_expose_simplex_and_best() {
+        let mut best_objectives = Vec::new();
+
+        minimize(
+            &Rosenbrock,
+            &Direct,
+            &InitialSimplex::around([-1.2, 1.0]),
+            &Config::default(),
+            |event: &Event<'_, Rosenbrock, Direct, 2>| {
+                if let Some(simplex) = event.simplex() {
+                    assert_eq!(simplex.vertices().len(), 3);
+                    best_objectives.push(event.best().expect("simplex exists").objective);
+                }
+                None
+            },
+        )
+        .expect("should converge");
+
+        // The best vertex never gets worse.
+        assert!(best_objectives.len() > 10);
+        assert!(best_objectives.windows(2).all(|pair| pair[1] <= pair[0]));
+    }
+
+    #[test]
+    fn initial_vertices_are_observed_as_they_are_evaluated() {
+        let model = CountingRosenbrock::default();
+
+        let solution = minimize(
+            &model,
+            &Direct,
+            &InitialSimplex::around([-1.2, 1.0]),
+            &Config::default(),
+            |event: &Event<'_, CountingRosenbrock, Direct, 2>| match event {
+                Event::Initial { index, .. } => {
+                    assert_eq!(model.calls.get(), index + 1);
+                    (*index == 1).then_some(Action::StopEarly)
+                }
+                Event::Trial { .. } => panic!("should stop during initialization"),
+            },
+        )
+        .expect("should stop");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.stats.evaluations, 2);
+        assert_eq!(model.calls.get(), 2);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_matches_serial() {
+        let initial = InitialSimplex::around([-1.2, 1.0]);
+        let config = Config::default();
+
+        let serial =
+            minimize(&Rosenbrock, &Direct, &initial, &config, ()).expect("should converge");
+        let parallel = minimize_parallel(&Rosenbrock, &Direct, &initial, &config, ())
+            .expect("should converge");
+
+        assert_eq!(parallel.x.map(f64::to_bits), serial.x.map(f64::to_bits));
+        assert_eq!(parallel.iters, serial.iters);
+        assert_eq!(parallel.stats.evaluations, serial.stats.evaluations);
+    }
+
+    #[test]
+    fn stop_early_returns_best_so_far() {
+        let solution = minimize(
+            &Rosenbrock,
+            &Direct,
+            &InitialSimplex::around([-1.2, 1.0]),
+            &Config::default(),
+            |event: &Event<'_, Rosenbrock, Direct, 2>| match event {
+                Event::Trial { iter: 5, .. } => Some(Action::StopEarly),
+                _ => None,
+            },
+        )
+        .expect("should stop");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.iters, 5);
+        assert!(solution.objective < Rosenbrock.call(&[-1.2, 1.0]).unwrap());
+    }
+
+    #[test]
+    fn assume_worse_recovers_from_errors() {
+        let initial = InitialSimplex::Steps {
+            x0: [0.0, 0.0],
+            steps: [1.0, 1.0],
+        };
+
+        let solution = minimize(
+            &Bounded,
+            &Direct,
+            &initial,
+            &Config::default(),
+            |event: &Event<'_, Bounded, Direct, 2>| {
+                event.result().is_err().then_some(Action::AssumeWorse)
+            },
+        )
+        .expect("should converge");
+
+        assert_eq!(solution.status, Status::Converged);
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(solution.x[1], 2.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn model_error_without_recovery_is_returned() {
+        let initial = InitialSimplex::Steps {
+            x0: [0.0, 0.0],
+            steps: [1.0, 1.0],
+        };
+
+        let result = minimize_unobserved(&Bounded, &Direct, &initial, &Config::default());
+
+        assert!(matches!(result, Err(Error::Model(_))));
+    }
+
+    #[test]
+    fn max_iters_reports_best() {
+        let config = Config {
+            max_iters: 3,
+            ..Config::default()
+        };
+
+        let solution = minimize_unobserved(
+            &Rosenbrock,
+            &Direct,
+            &InitialSimplex::around([-1.2, 1.0]),
+            &config,
+        )
+        .expect("should finish");
+
+        assert_eq!(solution.status, Status::MaxIters);
+        assert_eq!(solution.iters, 3);
+    }
+
+    #[test]
+    fn invalid_inputs_are_errors() {
+        let config = Config {
+            shrink: 1.5,
+            ..Config::default()
+        };
+        let result = minimize_unobserved(
+            &Rosenbrock,
+            &Direct,
+            &InitialSimplex::around([0.0, 0.0]),
+            &config,
+        );
+        assert!(matches!(
+            result,
+            Err(Error::InvalidConfig(ConfigError::Shrink))
+        ));
+
+        let flat = InitialSimplex::Vertices(vec![[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]]);
+        let result = minimize_unobserved(&Rosenbrock, &Direct, &flat, &Config::default());
+        assert!(matches!(result, Err(Error::InvalidSimplex)));
+    }
+}
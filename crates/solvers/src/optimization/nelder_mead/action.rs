@@ -0,0 +1,19 @@
+/// Actions an observer can take during a Nelder–Mead search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Stop the solver early and return the best solution found so far.
+    StopEarly,
+
+    /// Treat this point as worse than every vertex of the simplex.
+    ///
+    /// A trial point assumed worse is never accepted into the simplex, so the
+    /// solver contracts or shrinks away from it. An initial vertex assumed
+    /// worse stays in the simplex as its worst vertex.
+    /// The evaluation (if successful) is not considered for the best solution.
+    ///
+    /// Use this for:
+    /// - Recovering from model or problem errors when domain knowledge suggests
+    ///   the failed region is suboptimal but the search should continue.
+    /// - Steering the search away from a region even when evaluation succeeded.
+    AssumeWorse,
+}
@@ -0,0 +1,136 @@
+use thiserror::Error;
+
+/// Configuration for the Nelder–Mead solver.
+///
+/// The default coefficients are the standard choices
+/// (`reflection = 1`, `expansion = 2`, `contraction = 0.5`, `shrink = 0.5`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    /// Maximum number of simplex updates (reflect, expand, contract, or shrink).
+    pub max_iters: usize,
+    /// Absolute tolerance on the simplex size.
+    ///
+    /// The size is the largest distance (infinity norm) from the best vertex
+    /// to any other vertex.
+    pub x_abs_tol: f64,
+    /// Relative tolerance on the simplex size, scaled by `max|x_best|`.
+    pub x_rel_tol: f64,
+    /// Tolerance on the spread of objective values across the simplex.
+    pub objective_tol: f64,
+    /// Reflection coefficient `α`, must be positive.
+    pub reflection: f64,
+    /// Expansion coefficient `γ`, must exceed both 1 and `reflection`.
+    pub expansion: f64,
+    /// Contraction coefficient `ρ`, must lie in `(0, 1)`.
+    pub contraction: f64,
+    /// Shrink coefficient `σ`, must lie in `(0, 1)`.
+    pub shrink: f64,
+}
+
+/// Errors that can occur when validating a Nelder–Mead config.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("x_abs_tol must be finite and non-negative")]
+    XAbs,
+
+    #[error("x_rel_tol must be finite and non-negative")]
+    XRel,
+
+    #[error("objective_tol must be finite and non-negative")]
+    Objective,
+
+    #[error("reflection must be finite and positive")]
+    Reflection,
+
+    #[error("expansion must be finite and greater than 1 and reflection")]
+    Expansion,
+
+    #[error("contraction must be in (0, 1)")]
+    Contraction,
+
+    #[error("shrink must be in (0, 1)")]
+    Shrink,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_iters: 1000,
+            x_abs_tol: 1e-8,
+            x_rel_tol: 1e-8,
+            objective_tol: 1e-12,
+            reflection: 1.0,
+            expansion: 2.0,
+            contraction: 0.5,
+            shrink: 0.5,
+        }
+    }
+}
+
+impl Config {
+    /// Validates the tolerances and simplex coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any tolerance is negative or non-finite,
+    /// or if a coefficient is outside its documented range.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !self.x_abs_tol.is_finite() || self.x_abs_tol < 0.0 {
+            return Err(ConfigError::XAbs);
+        }
+        if !self.x_rel_tol.is_finite() || self.x_rel_tol < 0.0 {
+            return Err(ConfigError::XRel);
+        }
+        if !self.objective_tol.is_finite() || self.objective_tol < 0.0 {
+            return Err(ConfigError::Objective);
+        }
+        if !self.reflection.is_finite() || self.reflection <= 0.0 {
+            return Err(ConfigError::Reflection);
+        }
+        if !self.expansion.is_finite() || self.expansion <= self.reflection.max(1.0) {
+            return Err(ConfigError::Expansion);
+        }
+        if !(self.contraction > 0.0 && self.contraction < 1.0) {
+            return Err(ConfigError::Contraction);
+        }
+        if !(self.shrink > 0.0 && self.shrink < 1.0) {
+            return Err(ConfigError::Shrink);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_expansion_below_reflection() {
+        let config = Config {
+            reflection: 2.0,
+            expansion: 1.5,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Expansion));
+    }
+
+    #[test]
+    fn rejects_coefficients_outside_unit_interval() {
+        let config = Config {
+            contraction: 1.0,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Contraction));
+
+        let config = Config {
+            shrink: f64::NAN,
+            ..Config::default()
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Shrink));
+    }
+}
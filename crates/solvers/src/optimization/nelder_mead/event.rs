@@ -0,0 +1,86 @@
+use twine_core::{Model, OptimizationProblem};
+
+use crate::optimization::EvaluateResult;
+
+use super::{Simplex, Vertex};
+
+/// The kind of trial point proposed during an iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// The worst vertex reflected through the centroid of the others.
+    Reflection,
+    /// The reflected point pushed further from the centroid.
+    Expansion,
+    /// A point between the centroid and the reflected point.
+    OutsideContraction,
+    /// A point between the centroid and the worst vertex.
+    InsideContraction,
+    /// A vertex moved toward the best vertex.
+    Shrink,
+}
+
+/// Events emitted by the Nelder–Mead solver, one per evaluation.
+pub enum Event<'a, M, P, const N: usize>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    /// Evaluation of a vertex of the initial simplex.
+    Initial {
+        /// Index of the vertex in the initial simplex.
+        index: usize,
+        /// The x value that was evaluated.
+        x: [f64; N],
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, N>,
+    },
+    /// Evaluation of a trial point.
+    Trial {
+        /// The iteration that proposed this point.
+        iter: usize,
+        /// How the point was proposed.
+        step: Step,
+        /// The x value that was evaluated.
+        x: [f64; N],
+        /// The simplex at the start of the iteration.
+        simplex: &'a Simplex<N>,
+        /// The result of the evaluation.
+        result: &'a EvaluateResult<M, P, N>,
+    },
+}
+
+impl<'a, M, P, const N: usize> Event<'a, M, P, N>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    /// Returns the evaluated x value.
+    #[must_use]
+    pub fn x(&self) -> [f64; N] {
+        match self {
+            Event::Initial { x, .. } | Event::Trial { x, .. } => *x,
+        }
+    }
+
+    /// Returns the evaluation result.
+    pub fn result(&self) -> &'a EvaluateResult<M, P, N> {
+        match self {
+            Event::Initial { result, .. } | Event::Trial { result, .. } => result,
+        }
+    }
+
+    /// Returns the simplex, or `None` while the initial simplex is being built.
+    #[must_use]
+    pub fn simplex(&self) -> Option<&'a Simplex<N>> {
+        match self {
+            Event::Initial { .. } => None,
+            Event::Trial { simplex, .. } => Some(simplex),
+        }
+    }
+
+    /// Returns the best vertex of the simplex, if one exists yet.
+    #[must_use]
+    pub fn best(&self) -> Option<&'a Vertex<N>> {
+        self.simplex().map(Simplex::best)
+    }
+}
@@ -0,0 +1,318 @@
+use twine_core::{Model, Observer, OptimizationProblem, Snapshot};
+
+use crate::{
+    linalg,
+    optimization::evaluate::{Batch, EvaluateResult, evaluate},
+    stats::Tally,
+};
+
+use super::{
+    Action, Config, Error, Event, InitialSimplex, Simplex, Solution, Status, Step, Vertex,
+    simplex::Scored,
+};
+
+/// Core Nelder–Mead implementation.
+///
+/// The `transform` function is applied to objective values before comparison,
+/// allowing the same algorithm to handle both minimization
+/// (transform = identity) and maximization (transform = negation).
+///
+/// The initial vertices are evaluated with `batch` when one is given, and
+/// otherwise one at a time, each observed before the next is evaluated.
+pub(super) fn search<M, P, Obs, F, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: &InitialSimplex<N>,
+    config: &Config,
+    mut observer: Obs,
+    transform: F,
+    batch: Option<Batch<M, P, N>>,
+) -> Result<Solution<M::Input, M::Output, N>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+    F: Fn(f64) -> f64,
+{
+    config.validate()?;
+    let positions = initial.positions()?;
+
+    let mut ctx = EvalContext {
+        model,
+        problem,
+        observer: &mut observer,
+        transform,
+        best: None,
+        tally: Tally::start(),
+    };
+
+    let mut scored = Vec::with_capacity(N + 1);
+    if let Some(batch) = batch {
+        let results = batch(model, problem, &positions);
+        for result in &results {
+            ctx.tally.evaluated(result);
+        }
+        for (index, (x, result)) in positions.into_iter().zip(results).enumerate() {
+            match ctx.resolve(x, result, Origin::Initial(index))? {
+                Outcome::Continue(vertex) => scored.push(vertex),
+                Outcome::StopEarly => return ctx.finish(Status::StoppedByObserver, 0),
+            }
+        }
+    } else {
+        for (index, x) in positions.into_iter().enumerate() {
+            match ctx.evaluate(x, Origin::Initial(index))? {
+                Outcome::Continue(vertex) => scored.push(vertex),
+                Outcome::StopEarly => return ctx.finish(Status::StoppedByObserver, 0),
+            }
+        }
+    }
+    let mut simplex = Simplex::new(scored);
+
+    for iter in 1..=config.max_iters {
+        if is_converged(&simplex, config) {
+            return ctx.finish(Status::Converged, iter - 1);
+        }
+
+        if let Outcome::StopEarly = iterate(&mut ctx, &mut simplex, iter, config)? {
+            return ctx.finish(Status::StoppedByObserver, iter);
+        }
+    }
+
+    ctx.finish(Status::MaxIters, config.max_iters)
+}
+
+/// Returns true if both the simplex size and objective spread are within
+/// tolerance.
+fn is_converged<const N: usize>(simplex: &Simplex<N>, config: &Config) -> bool {
+    let x_tol = config.x_abs_tol + config.x_rel_tol * linalg::max_abs(&simplex.best().x);
+    simplex.size() <= x_tol && simplex.spread() <= config.objective_tol
+}
+
+/// Performs one Nelder–Mead iteration, updating the simplex in place.
+///
+/// The simplex is left unchanged if the observer stops mid-iteration.
+fn iterate<M, P, Obs, F, const N: usize>(
+    ctx: &mut EvalContext<'_, M, P, Obs, F, N>,
+    simplex: &mut Simplex<N>,
+    iter: usize,
+    config: &Config,
+) -> Result<Outcome<()>, Error>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+    F: Fn(f64) -> f64,
+{
+    let centroid = simplex.centroid();
+    let worst = simplex.worst().x;
+
+    // Moves from the centroid toward `x` by `coefficient` times their distance.
+    let toward = |x: &[f64; N], coefficient: f64| -> [f64; N] {
+        std::array::from_fn(|j| centroid[j] + coefficient * (x[j] - centroid[j]))
+    };
+
+    let mut trial = |step: Step, x: [f64; N], simplex: &Simplex<N>| {
+        ctx.evaluate(
+            x,
+            Origin::Trial {
+                iter,
+                step,
+                simplex,
+            },
+        )
+    };
+
+    let reflected_x = toward(&worst, -config.reflection);
+    let Outcome::Continue(reflected) = trial(Step::Reflection, reflected_x, simplex)? else {
+        return Ok(Outcome::StopEarly);
+    };
+
+    let accepted = if reflected.score < simplex.score(0) {
+        let x = toward(&reflected_x, config.expansion);
+        let Outcome::Continue(expanded) = trial(Step::Expansion, x, simplex)? else {
+            return Ok(Outcome::StopEarly);
+        };
+        Some(if expanded.score < reflected.score {
+            expanded
+        } else {
+            reflected
+        })
+    } else if reflected.score < simplex.score(N - 1) {
+        Some(reflected)
+    } else if reflected.score < simplex.score(N) {
+        let x = toward(&reflected_x, config.contraction);
+        let Outcome::Continue(contracted) = trial(Step::OutsideContraction, x, simplex)? else {
+            return Ok(Outcome::StopEarly);
+        };
+        (contracted.score <= reflected.score).then_some(contracted)
+    } else {
+        let x = toward(&worst, config.contraction);
+        let Outcome::Continue(contracted) = trial(Step::InsideContraction, x, simplex)? else {
+            return Ok(Outcome::StopEarly);
+        };
+        (contracted.score < simplex.score(N)).then_some(contracted)
+    };
+
+    if let Some(vertex) = accepted {
+        simplex.replace_worst(vertex);
+        return Ok(Outcome::Continue(()));
+    }
+
+    // Shrink every vertex toward the best one.
+    let best = *simplex.best();
+    let mut shrunk = Vec::with_capacity(N + 1);
+    shrunk.push(Scored {
+        vertex: best,
+        score: simplex.score(0),
+    });
+    for vertex in &simplex.vertices()[1..] {
+        let x = std::array::from_fn(|j| best.x[j] + config.shrink * (vertex.x[j] - best.x[j]));
+        match trial(Step::Shrink, x, simplex)? {
+            Outcome::Continue(vertex) => shrunk.push(vertex),
+            Outcome::StopEarly => return Ok(Outcome::StopEarly),
+        }
+    }
+    *simplex = Simplex::new(shrunk);
+
+    Ok(Outcome::Continue(()))
+}
+
+/// Whether the solver should keep going after an observed evaluation.
+enum Outcome<T> {
+    Continue(T),
+    StopEarly,
+}
+
+/// Where an evaluated point came from, used to build its event.
+#[derive(Clone, Copy)]
+enum Origin<'s, const N: usize> {
+    Initial(usize),
+    Trial {
+        iter: usize,
+        step: Step,
+        simplex: &'s Simplex<N>,
+    },
+}
+
+/// The best successful evaluation so far.
+struct Best<I, O, const N: usize> {
+    vertex: Vertex<N>,
+    score: f64,
+    snapshot: Snapshot<I, O>,
+}
+
+/// Bundles evaluation, observation, and best tracking for a single search.
+struct EvalContext<'ctx, M: Model, P, Obs, F, const N: usize> {
+    model: &'ctx M,
+    problem: &'ctx P,
+    observer: &'ctx mut Obs,
+    transform: F,
+    best: Option<Best<M::Input, M::Output, N>>,
+    tally: Tally,
+}
+
+impl<M, P, Obs, F, const N: usize> EvalContext<'_, M, P, Obs, F, N>
+where
+    M: Model,
+    P: OptimizationProblem<N, Input = M::Input, Output = M::Output>,
+    Obs: for<'a> Observer<Event<'a, M, P, N>, Action>,
+    F: Fn(f64) -> f64,
+{
+    /// Evaluates `x`, emits its event, and returns the scored vertex.
+    fn evaluate(
+        &mut self,
+        x: [f64; N],
+        origin: Origin<'_, N>,
+    ) -> Result<Outcome<Scored<N>>, Error> {
+        let result = evaluate(self.model, self.problem, x);
+        self.tally.evaluated(&result);
+        self.resolve(x, result, origin)
+    }
+
+    /// Emits the event for an evaluation of `x` and returns the scored vertex.
+    ///
+    /// An observer request to stop takes precedence over an evaluation error.
+    fn resolve(
+        &mut self,
+        x: [f64; N],
+        result: EvaluateResult<M, P, N>,
+        origin: Origin<'_, N>,
+    ) -> Result<Outcome<Scored<N>>, Error> {
+        let event = match origin {
+            Origin::Initial(index) => Event::Initial {
+                index,
+                x,
+                result: &result,
+            },
+            Origin::Trial {
+                iter,
+                step,
+                simplex,
+            } => Event::Trial {
+                iter,
+                step,
+                x,
+                simplex,
+                result: &result,
+            },
+        };
+        let action = self.observer.observe(&event);
+        self.tally.observed(action.as_ref());
+
+        match (action, result) {
+            (Some(Action::StopEarly), Ok(eval)) => {
+                self.update_best(eval.x, eval.objective, eval.snapshot);
+                Ok(Outcome::StopEarly)
+            }
+            (Some(Action::StopEarly), Err(_)) => Ok(Outcome::StopEarly),
+            (Some(Action::AssumeWorse), _) => Ok(Outcome::Continue(Scored {
+                vertex: Vertex::new(x, (self.transform)(f64::INFINITY)),
+                score: f64::INFINITY,
+            })),
+            (None, Ok(eval)) => {
+                let scored = self.update_best(eval.x, eval.objective, eval.snapshot);
+                Ok(Outcome::Continue(scored))
+            }
+            (None, Err(error)) => Err(error.into()),
+        }
+    }
+
+    /// Scores a successful evaluation and keeps it if it is the best so far.
+    fn update_best(
+        &mut self,
+        x: [f64; N],
+        objective: f64,
+        snapshot: Snapshot<M::Input, M::Output>,
+    ) -> Scored<N> {
+        let score = (self.transform)(objective);
+        let score = if score.is_nan() { f64::INFINITY } else { score };
+        let vertex = Vertex::new(x, objective);
+
+        if self.best.as_ref().is_none_or(|best| score < best.score) {
+            self.best = Some(Best {
+                vertex,
+                score,
+                snapshot,
+            });
+        }
+
+        Scored { vertex, score }
+    }
+
+    /// Finalizes the search using the best successful evaluation.
+    fn finish(
+        self,
+        status: Status,
+        iters: usize,
+    ) -> Result<Solution<M::Input, M::Output, N>, Error> {
+        let best = self.best.ok_or(Error::NoSuccessfulEvaluation)?;
+        Ok(Solution {
+            status,
+            x: best.vertex.x,
+            objective: best.vertex.objective,
+            snapshot: best.snapshot,
+            iters,
+            stats: self.tally.stats(),
+        })
+    }
+}
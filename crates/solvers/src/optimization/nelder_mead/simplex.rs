@@ -0,0 +1,267 @@
+use crate::linalg;
+
+use super::Error;
+
+/// A simplex vertex with its evaluated objective value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vertex<const N: usize> {
+    /// The vertex position.
+    pub x: [f64; N],
+
+    /// The objective value at x.
+    pub objective: f64,
+}
+
+impl<const N: usize> Vertex<N> {
+    /// Creates a new vertex.
+    #[must_use]
+    pub fn new(x: [f64; N], objective: f64) -> Self {
+        Self { x, objective }
+    }
+}
+
+/// A vertex paired with its score, where lower is better.
+///
+/// Scores are transformed objectives (negated when maximizing), so the simplex
+/// always minimizes. A vertex the observer marks as worse, or one with a `NaN`
+/// objective, has an infinite score.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Scored<const N: usize> {
+    pub(super) vertex: Vertex<N>,
+    pub(super) score: f64,
+}
+
+/// The current simplex: `N + 1` vertices ordered from best to worst.
+#[derive(Debug, Clone)]
+pub struct Simplex<const N: usize> {
+    vertices: Vec<Vertex<N>>,
+    scores: Vec<f64>,
+}
+
+impl<const N: usize> Simplex<N> {
+    /// Builds a simplex from scored vertices, sorting them best first.
+    ///
+    /// The sort is stable, so ties keep their original order.
+    pub(super) fn new(mut scored: Vec<Scored<N>>) -> Self {
+        debug_assert_eq!(scored.len(), N + 1);
+        scored.sort_by(|a, b| a.score.total_cmp(&b.score));
+        Self {
+            vertices: scored.iter().map(|s| s.vertex).collect(),
+            scores: scored.iter().map(|s| s.score).collect(),
+        }
+    }
+
+    /// Returns the vertices ordered from best to worst.
+    #[must_use]
+    pub fn vertices(&self) -> &[Vertex<N>] {
+        &self.vertices
+    }
+
+    /// Returns the best vertex.
+    #[must_use]
+    pub fn best(&self) -> &Vertex<N> {
+        &self.vertices[0]
+    }
+
+    /// Returns the worst vertex.
+    #[must_use]
+    pub fn worst(&self) -> &Vertex<N> {
+        &self.vertices[N]
+    }
+
+    /// Returns the largest distance (infinity norm) from the best vertex to
+    /// any other vertex.
+    #[must_use]
+    pub fn size(&self) -> f64 {
+        let best = self.best().x;
+        self.vertices[1..].iter().fold(0.0_f64, |acc, vertex| {
+            let edge: [f64; N] = std::array::from_fn(|j| vertex.x[j] - best[j]);
+            acc.max(linalg::max_abs(&edge))
+        })
+    }
+
+    /// Returns the objective spread `|f_worst - f_best|`.
+    ///
+    /// The spread is infinite while any vertex has been assumed worse.
+    #[must_use]
+    pub fn spread(&self) -> f64 {
+        self.scores[N] - self.scores[0]
+    }
+
+    /// Returns the score of the vertex at `index` (0 is best, `N` is worst).
+    pub(super) fn score(&self, index: usize) -> f64 {
+        self.scores[index]
+    }
+
+    /// Returns the centroid of every vertex except the worst.
+    pub(super) fn centroid(&self) -> [f64; N] {
+        #[allow(clippy::cast_precision_loss)]
+        let scale = 1.0 / N as f64;
+        std::array::from_fn(|j| self.vertices[..N].iter().map(|v| v.x[j]).sum::<f64>() * scale)
+    }
+
+    /// Replaces the worst vertex and restores the best-to-worst ordering.
+    ///
+    /// The new vertex is placed after any existing vertices with equal score.
+    pub(super) fn replace_worst(&mut self, scored: Scored<N>) {
+        self.vertices.pop();
+        self.scores.pop();
+        let index = self.scores.partition_point(|&s| s <= scored.score);
+        self.vertices.insert(index, scored.vertex);
+        self.scores.insert(index, scored.score);
+    }
+}
+
+/// How to build the starting simplex.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InitialSimplex<const N: usize> {
+    /// Offsets `x0` along each axis by `step * max(|x0_j|, 1)`.
+    Relative {
+        /// The starting point, which becomes the first vertex.
+        x0: [f64; N],
+        /// Relative step size.
+        step: f64,
+    },
+
+    /// Offsets `x0` along each axis `j` by `steps[j]`.
+    Steps {
+        /// The starting point, which becomes the first vertex.
+        x0: [f64; N],
+        /// Absolute step size for each axis.
+        steps: [f64; N],
+    },
+
+    /// Uses the given vertices directly.
+    ///
+    /// There must be exactly `N + 1` of them.
+    Vertices(Vec<[f64; N]>),
+}
+
+impl<const N: usize> InitialSimplex<N> {
+    /// Creates a simplex around `x0` with a relative step of 5%.
+    #[must_use]
+    pub fn around(x0: [f64; N]) -> Self {
+        Self::Relative { x0, step: 0.05 }
+    }
+
+    /// Returns the vertex positions described by this simplex.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSimplex`] unless `N > 0` and there are `N + 1`
+    /// finite vertices that are affinely independent (the simplex is not flat).
+    pub(super) fn positions(&self) -> Result<Vec<[f64; N]>, Error> {
+        let positions = match self {
+            Self::Relative { x0, step } => axis_vertices(x0, |j| *step * x0[j].abs().max(1.0)),
+            Self::Steps { x0, steps } => axis_vertices(x0, |j| steps[j]),
+            Self::Vertices(vertices) => vertices.clone(),
+        };
+
+        if N == 0 || positions.len() != N + 1 || positions.iter().flatten().any(|v| !v.is_finite())
+        {
+            return Err(Error::InvalidSimplex);
+        }
+
+        // The edges from the first vertex must be linearly independent.
+        let edges: [[f64; N]; N] =
+            std::array::from_fn(|i| std::array::from_fn(|j| positions[i + 1][j] - positions[0][j]));
+        if linalg::solve(edges, [0.0; N]).is_none() {
+            return Err(Error::InvalidSimplex);
+        }
+
+        Ok(positions)
+    }
+}
+
+/// Returns `x0` followed by `x0` offset along each axis `j` by `step(j)`.
+fn axis_vertices<const N: usize>(x0: &[f64; N], step: impl Fn(usize) -> f64) -> Vec<[f64; N]> {
+    std::iter::once(*x0)
+        .chain((0..N).map(|j| {
+            let mut x = *x0;
+            x[j] += step(j);
+            x
+        }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn scored(x: [f64; 2], score: f64) -> Scored<2> {
+        Scored {
+            vertex: Vertex::new(x, score),
+            score,
+        }
+    }
+
+    #[test]
+    fn new_sorts_best_first() {
+        let simplex = Simplex::new(vec![
+            scored([0.0, 0.0], 3.0),
+            scored([1.0, 0.0], 1.0),
+            scored([0.0, 1.0], 2.0),
+        ]);
+
+        assert_eq!(*simplex.best(), Vertex::new([1.0, 0.0], 1.0));
+        assert_eq!(*simplex.worst(), Vertex::new([0.0, 0.0], 3.0));
+        assert_relative_eq!(simplex.spread(), 2.0);
+        assert_relative_eq!(simplex.size(), 1.0);
+    }
+
+    #[test]
+    fn centroid_excludes_worst() {
+        let simplex = Simplex::new(vec![
+            scored([0.0, 0.0], 1.0),
+            scored([2.0, 0.0], 2.0),
+            scored([9.0, 9.0], 3.0),
+        ]);
+
+        let centroid = simplex.centroid();
+        assert_relative_eq!(centroid[0], 1.0);
+        assert_relative_eq!(centroid[1], 0.0);
+    }
+
+    #[test]
+    fn replace_worst_keeps_order() {
+        let mut simplex = Simplex::new(vec![
+            scored([0.0, 0.0], 1.0),
+            scored([1.0, 0.0], 2.0),
+            scored([0.0, 1.0], 3.0),
+        ]);
+
+        simplex.replace_worst(scored([1.0, 1.0], 1.0));
+
+        let xs: Vec<_> = simplex.vertices().iter().map(|v| v.x).collect();
+        assert_eq!(xs, vec![[0.0, 0.0], [1.0, 1.0], [1.0, 0.0]]);
+    }
+
+    #[test]
+    fn relative_steps_scale_with_x0() {
+        let positions = InitialSimplex::around([0.0, 10.0])
+            .positions()
+            .expect("valid simplex");
+
+        assert_eq!(positions.len(), 3);
+        assert_relative_eq!(positions[0][1], 10.0);
+        assert_relative_eq!(positions[1][0], 0.05);
+        assert_relative_eq!(positions[2][1], 10.5);
+    }
+
+    #[test]
+    fn rejects_flat_or_malformed_simplex() {
+        let flat = InitialSimplex::Vertices(vec![[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
+        assert!(matches!(flat.positions(), Err(Error::InvalidSimplex)));
+
+        let short = InitialSimplex::Vertices(vec![[0.0, 0.0], [1.0, 0.0]]);
+        assert!(matches!(short.positions(), Err(Error::InvalidSimplex)));
+
+        let zero_step = InitialSimplex::Steps {
+            x0: [1.0, 1.0],
+            steps: [0.1, 0.0],
+        };
+        assert!(matches!(zero_step.positions(), Err(Error::InvalidSimplex)));
+    }
+}
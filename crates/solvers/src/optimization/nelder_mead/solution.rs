@@ -0,0 +1,38 @@
+use twine_core::Snapshot;
+
+use crate::Stats;
+
+/// Indicates whether the solver converged or hit the iteration limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Converged according to the configured tolerances.
+    Converged,
+
+    /// Reached the iteration limit without converging.
+    MaxIters,
+
+    /// Stopped early due to an observer decision.
+    StoppedByObserver,
+}
+
+/// The result of a Nelder–Mead search.
+#[derive(Debug, Clone)]
+pub struct Solution<I, O, const N: usize> {
+    /// Final solver status.
+    pub status: Status,
+
+    /// Best vertex found.
+    pub x: [f64; N],
+
+    /// Objective value at the reported x.
+    pub objective: f64,
+
+    /// Snapshot at the reported x.
+    pub snapshot: Snapshot<I, O>,
+
+    /// Iteration count when the solver finished.
+    pub iters: usize,
+
+    /// Model evaluations and time spent, including the initial simplex.
+    pub stats: Stats,
+}
@@ -0,0 +1,44 @@
+//! Parallel evaluation of independent points, enabled by the `parallel`
+//! feature.
+//!
+//! The feature only adds entry points, such as
+//! [`equation::evaluate_batch`](crate::equation::evaluate_batch) and
+//! `golden_section::minimize_parallel`, that spread model calls across threads
+//! with rayon. These require the model and problem to be `Sync`, and their
+//! inputs, outputs, and errors to be `Send`. The serial entry points keep
+//! their bounds, so enabling the feature never breaks existing callers.
+//!
+//! Results always come back in input order and observers see events in the
+//! same order, so a parallel solve gives the same result as a serial one.
+
+use rayon::prelude::*;
+
+/// Applies `f` to each item across threads and collects the results in item
+/// order.
+pub(crate) fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    items.par_iter().map(f).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_preserves_order() {
+        let items: Vec<u64> = (0..1000).collect();
+
+        let squares = map(&items, |&i| i * i);
+
+        assert!(
+            squares
+                .iter()
+                .enumerate()
+                .all(|(i, &s)| s == (i * i) as u64)
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    cell::Cell,
+    ops::{Add, AddAssign},
+    time::{Duration, Instant},
+};
+
+use twine_core::Model;
+
+/// Cost of a solver run, reported in every solution.
+///
+/// Iteration counts differ in meaning between solvers and leave out work such
+/// as endpoint, initialization, and derivative evaluations. These counts
+/// include every model evaluation, so runs of different solvers can be
+/// compared directly.
+///
+/// Solvers built on other solvers, like continuation, include the evaluations
+/// of every inner solve.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Number of model evaluations, including failed ones.
+    pub evaluations: usize,
+    /// Number of evaluations where the model or problem returned an error.
+    pub failures: usize,
+    /// Number of times the observer returned an action, overriding the
+    /// solver's default handling of an event.
+    pub overridden: usize,
+    /// Wall-clock time of the run.
+    pub elapsed: Duration,
+}
+
+impl Add for Stats {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            evaluations: self.evaluations + other.evaluations,
+            failures: self.failures + other.failures,
+            overridden: self.overridden + other.overridden,
+            elapsed: self.elapsed + other.elapsed,
+        }
+    }
+}
+
+impl AddAssign for Stats {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+/// Running counts for a solve in progress.
+///
+/// Counters use interior mutability so the tally can be shared by reference
+/// with evaluation helpers that already borrow other solver state.
+#[derive(Debug)]
+pub(crate) struct Tally {
+    start: Instant,
+    evaluations: Cell<usize>,
+    failures: Cell<usize>,
+    overridden: Cell<usize>,
+}
+
+impl Tally {
+    /// Starts counting, and timing from now.
+    pub(crate) fn start() -> Self {
+        Self {
+            start: Instant::now(),
+            evaluations: Cell::new(0),
+            failures: Cell::new(0),
+            overridden: Cell::new(0),
+        }
+    }
+
+    /// Records one evaluation and whether it failed.
+    pub(crate) fn evaluated<T, E>(&self, result: &Result<T, E>) {
+        self.evaluations.set(self.evaluations.get() + 1);
+        if result.is_err() {
+            self.failures.set(self.failures.get() + 1);
+        }
+    }
+
+    /// Records an observer response, counting it if it is an action.
+    pub(crate) fn observed<A>(&self, action: Option<&A>) {
+        if action.is_some() {
+            self.overridden.set(self.overridden.get() + 1);
+        }
+    }
+
+    /// Adds the counts of an inner solve, whose time is already covered.
+    pub(crate) fn include(&self, stats: &Stats) {
+        self.evaluations
+            .set(self.evaluations.get() + stats.evaluations);
+        self.failures.set(self.failures.get() + stats.failures);
+        self.overridden
+            .set(self.overridden.get() + stats.overridden);
+    }
+
+    /// Returns the counts so far and the time since the tally started.
+    pub(crate) fn stats(&self) -> Stats {
+        Stats {
+            evaluations: self.evaluations.get(),
+            failures: self.failures.get(),
+            overridden: self.overridden.get(),
+            elapsed: self.start.elapsed(),
+        }
+    }
+}
+
+/// A model adapter that records every call in a [`Tally`].
+///
+/// Used where evaluations happen in many places at once, such as the stages
+/// of an integration step, so counting at each call site is impractical.
+#[derive(Debug)]
+pub(crate) struct Counted<'a, M> {
+    model: &'a M,
+    tally: Tally,
+}
+
+impl<'a, M> Counted<'a, M> {
+    /// Wraps `model` with a tally that starts timing now.
+    pub(crate) fn new(model: &'a M) -> Self {
+        Self {
+            model,
+            tally: Tally::start(),
+        }
+    }
+
+    /// Returns the tally recording the calls.
+    pub(crate) fn tally(&self) -> &Tally {
+        &self.tally
+    }
+}
+
+impl<M: Model> Model for Counted<'_, M> {
+    type Input = M::Input;
+    type Output = M::Output;
+    type Error = M::Error;
+
+    fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+        let result = self.model.call(input);
+        self.tally.evaluated(&result);
+        result
+    }
+}
@@ -0,0 +1,319 @@
+//! Parameter sweeps that run a solver at every point of a grid.
+//!
+//! A sweep takes a model, a factory that builds a problem from the sweep
+//! parameters, a [`Grid`] of parameter values, and a solver. It runs the
+//! solver at every grid point and collects the results in a [`Table`].
+//!
+//! # Solvers
+//!
+//! A sweep solver is any closure `(model, problem, previous) -> Result`.
+//! `previous` is the solution at the neighboring point solved just before
+//! (see [`run`]), which the solver can use as a warm start. Ready-made
+//! solvers with warm starts are provided for common cases:
+//!
+//! - [`bisection()`] and [`brent()`] — look for a bracket near the previous
+//!   root before falling back to a fixed bracket
+//! - [`newton()`] — start from the previous root
+//!
+//! # Failures
+//!
+//! A failed solve does not end the sweep. Its error is stored in the table
+//! like any other result, and the next point starts cold.
+//!
+//! # Parallel Sweeps
+//!
+//! With the `parallel` feature enabled, `run_parallel` solves the points
+//! across threads. Every point starts cold, and the table is in the same
+//! row-major order as with [`run`].
+//!
+//! # Example
+//!
+//! ```ignore
+//! let grid = Grid::new([sweep::linspace(1.0, 4.0, 31)]);
+//! let table = sweep::run(
+//!     &model,
+//!     |[target]| TargetProblem { target },
+//!     grid,
+//!     sweep::bisection([0.0, 10.0], bisection::Config::default()),
+//! );
+//! for ([target], solution) in table.successes() { /* ... */ }
+//! ```
+
+mod grid;
+mod solvers;
+mod table;
+
+pub use grid::{Grid, linspace};
+pub use solvers::{bisection, brent, newton};
+pub use table::{Row, Table};
+
+use twine_core::Model;
+
+/// Runs `solve` at every point of `grid` and returns a table of the results.
+///
+/// Points are solved in row-major order. Each point is warm started from its
+/// neighbor along the last axis whose index is nonzero, which is the point
+/// just before it on the same row, or the first point of the previous row
+/// when starting a new one. The warm start is `None` at the first point and
+/// whenever that neighbor failed.
+pub fn run<M, P, F, S, E, Solve, const D: usize>(
+    model: &M,
+    factory: F,
+    grid: Grid<D>,
+    mut solve: Solve,
+) -> Table<D, S, E>
+where
+    M: Model,
+    F: Fn([f64; D]) -> P,
+    Solve: FnMut(&M, &P, Option<&S>) -> Result<S, E>,
+{
+    let mut rows: Vec<Row<D, S, E>> = Vec::with_capacity(grid.len());
+
+    for index in grid.indices() {
+        let parameters = grid.parameters(index);
+        let problem = factory(parameters);
+
+        let previous = warm_source(&grid, index).and_then(|flat| rows[flat].result.as_ref().ok());
+        let result = solve(model, &problem, previous);
+
+        rows.push(Row {
+            index,
+            parameters,
+            result,
+        });
+    }
+
+    Table::new(grid, rows)
+}
+
+/// Runs `solve` at every point of `grid` across threads.
+///
+/// Unlike [`run`], points are solved independently, so `solve` always
+/// receives `None` as its warm start. Rows are in row-major order regardless
+/// of which thread solved them.
+#[cfg(feature = "parallel")]
+pub fn run_parallel<M, P, F, S, E, Solve, const D: usize>(
+    model: &M,
+    factory: F,
+    grid: Grid<D>,
+    solve: Solve,
+) -> Table<D, S, E>
+where
+    M: Model + Sync,
+    F: Fn([f64; D]) -> P + Sync,
+    Solve: Fn(&M, &P, Option<&S>) -> Result<S, E> + Sync,
+    S: Send,
+    E: Send,
+{
+    let indices: Vec<_> = grid.indices().collect();
+
+    let rows = crate::parallel::map(&indices, |&index| {
+        let parameters = grid.parameters(index);
+        let problem = factory(parameters);
+        Row {
+            index,
+            parameters,
+            result: solve(model, &problem, None),
+        }
+    });
+
+    Table::new(grid, rows)
+}
+
+/// Returns the row-major position of the point to warm start `index` from.
+fn warm_source<const D: usize>(grid: &Grid<D>, mut index: [usize; D]) -> Option<usize> {
+    let axis = (0..D).rev().find(|&axis| index[axis] > 0)?;
+    index[axis] -= 1;
+    grid.flatten(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{cell::Cell, convert::Infallible};
+
+    use approx::assert_relative_eq;
+    use twine_core::EquationProblem;
+
+    use crate::equation::{bisection as bisection_solver, newton as newton_solver};
+
+    /// Model that squares its input and counts its calls.
+    #[derive(Default)]
+    struct SquareModel {
+        calls: Cell<usize>,
+    }
+    impl Model for SquareModel {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(input * input)
+        }
+    }
+
+    /// Drives the model output to a target value.
+    struct TargetOutputProblem {
+        target: f64,
+    }
+    impl EquationProblem<1> for TargetOutputProblem {
+        type Input = f64;
+        type Output = f64;
+        type Error = Infallible;
+
+        fn input(&self, x: &[f64; 1]) -> Result<Self::Input, Self::Error> {
+            Ok(x[0])
+        }
+
+        fn residuals(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<[f64; 1], Self::Error> {
+            Ok([output - self.target])
+        }
+    }
+
+    #[test]
+    fn warm_started_bisection_needs_fewer_calls() {
+        let grid = Grid::new([linspace(1.0, 4.0, 31)]);
+        let factory = |[target]: [f64; 1]| TargetOutputProblem { target };
+        let config = bisection_solver::Config::default();
+
+        let cold_model = SquareModel::default();
+        let cold = run(&cold_model, factory, grid.clone(), |model, problem, _| {
+            bisection_solver::solve_unobserved(model, problem, [0.0, 10.0], &config)
+        });
+
+        let warm_model = SquareModel::default();
+        let warm = run(&warm_model, factory, grid, bisection([0.0, 10.0], config));
+
+        assert_eq!(warm.failures().count(), 0);
+        for ([target], solution) in warm.successes() {
+            assert_relative_eq!(solution.x, target.sqrt(), epsilon = 1e-9);
+        }
+        assert_eq!(cold.successes().count(), 31);
+
+        // Reported stats include the warm-start bracket searches.
+        let reported: usize = warm.successes().map(|(_, s)| s.stats.evaluations).sum();
+        assert_eq!(reported, warm_model.calls.get());
+        assert!(
+            warm_model.calls.get() < cold_model.calls.get(),
+            "warm: {}, cold: {}",
+            warm_model.calls.get(),
+            cold_model.calls.get(),
+        );
+    }
+
+    #[test]
+    fn failures_are_recorded_as_rows() {
+        // x² cannot reach a negative target, so those brackets are invalid.
+        let grid = Grid::new([vec![-2.0, 1.0, -0.5, 2.5]]);
+
+        let table = run(
+            &SquareModel::default(),
+            |[target]| TargetOutputProblem { target },
+            grid,
+            bisection([0.0, 3.0], bisection_solver::Config::default()),
+        );
+
+        assert_eq!(table.len(), 4);
+        let failed: Vec<_> = table.failures().map(|([target], _)| target).collect();
+        assert_eq!(failed, [-2.0, -0.5]);
+        assert!(matches!(
+            table.rows()[0].result,
+            Err(bisection_solver::Error::InvalidBracket(_))
+        ));
+
+        let solution = table
+            .get([3])
+            .expect("row")
+            .result
+            .as_ref()
+            .expect("solved");
+        assert_relative_eq!(solution.x, 2.5_f64.sqrt(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn sweeps_cartesian_grid() {
+        let grid = Grid::new([linspace(1.0, 2.0, 3), vec![3.0, 4.0]]);
+        let mut starts = Vec::new();
+        let solve = newton([1.0], newton_solver::Config::default());
+
+        let table = run(
+            &SquareModel::default(),
+            |[a, b]| TargetOutputProblem { target: a + b },
+            grid,
+            |model, problem, previous: Option<&newton_solver::Solution<f64, f64, 1>>| {
+                starts.push(previous.map(|s| s.snapshot.output));
+                solve(model, problem, previous)
+            },
+        );
+
+        assert_eq!(table.len(), 6);
+        for row in table.rows() {
+            let [a, b] = row.parameters;
+            let solution = row.result.as_ref().expect("solved");
+            assert_relative_eq!(solution.x[0], (a + b).sqrt(), epsilon = 1e-9);
+        }
+
+        // Targets of the warm-start source for each point, in row-major order.
+        let expected = [None, Some(4.0), Some(4.0), Some(4.5), Some(4.5), Some(5.0)];
+        assert_eq!(starts.len(), expected.len());
+        for (start, expected) in starts.iter().zip(expected) {
+            match (start, expected) {
+                (Some(start), Some(expected)) => {
+                    assert_relative_eq!(*start, expected, epsilon = 1e-9);
+                }
+                (start, expected) => assert_eq!(start.is_some(), expected.is_some()),
+            }
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_sweep_matches_cold_serial_sweep() {
+        /// Squares its input; unlike `SquareModel`, it can be shared across threads.
+        struct Square;
+        impl Model for Square {
+            type Input = f64;
+            type Output = f64;
+            type Error = Infallible;
+
+            fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+                Ok(input * input)
+            }
+        }
+
+        let grid = Grid::new([linspace(1.0, 2.0, 4), vec![-9.0, 3.0, 4.0]]);
+        let factory = |[a, b]: [f64; 2]| TargetOutputProblem { target: a + b };
+        let config = bisection_solver::Config::default();
+        let solve = |model: &Square, problem: &TargetOutputProblem, _: Option<&_>| {
+            bisection_solver::solve_unobserved(model, problem, [0.0, 10.0], &config)
+        };
+
+        let serial = run(&Square, factory, grid.clone(), solve);
+        let parallel = run_parallel(&Square, factory, grid, solve);
+
+        assert_eq!(parallel.len(), serial.len());
+        assert_eq!(parallel.failures().count(), 4);
+        for (serial, parallel) in serial.rows().iter().zip(parallel.rows()) {
+            assert_eq!(serial.index, parallel.index);
+            match (&serial.result, &parallel.result) {
+                (Ok(serial), Ok(parallel)) => assert_relative_eq!(serial.x, parallel.x),
+                (serial, parallel) => assert_eq!(serial.is_ok(), parallel.is_ok()),
+            }
+        }
+    }
+
+    #[test]
+    fn warm_source_follows_last_nonzero_axis() {
+        let grid = Grid::new([vec![0.0; 2], vec![0.0; 3]]);
+
+        assert_eq!(warm_source(&grid, [0, 0]), None);
+        assert_eq!(warm_source(&grid, [0, 2]), grid.flatten([0, 1]));
+        assert_eq!(warm_source(&grid, [1, 0]), grid.flatten([0, 0]));
+    }
+}
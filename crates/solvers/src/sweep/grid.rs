@@ -0,0 +1,130 @@
+/// A Cartesian grid of sweep parameters.
+///
+/// Each of the `D` axes lists the values of one parameter, and the grid holds
+/// every combination. Points are visited in row-major order, so the last
+/// axis varies fastest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<const D: usize> {
+    axes: [Vec<f64>; D],
+}
+
+impl<const D: usize> Grid<D> {
+    /// Creates a grid from the values along each axis.
+    #[must_use]
+    pub fn new(axes: [Vec<f64>; D]) -> Self {
+        Self { axes }
+    }
+
+    /// Returns the values along each axis.
+    #[must_use]
+    pub fn axes(&self) -> &[Vec<f64>; D] {
+        &self.axes
+    }
+
+    /// Returns the number of values along each axis.
+    #[must_use]
+    pub fn shape(&self) -> [usize; D] {
+        std::array::from_fn(|axis| self.axes[axis].len())
+    }
+
+    /// Returns the number of points in the grid.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.shape().iter().product()
+    }
+
+    /// Returns true if any axis is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the parameters at a grid index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds for any axis.
+    #[must_use]
+    pub fn parameters(&self, index: [usize; D]) -> [f64; D] {
+        std::array::from_fn(|axis| self.axes[axis][index[axis]])
+    }
+
+    /// Iterates over grid indices in row-major order.
+    pub fn indices(&self) -> impl Iterator<Item = [usize; D]> + '_ {
+        (0..self.len()).map(|flat| self.unflatten(flat))
+    }
+
+    /// Returns the row-major position of a grid index, or `None` if it is out
+    /// of bounds.
+    pub(super) fn flatten(&self, index: [usize; D]) -> Option<usize> {
+        let shape = self.shape();
+        let mut flat = 0;
+        for axis in 0..D {
+            if index[axis] >= shape[axis] {
+                return None;
+            }
+            flat = flat * shape[axis] + index[axis];
+        }
+        Some(flat)
+    }
+
+    /// Returns the grid index at a row-major position.
+    fn unflatten(&self, mut flat: usize) -> [usize; D] {
+        let shape = self.shape();
+        let mut index = [0; D];
+        for axis in (0..D).rev() {
+            index[axis] = flat % shape[axis];
+            flat /= shape[axis];
+        }
+        index
+    }
+}
+
+/// Returns `count` evenly spaced values from `start` to `end`, inclusive.
+///
+/// A single value is `start`, and zero values gives an empty axis.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn linspace(start: f64, end: f64, count: usize) -> Vec<f64> {
+    match count {
+        0 => Vec::new(),
+        1 => vec![start],
+        _ => {
+            let intervals = (count - 1) as f64;
+            (0..count)
+                .map(|i| start + (i as f64 / intervals) * (end - start))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn linspace_includes_endpoints() {
+        let values = linspace(1.0, 2.0, 5);
+
+        assert_eq!(values.len(), 5);
+        assert_relative_eq!(values[0], 1.0);
+        assert_relative_eq!(values[2], 1.5);
+        assert_relative_eq!(values[4], 2.0);
+        assert!(linspace(1.0, 2.0, 0).is_empty());
+    }
+
+    #[test]
+    fn indices_are_row_major() {
+        let grid = Grid::new([vec![0.0, 1.0], vec![10.0, 20.0, 30.0]]);
+
+        let indices: Vec<_> = grid.indices().collect();
+
+        assert_eq!(grid.shape(), [2, 3]);
+        assert_eq!(indices, [[0, 0], [0, 1], [0, 2], [1, 0], [1, 1], [1, 2]]);
+        assert_eq!(grid.flatten([1, 0]), Some(3));
+        assert_eq!(grid.flatten([0, 3]), None);
+        assert_relative_eq!(grid.parameters([1, 2])[1], 30.0);
+    }
+}
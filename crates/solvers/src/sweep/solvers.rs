@@ -0,0 +1,115 @@
+use twine_core::{EquationProblem, Model};
+
+use crate::{
+    Stats,
+    equation::{bisection, bracket, brent, newton},
+    stats::Counted,
+};
+
+/// Initial bracket expansion step when warm starting, relative to the
+/// configured bracket width.
+const WARM_STEP: f64 = 1e-3;
+
+/// Solution type shared by [`bisection`](crate::equation::bisection) and
+/// [`brent`](crate::equation::brent).
+type BracketedSolution<M> = bisection::Solution<<M as Model>::Input, <M as Model>::Output>;
+
+/// Solution type of [`newton`](crate::equation::newton).
+type NewtonSolution<M, const N: usize> =
+    newton::Solution<<M as Model>::Input, <M as Model>::Output, N>;
+
+/// Returns a sweep solver that runs [`bisection`](mod@bisection) within `bracket`.
+///
+/// Warm starts search outward from the previous root with
+/// [`bracket::expand`], staying within `bracket`, and fall back to `bracket`
+/// itself when no sign change is found. The search's evaluations are included
+/// in the solution's [`stats`](bisection::Solution::stats).
+pub fn bisection<M, P>(
+    bracket: [f64; 2],
+    config: bisection::Config,
+) -> impl Fn(&M, &P, Option<&BracketedSolution<M>>) -> Result<BracketedSolution<M>, bisection::Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    move |model, problem, previous| {
+        let (bracket, warm) = warm_bracket(model, problem, bracket, previous.map(|s| s.x));
+        let mut solution = bisection::solve_unobserved(model, problem, bracket, &config)?;
+        solution.stats += warm;
+        Ok(solution)
+    }
+}
+
+/// Returns a sweep solver that runs [`brent`](mod@brent) within `bracket`.
+///
+/// Warm starts work as in [`bisection()`].
+pub fn brent<M, P>(
+    bracket: [f64; 2],
+    config: brent::Config,
+) -> impl Fn(&M, &P, Option<&BracketedSolution<M>>) -> Result<BracketedSolution<M>, brent::Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    move |model, problem, previous| {
+        let (bracket, warm) = warm_bracket(model, problem, bracket, previous.map(|s| s.x));
+        let mut solution = brent::solve_unobserved(model, problem, bracket, &config)?;
+        solution.stats += warm;
+        Ok(solution)
+    }
+}
+
+/// Returns a sweep solver that runs [`newton`](mod@newton) from `x0`.
+///
+/// Warm starts use the previous solution's `x` as the initial guess.
+pub fn newton<M, P, const N: usize>(
+    x0: [f64; N],
+    config: newton::Config,
+) -> impl Fn(&M, &P, Option<&NewtonSolution<M, N>>) -> Result<NewtonSolution<M, N>, newton::Error>
+where
+    M: Model,
+    P: EquationProblem<N, Input = M::Input, Output = M::Output>,
+{
+    move |model, problem, previous| {
+        let x0 = previous.map_or(x0, |s| s.x);
+        newton::solve_unobserved(model, problem, x0, &config)
+    }
+}
+
+/// Searches for a bracket around the previous root, within `bracket`.
+///
+/// Returns `bracket` unchanged when there is no previous root or the search
+/// fails, leaving the solver to report any problem with it. The search's
+/// stats are returned alongside.
+fn warm_bracket<M, P>(
+    model: &M,
+    problem: &P,
+    bracket: [f64; 2],
+    previous: Option<f64>,
+) -> ([f64; 2], Stats)
+where
+    M: Model,
+    P: EquationProblem<1, Input = M::Input, Output = M::Output>,
+{
+    let Some(root) = previous else {
+        return (bracket, Stats::default());
+    };
+
+    let lower = bracket[0].min(bracket[1]);
+    let upper = bracket[0].max(bracket[1]);
+    let config = bracket::Config {
+        initial_step: WARM_STEP * (upper - lower),
+        lower: Some(lower),
+        upper: Some(upper),
+        ..bracket::Config::default()
+    };
+
+    let model = Counted::new(model);
+    let bracket = bracket::expand_unobserved(&model, problem, root, &config)
+        .map_or(bracket, |found| found.as_array());
+    (bracket, model.tally().stats())
+}
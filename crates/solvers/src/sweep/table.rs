@@ -0,0 +1,77 @@
+use super::Grid;
+
+/// One grid point of a sweep and the solver's result there.
+#[derive(Debug, Clone)]
+pub struct Row<const D: usize, S, E> {
+    /// Position of the point along each grid axis.
+    pub index: [usize; D],
+    /// Sweep parameters at the point.
+    pub parameters: [f64; D],
+    /// The solution, or the error if the solver failed.
+    pub result: Result<S, E>,
+}
+
+/// The results of a sweep, one [`Row`] per grid point in row-major order.
+#[derive(Debug, Clone)]
+pub struct Table<const D: usize, S, E> {
+    grid: Grid<D>,
+    rows: Vec<Row<D, S, E>>,
+}
+
+impl<const D: usize, S, E> Table<D, S, E> {
+    /// Creates a table from rows in the grid's row-major order.
+    pub(super) fn new(grid: Grid<D>, rows: Vec<Row<D, S, E>>) -> Self {
+        debug_assert_eq!(grid.len(), rows.len());
+        Self { grid, rows }
+    }
+
+    /// Returns the grid that was swept.
+    #[must_use]
+    pub fn grid(&self) -> &Grid<D> {
+        &self.grid
+    }
+
+    /// Returns every row in row-major order.
+    #[must_use]
+    pub fn rows(&self) -> &[Row<D, S, E>] {
+        &self.rows
+    }
+
+    /// Returns the row at a grid index, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: [usize; D]) -> Option<&Row<D, S, E>> {
+        self.grid.flatten(index).map(|flat| &self.rows[flat])
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns true if the table has no rows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Iterates over the parameters and solution of every successful row.
+    pub fn successes(&self) -> impl Iterator<Item = ([f64; D], &S)> {
+        self.rows
+            .iter()
+            .filter_map(|row| row.result.as_ref().ok().map(|s| (row.parameters, s)))
+    }
+
+    /// Iterates over the parameters and error of every failed row.
+    pub fn failures(&self) -> impl Iterator<Item = ([f64; D], &E)> {
+        self.rows
+            .iter()
+            .filter_map(|row| row.result.as_ref().err().map(|e| (row.parameters, e)))
+    }
+
+    /// Consumes the table and returns its rows.
+    #[must_use]
+    pub fn into_rows(self) -> Vec<Row<D, S, E>> {
+        self.rows
+    }
+}
@@ -1,3 +1,29 @@
 //! Solvers for transient (time-dependent) problems.
+//!
+//! # Solvers
+//!
+//! - [`euler`] — first-order forward Euler with a fixed step
+//! - [`rk4`] — classic fourth-order Runge–Kutta with a fixed step
+//! - [`dopri5`] — adaptive Dormand–Prince 5(4) with error control
+//! - [`backward_euler`] — first-order implicit Euler for stiff problems
+//! - [`bdf`] — backward differentiation formulas up to fifth order for stiff
+//!   problems
+//! - [`steady_state`] — integrates with a fixed step until the state settles
+//!
+//! Forward Euler and RK4 can also locate discrete events during integration;
+//! see [`crossing`].
+//!
+//! Solution histories can be interpolated between steps at any value of the
+//! independent variable; see [`dense`].
 
+mod fixed_step;
+mod implicit;
+
+pub mod backward_euler;
+pub mod bdf;
+pub mod crossing;
+pub mod dense;
+pub mod dopri5;
 pub mod euler;
+pub mod rk4;
+pub mod steady_state;
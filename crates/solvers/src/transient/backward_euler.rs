@@ -0,0 +1,490 @@
+//! Backward (implicit) Euler solver for stiff ODE problems.
+//!
+//! Backward Euler evaluates the derivative at the end of the step rather than
+//! the start:
+//!
+//! ```text
+//! state_{n+1} = state_n + derivative(t_{n+1}, state_{n+1}) * dt
+//! ```
+//!
+//! Because the unknown state appears on both sides, each step solves this
+//! equation with [`newton`](crate::equation::newton) over the flattened state,
+//! approximating the Jacobian by finite differences. States therefore
+//! implement [`Flatten`] in addition to [`StepIntegrable`].
+//!
+//! The method is first-order accurate but stable for any step size on
+//! decaying problems, which makes it suitable for stiff systems where forward
+//! [`euler`] needs a prohibitively small `dt`. Each step costs
+//! a few Newton iterations of `N + 1` model calls each.
+//!
+//! The solver shares its [`Event`], [`Action`], [`Solution`], and [`Status`]
+//! types with Euler, so observers work with either integrator. Its [`Error`]
+//! wraps Euler's with the failures of the Newton solve, and its [`Config`] is
+//! the Newton configuration used for each step.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use twine_solvers::transient::backward_euler;
+//!
+//! let config = backward_euler::Config::default();
+//! let solution =
+//!     backward_euler::solve_unobserved(&model, &problem, initial_input, dt, steps, &config)?;
+//! ```
+//!
+//! [`StepIntegrable`]: twine_core::StepIntegrable
+
+mod error;
+
+pub use super::euler::{Action, Event, Solution, Status};
+pub use crate::equation::newton::Config;
+pub use error::Error;
+
+use std::ops::{Mul, Sub};
+
+use twine_core::{Flatten, IndependentVariable, Model, Observer, OdeProblem, Snapshot};
+
+use crate::stats::Counted;
+
+use super::{
+    euler,
+    fixed_step::{self, UntilResult},
+    implicit,
+};
+
+/// Integrates an ODE problem using backward Euler.
+///
+/// # Algorithm
+///
+/// 1. Call the model with the initial input to get the initial snapshot.
+/// 2. For each step:
+///    - Solve `state = state_n + derivative(state) * dt` for the end-of-step
+///      state with Newton's method, starting from `state_n`.
+///    - Build the next input from the solved state and finalize the step.
+///    - Call the model to get the next output.
+///    - Emit an `Event` to the observer.
+///    - If the observer returns `StopEarly`, terminate.
+/// 3. Return the solution with the full history.
+///
+/// # Observer
+///
+/// The observer receives an [`Event`] after each integration step and may
+/// return [`Action::StopEarly`] to terminate the simulation early.
+///
+/// # Errors
+///
+/// Returns [`Error::Implicit`] if the config is invalid or a Newton solve
+/// fails, [`Error::NotConverged`] if a Newton solve hits its iteration limit,
+/// or an error if the model or problem returns an error at any point.
+pub fn solve<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    steps: usize,
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone,
+    P::State: Flatten<N>,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+{
+    config
+        .validate()
+        .map_err(|err| Error::Implicit(err.into()))?;
+
+    let model = &Counted::new(model);
+    fixed_step::integrate(
+        model,
+        problem,
+        initial,
+        dt,
+        steps,
+        observer,
+        |current, dt| advance(model, problem, current, dt, config),
+    )
+}
+
+/// Integrates an ODE problem using backward Euler without observation.
+///
+/// This is a convenience wrapper around [`solve`] that discards events.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`solve`].
+pub fn solve_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    steps: usize,
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone,
+    P::State: Flatten<N>,
+{
+    solve(model, problem, initial, dt, steps, config, ())
+}
+
+/// Integrates an ODE problem using backward Euler until the independent
+/// variable reaches `end`.
+///
+/// Behaves like [`euler::solve_until`], with each
+/// step taken by backward Euler.
+///
+/// # Errors
+///
+/// Returns [`Error::Step`] with [`euler::Error::InvalidStep`] if `dt` is not
+/// positive, or an error under the same conditions as [`solve`].
+pub fn solve_until<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+    config: &Config,
+    observer: Obs,
+) -> UntilResult<M, P, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+    P::State: Flatten<N>,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+{
+    config
+        .validate()
+        .map_err(|err| Error::Implicit(err.into()))?;
+
+    let model = &Counted::new(model);
+    fixed_step::integrate_until(model, problem, initial, dt, end, observer, |current, dt| {
+        advance(model, problem, current, dt, config)
+    })
+}
+
+/// Integrates an ODE problem using backward Euler until `end` without observation.
+///
+/// This is a convenience wrapper around [`solve_until`] that discards events.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`solve_until`].
+pub fn solve_until_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+    config: &Config,
+) -> UntilResult<M, P, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+    P::State: Flatten<N>,
+{
+    solve_until(model, problem, initial, dt, end, config, ())
+}
+
+/// Computes the next (unfinalized) input with a backward Euler step.
+fn advance<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    current: &Snapshot<M::Input, M::Output>,
+    dt: &P::Delta,
+    config: &Config,
+) -> Result<M::Input, Error>
+where
+    M: Model,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone,
+    P::State: Flatten<N>,
+{
+    let state = problem
+        .state(&current.input)
+        .map_err(euler::Error::problem)?;
+    implicit::solve(model, problem, current, dt, &state, dt.clone(), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use twine_core::{DerivativeOf, StepIntegrable};
+
+    // --- Test fixtures ---
+
+    /// Scalar state.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Value(f64);
+
+    /// Derivative of the scalar state.
+    #[derive(Debug, Clone, Copy)]
+    struct Rate(f64);
+
+    impl StepIntegrable<f64> for Value {
+        type Derivative = Rate;
+
+        fn step(&self, derivative: Rate, dt: f64) -> Self {
+            Value(self.0 + derivative.0 * dt)
+        }
+    }
+
+    impl Flatten<1> for Value {
+        fn flatten(&self) -> [f64; 1] {
+            [self.0]
+        }
+
+        fn unflatten(&self, values: &[f64; 1]) -> Self {
+            Value(values[0])
+        }
+    }
+
+    /// Model input: current value and time.
+    #[derive(Debug, Clone)]
+    struct Input {
+        value: Value,
+        time: f64,
+    }
+
+    /// Model output: rate of change at the current state.
+    #[derive(Debug, Clone)]
+    struct Output {
+        rate: Rate,
+    }
+
+    /// Exponential decay: `dy/dt = -k y`.
+    struct DecayModel {
+        k: f64,
+    }
+
+    impl Model for DecayModel {
+        type Input = Input;
+        type Output = Output;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(Output {
+                rate: Rate(-self.k * input.value.0),
+            })
+        }
+    }
+
+    /// Decay model that fails once the value drops below a floor.
+    struct FloorModel {
+        floor: f64,
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("value below floor")]
+    struct BelowFloor;
+
+    impl Model for FloorModel {
+        type Input = Input;
+        type Output = Output;
+        type Error = BelowFloor;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            if input.value.0 < self.floor {
+                return Err(BelowFloor);
+            }
+            Ok(Output {
+                rate: Rate(-input.value.0),
+            })
+        }
+    }
+
+    struct ValueProblem;
+
+    impl OdeProblem for ValueProblem {
+        type Input = Input;
+        type Output = Output;
+        type Delta = f64;
+        type State = Value;
+        type Error = Infallible;
+
+        fn state(&self, input: &Self::Input) -> Result<Self::State, Self::Error> {
+            Ok(input.value)
+        }
+
+        fn derivative(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<DerivativeOf<Self::State, Self::Delta>, Self::Error> {
+            Ok(output.rate)
+        }
+
+        fn build_input(
+            &self,
+            base: &Self::Input,
+            state: &Self::State,
+            delta: &Self::Delta,
+        ) -> Result<Self::Input, Self::Error> {
+            Ok(Input {
+                value: *state,
+                time: base.time + delta,
+            })
+        }
+    }
+
+    impl IndependentVariable for ValueProblem {
+        fn independent_variable(&self, input: &Self::Input) -> Result<Self::Delta, Self::Error> {
+            Ok(input.time)
+        }
+    }
+
+    fn initial() -> Input {
+        Input {
+            value: Value(1.0),
+            time: 0.0,
+        }
+    }
+
+    /// Absolute error at `t = 1` for `dy/dt = -y` with `y(0) = 1`.
+    fn decay_error(dt: f64, steps: usize) -> f64 {
+        let solution = solve_unobserved(
+            &DecayModel { k: 1.0 },
+            &ValueProblem,
+            initial(),
+            dt,
+            steps,
+            &Config::default(),
+        )
+        .expect("should solve");
+
+        let last = solution.history.last().unwrap();
+        (last.input.value.0 - (-1.0_f64).exp()).abs()
+    }
+
+    // --- Tests ---
+
+    #[test]
+    fn stiff_decay_is_stable() {
+        // Forward Euler would amplify by |1 - k dt| = 99 per step.
+        let solution = solve_unobserved(
+            &DecayModel { k: 1000.0 },
+            &ValueProblem,
+            initial(),
+            0.1,
+            5,
+            &Config::default(),
+        )
+        .expect("should solve");
+
+        assert_eq!(solution.status, Status::Complete);
+        assert_eq!(solution.history.len(), 6);
+
+        for (n, snapshot) in solution.history.iter().enumerate() {
+            let expected = (1.0_f64 / 101.0).powi(i32::try_from(n).unwrap());
+            assert_relative_eq!(snapshot.input.value.0, expected, epsilon = 1e-12);
+            assert_relative_eq!(snapshot.output.rate.0, -1000.0 * expected, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn converges_at_first_order() {
+        let coarse = decay_error(0.1, 10);
+        let fine = decay_error(0.05, 20);
+
+        let ratio = coarse / fine;
+        assert!(ratio > 1.8 && ratio < 2.2, "ratio = {ratio}");
+    }
+
+    #[test]
+    fn solve_until_shortens_final_step() {
+        let solution = solve_until_unobserved(
+            &DecayModel { k: 1.0 },
+            &ValueProblem,
+            initial(),
+            0.4,
+            1.0,
+            &Config::default(),
+        )
+        .expect("should solve");
+
+        assert_eq!(solution.steps, 3);
+        assert_relative_eq!(solution.end, 1.0);
+
+        let expected = 1.0 / (1.4 * 1.4 * 1.2);
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(last.input.value.0, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn newton_iteration_limit_is_an_error() {
+        let config = Config {
+            max_iters: 0,
+            ..Config::default()
+        };
+
+        let result = solve_unobserved(
+            &DecayModel { k: 1.0 },
+            &ValueProblem,
+            initial(),
+            0.1,
+            1,
+            &config,
+        );
+
+        assert!(matches!(result, Err(Error::NotConverged { iters: 0 })));
+    }
+
+    #[test]
+    fn invalid_config_is_an_error() {
+        let config = Config {
+            fd_step: 0.0,
+            ..Config::default()
+        };
+
+        let result = solve_unobserved(
+            &DecayModel { k: 1.0 },
+            &ValueProblem,
+            initial(),
+            0.1,
+            1,
+            &config,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::Implicit(
+                crate::equation::newton::Error::InvalidConfig(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn model_error_during_newton_keeps_its_type() {
+        let result = solve_unobserved(
+            &FloorModel { floor: 0.5 },
+            &ValueProblem,
+            initial(),
+            0.1,
+            20,
+            &Config::default(),
+        );
+
+        let Err(Error::Step(euler::Error::Model(err))) = result else {
+            panic!("expected a model error, got {result:?}");
+        };
+        assert!(err.downcast_ref::<BelowFloor>().is_some());
+    }
+}
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+use crate::{equation::newton, transient::euler};
+
+/// Errors that can occur during implicit integration.
+///
+/// Shared by [`backward_euler`](super) and [`bdf`](crate::transient::bdf).
+/// Failures common to every fixed-step integrator, such as model and problem
+/// errors, are wrapped in [`Error::Step`].
+#[derive(Debug, Error)]
+pub enum Error {
+    /// A failure shared with the explicit fixed-step integrators.
+    #[error(transparent)]
+    Step(#[from] euler::Error),
+
+    /// The Newton solve for the next state failed, or its config is invalid.
+    #[error("implicit solve failed: {0}")]
+    Implicit(#[source] newton::Error),
+
+    /// The Newton solve for the next state hit its iteration limit.
+    #[error("implicit solve did not converge in {iters} iterations")]
+    NotConverged { iters: usize },
+}
@@ -0,0 +1,477 @@
+//! Backward differentiation formula (BDF) solver for stiff ODE problems.
+//!
+//! A BDF of order `k` fits a polynomial through the new state and the `k`
+//! previous ones and requires its slope at the new state to match the
+//! derivative there:
+//!
+//! ```text
+//! state_{n+1} = Σ α_j · state_{n-j} + β · derivative(t_{n+1}, state_{n+1}) · dt
+//! ```
+//!
+//! As with [`backward_euler`](super::backward_euler), each step solves for
+//! the new state with [`newton`](crate::equation::newton) over the flattened
+//! state, so states implement [`Flatten`] in addition to [`StepIntegrable`].
+//! Order one is backward Euler.
+//!
+//! # Order selection
+//!
+//! The formulas assume equal steps. The solver starts at order one and raises
+//! the order by one each step, up to [`Config::order`], as history becomes
+//! available. When the step size changes, as on the final step of
+//! [`solve_until`], history restarts at order one. Because of the low-order
+//! start, global accuracy above second order is limited by the first few
+//! steps; the higher orders mainly reduce error over long runs.
+//!
+//! A discrete change applied in [`OdeProblem::finalize_step`] is not seen by
+//! the formulas as a restart, so problems with large jumps may prefer
+//! [`Order::One`].
+//!
+//! The solver shares its [`Event`], [`Action`], [`Solution`], and [`Status`]
+//! types with Euler, so observers work with either integrator, and its
+//! [`Error`] type with backward Euler.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use twine_solvers::transient::bdf;
+//!
+//! let config = bdf::Config {
+//!     order: bdf::Order::Three,
+//!     ..bdf::Config::default()
+//! };
+//! let solution = bdf::solve_unobserved(&model, &problem, initial_input, dt, steps, &config)?;
+//! ```
+//!
+//! [`StepIntegrable`]: twine_core::StepIntegrable
+
+mod config;
+mod history;
+
+pub use super::backward_euler::Error;
+pub use super::euler::{Action, Event, Solution, Status};
+pub use config::{Config, Order};
+
+use std::ops::{Mul, Sub};
+
+use twine_core::{Flatten, IndependentVariable, Model, Observer, OdeProblem, Snapshot};
+
+use crate::stats::Counted;
+
+use super::{
+    euler,
+    fixed_step::{self, UntilResult},
+    implicit,
+};
+
+use history::History;
+
+/// Integrates an ODE problem using backward differentiation formulas.
+///
+/// # Algorithm
+///
+/// 1. Call the model with the initial input to get the initial snapshot.
+/// 2. For each step:
+///    - Record the current state in the history and combine the history with
+///      the BDF weights for the current order.
+///    - Solve the implicit BDF equation for the end-of-step state with
+///      Newton's method.
+///    - Build the next input from the solved state and finalize the step.
+///    - Call the model to get the next output.
+///    - Emit an `Event` to the observer.
+///    - If the observer returns `StopEarly`, terminate.
+/// 3. Return the solution with the full history.
+///
+/// # Observer
+///
+/// The observer receives an [`Event`] after each integration step and may
+/// return [`Action::StopEarly`] to terminate the simulation early.
+///
+/// # Errors
+///
+/// Returns [`Error::Implicit`] if the Newton config is invalid or a Newton
+/// solve fails, [`Error::NotConverged`] if a Newton solve hits its iteration
+/// limit, or an error if the model or problem returns an error at any point.
+pub fn solve<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    steps: usize,
+    config: &Config,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialEq + Mul<f64, Output = P::Delta>,
+    P::State: Flatten<N>,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+{
+    config
+        .newton
+        .validate()
+        .map_err(|err| Error::Implicit(err.into()))?;
+
+    let mut history = History::new();
+    let model = &Counted::new(model);
+    fixed_step::integrate(
+        model,
+        problem,
+        initial,
+        dt,
+        steps,
+        observer,
+        |current, dt| advance(model, problem, current, dt, config, &mut history),
+    )
+}
+
+/// Integrates an ODE problem using BDF without observation.
+///
+/// This is a convenience wrapper around [`solve`] that discards events.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`solve`].
+pub fn solve_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    steps: usize,
+    config: &Config,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialEq + Mul<f64, Output = P::Delta>,
+    P::State: Flatten<N>,
+{
+    solve(model, problem, initial, dt, steps, config, ())
+}
+
+/// Integrates an ODE problem using BDF until the independent variable
+/// reaches `end`.
+///
+/// Behaves like [`euler::solve_until`], with each
+/// step taken by BDF. The shortened final step restarts at order one.
+///
+/// # Errors
+///
+/// Returns [`Error::Step`] with [`euler::Error::InvalidStep`] if `dt` is not
+/// positive, or an error under the same conditions as [`solve`].
+pub fn solve_until<M, P, Obs, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+    config: &Config,
+    observer: Obs,
+) -> UntilResult<M, P, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+    P::State: Flatten<N>,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+{
+    config
+        .newton
+        .validate()
+        .map_err(|err| Error::Implicit(err.into()))?;
+
+    let mut history = History::new();
+    let model = &Counted::new(model);
+    fixed_step::integrate_until(model, problem, initial, dt, end, observer, |current, dt| {
+        advance(model, problem, current, dt, config, &mut history)
+    })
+}
+
+/// Integrates an ODE problem using BDF until `end` without observation.
+///
+/// This is a convenience wrapper around [`solve_until`] that discards events.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`solve_until`].
+pub fn solve_until_unobserved<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+    config: &Config,
+) -> UntilResult<M, P, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+    P::State: Flatten<N>,
+{
+    solve_until(model, problem, initial, dt, end, config, ())
+}
+
+/// Computes the next (unfinalized) input with a BDF step.
+fn advance<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    current: &Snapshot<M::Input, M::Output>,
+    dt: &P::Delta,
+    config: &Config,
+    history: &mut History<P::Delta, N>,
+) -> Result<M::Input, Error>
+where
+    M: Model,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialEq + Mul<f64, Output = P::Delta>,
+    P::State: Flatten<N>,
+{
+    let state = problem
+        .state(&current.input)
+        .map_err(euler::Error::problem)?;
+    history.push(state.flatten(), dt, config.order as usize);
+
+    let (base, beta) = history.base();
+    let base = state.unflatten(&base);
+    implicit::solve(
+        model,
+        problem,
+        current,
+        dt,
+        &base,
+        dt.clone() * beta,
+        &config.newton,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use twine_core::{DerivativeOf, StepIntegrable};
+
+    // --- Test fixtures ---
+
+    /// Two-component state.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Pair([f64; 2]);
+
+    impl StepIntegrable<f64> for Pair {
+        type Derivative = Pair;
+
+        fn step(&self, derivative: Pair, dt: f64) -> Self {
+            Pair(std::array::from_fn(|i| self.0[i] + derivative.0[i] * dt))
+        }
+    }
+
+    impl Flatten<2> for Pair {
+        fn flatten(&self) -> [f64; 2] {
+            self.0
+        }
+
+        fn unflatten(&self, values: &[f64; 2]) -> Self {
+            Pair(*values)
+        }
+    }
+
+    /// Model input: current state and time.
+    #[derive(Debug, Clone)]
+    struct Input {
+        y: Pair,
+        time: f64,
+    }
+
+    /// Linear system: `dy/dt = A y`.
+    struct LinearModel {
+        a: [[f64; 2]; 2],
+    }
+
+    impl Model for LinearModel {
+        type Input = Input;
+        type Output = Pair;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            let [y0, y1] = input.y.0;
+            Ok(Pair(self.a.map(|row| row[0] * y0 + row[1] * y1)))
+        }
+    }
+
+    struct LinearProblem;
+
+    impl OdeProblem for LinearProblem {
+        type Input = Input;
+        type Output = Pair;
+        type Delta = f64;
+        type State = Pair;
+        type Error = Infallible;
+
+        fn state(&self, input: &Self::Input) -> Result<Self::State, Self::Error> {
+            Ok(input.y)
+        }
+
+        fn derivative(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<DerivativeOf<Self::State, Self::Delta>, Self::Error> {
+            Ok(*output)
+        }
+
+        fn build_input(
+            &self,
+            base: &Self::Input,
+            state: &Self::State,
+            delta: &Self::Delta,
+        ) -> Result<Self::Input, Self::Error> {
+            Ok(Input {
+                y: *state,
+                time: base.time + delta,
+            })
+        }
+    }
+
+    impl IndependentVariable for LinearProblem {
+        fn independent_variable(&self, input: &Self::Input) -> Result<Self::Delta, Self::Error> {
+            Ok(input.time)
+        }
+    }
+
+    /// Stiff system with eigenvalues -1000 and -1.
+    ///
+    /// From `y(0) = [2, 1]` the solution is `y1 = e^-t` and
+    /// `y0 = e^-t + e^-1000t`, so the fast mode dies out almost immediately.
+    fn stiff() -> LinearModel {
+        LinearModel {
+            a: [[-1000.0, 999.0], [0.0, -1.0]],
+        }
+    }
+
+    fn initial() -> Input {
+        Input {
+            y: Pair([2.0, 1.0]),
+            time: 0.0,
+        }
+    }
+
+    fn config(order: Order) -> Config {
+        Config {
+            order,
+            ..Config::default()
+        }
+    }
+
+    /// Absolute error at `t = 1` for the slow component of the stiff system.
+    fn stiff_error(order: Order, steps: usize) -> f64 {
+        let dt = 1.0 / f64::from(u32::try_from(steps).unwrap());
+        let solution = solve_unobserved(
+            &stiff(),
+            &LinearProblem,
+            initial(),
+            dt,
+            steps,
+            &config(order),
+        )
+        .expect("should solve");
+
+        let last = solution.history.last().unwrap();
+        (last.input.y.0[0] - (-1.0_f64).exp()).abs()
+    }
+
+    // --- Tests ---
+
+    #[test]
+    fn stiff_system_is_stable_with_large_steps() {
+        // Forward Euler would need dt < 0.002 here.
+        for order in [
+            Order::One,
+            Order::Two,
+            Order::Three,
+            Order::Four,
+            Order::Five,
+        ] {
+            let solution =
+                solve_unobserved(&stiff(), &LinearProblem, initial(), 0.1, 10, &config(order))
+                    .expect("should solve");
+
+            assert_eq!(solution.status, Status::Complete);
+            let [y0, y1] = solution.history.last().unwrap().input.y.0;
+            assert_relative_eq!(y0, (-1.0_f64).exp(), max_relative = 0.05);
+            assert_relative_eq!(y1, (-1.0_f64).exp(), max_relative = 0.05);
+        }
+    }
+
+    #[test]
+    fn order_one_matches_backward_euler() {
+        let bdf = solve_unobserved(
+            &stiff(),
+            &LinearProblem,
+            initial(),
+            0.1,
+            5,
+            &config(Order::One),
+        )
+        .expect("should solve");
+        let backward_euler = crate::transient::backward_euler::solve_unobserved(
+            &stiff(),
+            &LinearProblem,
+            initial(),
+            0.1,
+            5,
+            &config(Order::One).newton,
+        )
+        .expect("should solve");
+
+        for (a, b) in bdf.history.iter().zip(&backward_euler.history) {
+            assert_eq!(a.input.y, b.input.y);
+        }
+    }
+
+    #[test]
+    fn converges_at_second_order() {
+        let coarse = stiff_error(Order::Two, 20);
+        let fine = stiff_error(Order::Two, 40);
+
+        let ratio = coarse / fine;
+        assert!(ratio > 3.5 && ratio < 4.5, "ratio = {ratio}");
+    }
+
+    #[test]
+    fn second_order_beats_first_order() {
+        let one = stiff_error(Order::One, 40);
+        let two = stiff_error(Order::Two, 40);
+
+        assert!(two < one / 10.0, "one = {one}, two = {two}");
+    }
+
+    #[test]
+    fn solve_until_restarts_on_shortened_step() {
+        let solution = solve_until_unobserved(
+            &stiff(),
+            &LinearProblem,
+            initial(),
+            0.3,
+            1.0,
+            &config(Order::Three),
+        )
+        .expect("should solve");
+
+        assert_eq!(solution.steps, 4);
+        assert_relative_eq!(solution.end, 1.0);
+
+        let [y0, y1] = solution.history.last().unwrap().input.y.0;
+        assert_relative_eq!(y0, (-1.0_f64).exp(), max_relative = 0.1);
+        assert_relative_eq!(y1, (-1.0_f64).exp(), max_relative = 0.1);
+    }
+}
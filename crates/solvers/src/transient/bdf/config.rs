@@ -0,0 +1,38 @@
+use crate::equation::newton;
+
+/// Highest BDF order the solver may use.
+///
+/// Orders one and two are A-stable, so they stay stable for any step size on
+/// decaying problems, including those with oscillatory modes. Orders three
+/// through five are more accurate but lose stability near the imaginary axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Order {
+    /// First order, equivalent to backward Euler.
+    One = 1,
+    /// Second order.
+    Two,
+    /// Third order.
+    Three,
+    /// Fourth order.
+    Four,
+    /// Fifth order.
+    Five,
+}
+
+/// Configuration for the BDF solver.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    /// Highest order to use once enough history is available.
+    pub order: Order,
+    /// Newton configuration for the implicit solve in each step.
+    pub newton: newton::Config,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            order: Order::Two,
+            newton: newton::Config::default(),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+/// Weights on previous states for each order, most recent first.
+///
+/// The order `k` formula is
+/// `y_{n+1} = Σ ALPHA[k-1][j] · y_{n-j} + BETA[k-1] · dt · f(y_{n+1})`.
+const ALPHA: [&[f64]; 5] = [
+    &[1.0],
+    &[4.0 / 3.0, -1.0 / 3.0],
+    &[18.0 / 11.0, -9.0 / 11.0, 2.0 / 11.0],
+    &[48.0 / 25.0, -36.0 / 25.0, 16.0 / 25.0, -3.0 / 25.0],
+    &[
+        300.0 / 137.0,
+        -300.0 / 137.0,
+        200.0 / 137.0,
+        -75.0 / 137.0,
+        12.0 / 137.0,
+    ],
+];
+
+/// Weight on the end-of-step derivative for each order.
+const BETA: [f64; 5] = [1.0, 2.0 / 3.0, 6.0 / 11.0, 12.0 / 25.0, 60.0 / 137.0];
+
+/// Flattened states from previous steps of equal size, most recent first.
+///
+/// The formulas assume a constant step, so the history restarts whenever the
+/// step size changes. The order in use is the number of states held, which
+/// ramps up to the configured order one step at a time.
+pub(super) struct History<D, const N: usize> {
+    states: Vec<[f64; N]>,
+    dt: Option<D>,
+}
+
+impl<D: Clone + PartialEq, const N: usize> History<D, N> {
+    pub(super) fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            dt: None,
+        }
+    }
+
+    /// Records the state at the start of a step of size `dt`.
+    pub(super) fn push(&mut self, state: [f64; N], dt: &D, max_order: usize) {
+        if self.dt.as_ref() != Some(dt) {
+            self.states.clear();
+            self.dt = Some(dt.clone());
+        }
+        self.states.insert(0, state);
+        self.states.truncate(max_order);
+    }
+
+    /// Returns the weighted sum of previous states and the derivative weight
+    /// for the current order.
+    pub(super) fn base(&self) -> ([f64; N], f64) {
+        let order = self.states.len();
+        debug_assert!(order > 0, "a state is pushed before each step");
+
+        let mut base = [0.0; N];
+        for (alpha, state) in ALPHA[order - 1].iter().zip(&self.states) {
+            for (sum, value) in base.iter_mut().zip(state) {
+                *sum += alpha * value;
+            }
+        }
+
+        (base, BETA[order - 1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn weights_reproduce_constants() {
+        for alpha in ALPHA {
+            assert_relative_eq!(alpha.iter().sum::<f64>(), 1.0, epsilon = 1e-14);
+        }
+    }
+
+    #[test]
+    fn order_ramps_up_and_restarts_on_new_step_size() {
+        let mut history = History::<f64, 1>::new();
+
+        history.push([1.0], &0.1, 2);
+        assert_eq!(history.base(), ([1.0], 1.0));
+
+        history.push([2.0], &0.1, 2);
+        history.push([3.0], &0.1, 2);
+        let (base, beta) = history.base();
+        assert_relative_eq!(base[0], 4.0 / 3.0 * 3.0 - 1.0 / 3.0 * 2.0);
+        assert_relative_eq!(beta, 2.0 / 3.0);
+
+        history.push([4.0], &0.05, 2);
+        assert_eq!(history.base(), ([4.0], 1.0));
+    }
+}
@@ -0,0 +1,484 @@
+//! Zero-crossing event detection for fixed-step integration.
+//!
+//! Problems that implement [`ZeroCrossing`] supply scalar event functions of
+//! model input and output. After each step, the solver compares their signs at
+//! both ends of the step. When a function changes sign, the crossing is
+//! located by regula falsi over partial steps, starting from the values
+//! already known at both ends, and reported to the observer as an
+//! [`Event::Crossing`].
+//!
+//! The observer decides what happens next:
+//!
+//! - `None` ignores the crossing and the full step is accepted.
+//! - [`Action::Reset`] ends the step at the crossing. The truncated input goes
+//!   through [`OdeProblem::finalize_step`], where the problem applies its
+//!   discrete change (refill the tank, latch the valve), and integration
+//!   continues from there.
+//! - [`Action::StopEarly`] ends the step at the crossing and stops.
+//!
+//! Event detection is available through `solve_with_crossings` in
+//! [`euler`](super::euler) and [`rk4`](super::rk4), which integrate until an
+//! end value like `solve_until` does. Crossings are detected on the step's
+//! unfinalized end, so each step costs one extra model call, plus the partial
+//! steps needed to locate any crossing.
+//!
+//! [`OdeProblem::finalize_step`]: twine_core::OdeProblem::finalize_step
+
+mod action;
+mod event;
+mod locate;
+
+pub use action::Action;
+pub use event::{Direction, Event};
+
+use std::ops::{Mul, Sub};
+
+use twine_core::{IndependentVariable, Model, Observer, Snapshot, ZeroCrossing};
+
+use crate::stats::Counted;
+
+use super::{
+    euler::{Error, Solution, Status},
+    fixed_step::{self, Until, UntilResult},
+};
+
+/// Integrates until `end`, locating and reporting zero crossings.
+///
+/// The `advance` closure takes a step of any size from a snapshot and returns
+/// the unfinalized next input, which lets crossings be located by re-stepping
+/// over a fraction of the step. It should evaluate any stages through `model`
+/// so they are counted.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn integrate<M, P, Obs, F, const K: usize>(
+    model: &Counted<'_, M>,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+    mut observer: Obs,
+    advance: F,
+) -> UntilResult<M, P>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>
+        + ZeroCrossing<K, Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+    F: Fn(&Snapshot<M::Input, M::Output>, &P::Delta) -> Result<M::Input, Error>,
+{
+    let mut until = Until::new(problem, dt, end)?;
+    let event_values = |snapshot: &Snapshot<M::Input, M::Output>| {
+        problem
+            .event_functions(&snapshot.input, &snapshot.output)
+            .map_err(Error::problem)
+    };
+
+    // Evaluate initial state.
+    let initial_output = model.call(&initial).map_err(Error::model)?;
+    let mut current = Snapshot::new(initial, initial_output);
+    let mut current_values = event_values(&current)?;
+
+    let mut history = vec![current.clone()];
+    let mut step = 0;
+    let mut status = Status::Complete;
+
+    let event = Event::Step {
+        step,
+        snapshot: current.clone(),
+    };
+    let action = observer.observe(&event);
+    model.tally().observed(action.as_ref());
+    if let Some(Action::StopEarly) = action {
+        status = Status::StoppedByObserver;
+    }
+
+    while status == Status::Complete
+        && let Some(dt) = until.next(&current)?
+    {
+        step += 1;
+
+        // Take the full step, then look for crossings along it. Detection uses
+        // the unfinalized end so `finalize_step` can't hide a crossing.
+        let full_input = advance(&current, &dt)?;
+        let full_output = model.call(&full_input).map_err(Error::model)?;
+        let full = Snapshot::new(full_input, full_output);
+        let full_values = event_values(&full)?;
+        let crossings = locate::locate(
+            model,
+            problem,
+            &advance,
+            &current,
+            &dt,
+            &current_values,
+            (&full_values, &full),
+        )?;
+
+        // The first crossing the observer acts on truncates the step.
+        let mut truncated = None;
+        for crossing in crossings {
+            let event = Event::Crossing {
+                step,
+                index: crossing.index,
+                direction: crossing.direction,
+                fraction: crossing.fraction,
+                snapshot: crossing.snapshot.clone(),
+            };
+            let action = observer.observe(&event);
+            model.tally().observed(action.as_ref());
+            if let Some(action) = action {
+                let partial_dt = dt.clone() * crossing.fraction;
+                let snapshot = fixed_step::accept(
+                    model,
+                    problem,
+                    &current,
+                    crossing.snapshot.input,
+                    &partial_dt,
+                )?;
+                truncated = Some((snapshot, action));
+                break;
+            }
+        }
+
+        let next = match truncated {
+            Some((snapshot, action)) => {
+                if action == Action::StopEarly {
+                    status = Status::StoppedByObserver;
+                }
+                snapshot
+            }
+            None => fixed_step::accept(model, problem, &current, full.input, &dt)?,
+        };
+        let next_values = event_values(&next)?;
+
+        history.push(next.clone());
+        if status == Status::StoppedByObserver {
+            break;
+        }
+
+        let event = Event::Step {
+            step,
+            snapshot: next.clone(),
+        };
+        let action = observer.observe(&event);
+        model.tally().observed(action.as_ref());
+        if let Some(Action::StopEarly) = action {
+            status = Status::StoppedByObserver;
+        }
+
+        current = next;
+        current_values = next_values;
+    }
+
+    let last = history
+        .last()
+        .expect("history always contains the initial snapshot");
+    let end = problem
+        .independent_variable(&last.input)
+        .map_err(Error::problem)?;
+
+    Ok(Solution {
+        status,
+        history,
+        steps: step,
+        stats: model.tally().stats(),
+        end,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{cell::RefCell, convert::Infallible};
+
+    use approx::assert_relative_eq;
+    use twine_core::{DerivativeOf, OdeProblem, StepIntegrable};
+
+    use crate::transient::{euler, rk4};
+
+    // --- Test fixtures ---
+
+    /// Tank level.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Level(f64);
+
+    /// Rate of change of the tank level.
+    #[derive(Debug, Clone, Copy)]
+    struct Flow(f64);
+
+    impl StepIntegrable<f64> for Level {
+        type Derivative = Flow;
+
+        fn step(&self, derivative: Flow, dt: f64) -> Self {
+            Level(self.0 + derivative.0 * dt)
+        }
+    }
+
+    /// Model input: current level and time.
+    #[derive(Debug, Clone)]
+    struct Input {
+        level: Level,
+        time: f64,
+    }
+
+    /// Model output: net flow into the tank.
+    #[derive(Debug, Clone)]
+    struct Output {
+        flow: Flow,
+    }
+
+    /// Tank draining at a constant rate.
+    struct DrainModel;
+
+    impl Model for DrainModel {
+        type Input = Input;
+        type Output = Output;
+        type Error = Infallible;
+
+        fn call(&self, _input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(Output { flow: Flow(-1.0) })
+        }
+    }
+
+    /// Draining tank that records the time of every model call.
+    #[derive(Default)]
+    struct RecordingDrain {
+        times: RefCell<Vec<f64>>,
+    }
+
+    impl Model for RecordingDrain {
+        type Input = Input;
+        type Output = Output;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            self.times.borrow_mut().push(input.time);
+            DrainModel.call(input)
+        }
+    }
+
+    /// Problem with events when the level drops through each threshold.
+    ///
+    /// When `refill` is set, `finalize_step` refills an empty tank to 1.
+    struct TankProblem<const K: usize> {
+        thresholds: [f64; K],
+        refill: bool,
+    }
+
+    impl<const K: usize> OdeProblem for TankProblem<K> {
+        type Input = Input;
+        type Output = Output;
+        type Delta = f64;
+        type State = Level;
+        type Error = Infallible;
+
+        fn state(&self, input: &Self::Input) -> Result<Self::State, Self::Error> {
+            Ok(input.level)
+        }
+
+        fn derivative(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<DerivativeOf<Self::State, Self::Delta>, Self::Error> {
+            Ok(output.flow)
+        }
+
+        fn build_input(
+            &self,
+            base: &Self::Input,
+            state: &Self::State,
+            delta: &Self::Delta,
+        ) -> Result<Self::Input, Self::Error> {
+            Ok(Input {
+                level: *state,
+                time: base.time + delta,
+            })
+        }
+
+        fn finalize_step(
+            &self,
+            mut next_input: Self::Input,
+            _prev_input: &Self::Input,
+            _prev_output: &Self::Output,
+            _step_delta: &Self::Delta,
+        ) -> Result<Self::Input, Self::Error> {
+            if self.refill && next_input.level.0 <= 0.0 {
+                next_input.level = Level(1.0);
+            }
+            Ok(next_input)
+        }
+    }
+
+    impl<const K: usize> IndependentVariable for TankProblem<K> {
+        fn independent_variable(&self, input: &Self::Input) -> Result<Self::Delta, Self::Error> {
+            Ok(input.time)
+        }
+    }
+
+    impl<const K: usize> ZeroCrossing<K> for TankProblem<K> {
+        fn event_functions(
+            &self,
+            input: &Self::Input,
+            _output: &Self::Output,
+        ) -> Result<[f64; K], Self::Error> {
+            Ok(self.thresholds.map(|threshold| input.level.0 - threshold))
+        }
+    }
+
+    fn full_tank() -> Input {
+        Input {
+            level: Level(1.0),
+            time: 0.0,
+        }
+    }
+
+    // --- Tests ---
+
+    #[test]
+    fn stop_at_crossing_truncates_step() {
+        let problem = TankProblem {
+            thresholds: [0.0],
+            refill: false,
+        };
+
+        let mut crossings = Vec::new();
+        let observer = |event: &Event<Input, Output>| match event {
+            Event::Crossing {
+                direction,
+                fraction,
+                ..
+            } => {
+                crossings.push((*direction, *fraction));
+                Some(Action::StopEarly)
+            }
+            Event::Step { .. } => None,
+        };
+
+        let solution =
+            euler::solve_with_crossings(&DrainModel, &problem, full_tank(), 0.3, 5.0, observer)
+                .expect("should solve");
+
+        // Empties at t = 1.0, one third of the way through the fourth step.
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.steps, 4);
+        assert_relative_eq!(solution.end, 1.0, epsilon = 1e-9);
+        assert_eq!(crossings.len(), 1);
+        assert_eq!(crossings[0].0, Direction::Falling);
+        assert_relative_eq!(crossings[0].1, 1.0 / 3.0, epsilon = 1e-9);
+
+        let last = solution.history.last().unwrap();
+        assert!(last.input.level.0 <= 0.0 && last.input.level.0 > -1e-9);
+    }
+
+    #[test]
+    fn reset_applies_finalize_step_at_crossing() {
+        let problem = TankProblem {
+            thresholds: [0.0],
+            refill: true,
+        };
+
+        let mut refills = Vec::new();
+        let observer = |event: &Event<Input, Output>| match event {
+            Event::Crossing { snapshot, .. } => {
+                refills.push(snapshot.input.time);
+                Some(Action::Reset)
+            }
+            Event::Step { .. } => None,
+        };
+
+        let solution =
+            euler::solve_with_crossings(&DrainModel, &problem, full_tank(), 0.3, 2.5, observer)
+                .expect("should solve");
+
+        // Refilled at t = 1 and t = 2, leaving half a tank at t = 2.5.
+        assert_eq!(solution.status, Status::Complete);
+        assert_eq!(refills.len(), 2);
+        assert_relative_eq!(refills[0], 1.0, epsilon = 1e-9);
+        assert_relative_eq!(refills[1], 2.0, epsilon = 1e-9);
+        assert_relative_eq!(solution.end, 2.5);
+
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(last.input.level.0, 0.5, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn ignored_crossing_keeps_full_step() {
+        let problem = TankProblem {
+            thresholds: [0.0],
+            refill: false,
+        };
+
+        let mut count = 0;
+        let observer = |event: &Event<Input, Output>| {
+            if matches!(event, Event::Crossing { .. }) {
+                count += 1;
+            }
+            None
+        };
+
+        let solution =
+            euler::solve_with_crossings(&DrainModel, &problem, full_tank(), 0.5, 2.0, observer)
+                .expect("should solve");
+
+        assert_eq!(count, 1);
+        assert_eq!(solution.steps, 4);
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(last.input.level.0, -1.0);
+    }
+
+    #[test]
+    fn crossings_in_one_step_are_reported_in_order() {
+        let problem = TankProblem {
+            thresholds: [0.25, 0.5],
+            refill: false,
+        };
+
+        let mut crossings = Vec::new();
+        let observer = |event: &Event<Input, Output>| {
+            if let Event::Crossing {
+                index, snapshot, ..
+            } = event
+            {
+                crossings.push((*index, snapshot.input.time));
+            }
+            None
+        };
+
+        rk4::solve_with_crossings(&DrainModel, &problem, full_tank(), 1.0, 1.0, observer)
+            .expect("should solve");
+
+        assert_eq!(crossings.len(), 2);
+        assert_eq!(crossings[0].0, 1);
+        assert_relative_eq!(crossings[0].1, 0.5, epsilon = 1e-9);
+        assert_eq!(crossings[1].0, 0);
+        assert_relative_eq!(crossings[1].1, 0.75, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn locating_reuses_the_known_step_ends() {
+        let problem = TankProblem {
+            thresholds: [0.0],
+            refill: false,
+        };
+        let model = RecordingDrain::default();
+        let observer = |event: &Event<Input, Output>| {
+            matches!(event, Event::Crossing { .. }).then_some(Action::StopEarly)
+        };
+
+        let solution =
+            euler::solve_with_crossings(&model, &problem, full_tank(), 0.3, 5.0, observer)
+                .expect("should solve");
+
+        // The crossing step runs from t = 0.9 to 1.2. Its start was evaluated
+        // as both the unfinalized and the accepted end of the previous step,
+        // and its end only as the unfinalized end of this one.
+        let times = model.times.into_inner();
+        let calls_at = |time: f64| times.iter().filter(|t| (*t - time).abs() < 1e-12).count();
+        assert_eq!(solution.steps, 4);
+        assert_eq!(calls_at(0.9), 2);
+        assert_eq!(calls_at(1.2), 1);
+    }
+}
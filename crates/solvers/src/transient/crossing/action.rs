@@ -0,0 +1,18 @@
+/// Control actions supported by event-aware integration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Stop the solver and return the solution so far.
+    ///
+    /// On a [`Event::Crossing`](super::Event::Crossing), the step is first
+    /// truncated at the crossing so the history ends there.
+    StopEarly,
+
+    /// End the step at the crossing and continue integrating from there.
+    ///
+    /// The truncated input goes through [`OdeProblem::finalize_step`], which
+    /// is where the problem applies its state reset. Only meaningful on
+    /// [`Event::Crossing`](super::Event::Crossing); ignored otherwise.
+    ///
+    /// [`OdeProblem::finalize_step`]: twine_core::OdeProblem::finalize_step
+    Reset,
+}
@@ -0,0 +1,51 @@
+use twine_core::Snapshot;
+
+/// Direction in which an event function crossed zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The function went from negative to non-negative.
+    Rising,
+    /// The function went from non-negative to negative.
+    Falling,
+}
+
+/// Event emitted by event-aware integration.
+#[derive(Debug, Clone)]
+pub enum Event<I, O> {
+    /// A snapshot after an accepted step.
+    ///
+    /// Step 0 is the initial state before any integration.
+    Step {
+        /// The step number (0 for initial, then 1, 2, ...).
+        step: usize,
+        /// Snapshot of the model input and output at this step.
+        snapshot: Snapshot<I, O>,
+    },
+
+    /// A zero crossing located within the step about to be accepted.
+    ///
+    /// Crossings within a step are reported in order along the step. Returning
+    /// `None` ignores the crossing and moves on to the next one.
+    Crossing {
+        /// The number the step will have once accepted.
+        step: usize,
+        /// Index of the event function that crossed zero.
+        index: usize,
+        /// Direction of the crossing.
+        direction: Direction,
+        /// Location of the crossing as a fraction of the step, in `(0, 1]`.
+        fraction: f64,
+        /// Snapshot just past the crossing, before `finalize_step` is applied.
+        snapshot: Snapshot<I, O>,
+    },
+}
+
+impl<I, O> Event<I, O> {
+    /// Returns the snapshot carried by this event.
+    #[must_use]
+    pub fn snapshot(&self) -> &Snapshot<I, O> {
+        match self {
+            Event::Step { snapshot, .. } | Event::Crossing { snapshot, .. } => snapshot,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Locating zero crossings within a step.
+//!
+//! A partial step of size `fraction * dt` is an ordinary step with a smaller
+//! delta, so the crossing location becomes a scalar root-finding problem in
+//! `fraction ∈ [0, 1]`. The event function values at both ends of the step
+//! are already known, so the search starts from them and only evaluates
+//! interior points, using the Illinois variant of regula falsi.
+
+use std::ops::Mul;
+
+use twine_core::{Model, Snapshot, ZeroCrossing};
+
+use crate::{equation::bisection::Sign, transient::euler::Error};
+
+use super::Direction;
+
+/// Maximum number of partial steps taken to locate one crossing.
+const MAX_ITERS: usize = 100;
+
+/// Width of the bracket, in fractions of a step, at which the search stops.
+const FRACTION_TOL: f64 = 1e-10;
+
+/// Crossings located within a step, ordered along it.
+pub(crate) type LocateResult<I, O> = Result<Vec<Located<I, O>>, Error>;
+
+/// A zero crossing located within a step.
+pub(crate) struct Located<I, O> {
+    pub(crate) index: usize,
+    pub(crate) direction: Direction,
+    pub(crate) fraction: f64,
+    /// Unfinalized snapshot just past the crossing.
+    pub(crate) snapshot: Snapshot<I, O>,
+}
+
+/// Finds every event function that changes sign over a step and locates each
+/// crossing, returning them ordered along the step.
+///
+/// Crossings are placed at the first evaluated point past the zero, so the
+/// event function already has its new sign there and a step restarted from
+/// it does not detect the same crossing again. The end of the step is always
+/// past the zero, so it is used if no interior point is found closer.
+pub(crate) fn locate<M, P, F, const K: usize>(
+    model: &M,
+    problem: &P,
+    advance: &F,
+    current: &Snapshot<M::Input, M::Output>,
+    dt: &P::Delta,
+    start: &[f64; K],
+    end: (&[f64; K], &Snapshot<M::Input, M::Output>),
+) -> LocateResult<M::Input, M::Output>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: ZeroCrossing<K, Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + Mul<f64, Output = P::Delta>,
+    F: Fn(&Snapshot<M::Input, M::Output>, &P::Delta) -> Result<M::Input, Error>,
+{
+    let (end_values, end_snapshot) = end;
+
+    let mut located = Vec::new();
+    for (index, (&before, &after)) in start.iter().zip(end_values).enumerate() {
+        if !before.is_finite() || !after.is_finite() || Sign::of(before) == Sign::of(after) {
+            continue;
+        }
+
+        let target = Sign::of(after);
+        let direction = match target {
+            Sign::Positive => Direction::Rising,
+            Sign::Negative => Direction::Falling,
+        };
+
+        // The bracket [lo, hi] always has the old sign at `lo` and the new
+        // sign at `hi`, whose snapshot is the earliest known point past the
+        // crossing.
+        let (mut lo, mut lo_value) = (0.0, before);
+        let (mut hi, mut hi_value) = (1.0, after);
+        let mut past = end_snapshot.clone();
+        let mut last_moved = None;
+
+        for _ in 0..MAX_ITERS {
+            if hi - lo <= FRACTION_TOL {
+                break;
+            }
+
+            // Fall back to the midpoint if rounding puts the secant point
+            // outside the open bracket.
+            let secant = lo - lo_value * (hi - lo) / (hi_value - lo_value);
+            let fraction = if secant > lo && secant < hi {
+                secant
+            } else {
+                0.5 * (lo + hi)
+            };
+
+            let input = advance(current, &(dt.clone() * fraction))?;
+            let output = model.call(&input).map_err(Error::model)?;
+            let snapshot = Snapshot::new(input, output);
+            let value = problem
+                .event_functions(&snapshot.input, &snapshot.output)
+                .map_err(Error::problem)?[index];
+            if !value.is_finite() {
+                break;
+            }
+
+            // Illinois: when the same end moves twice in a row, halve the
+            // value kept at the other end so the secant stops stalling there.
+            let moved = Sign::of(value) == target;
+            if moved {
+                hi = fraction;
+                hi_value = value;
+                past = snapshot;
+                if last_moved == Some(true) {
+                    lo_value *= 0.5;
+                }
+            } else {
+                lo = fraction;
+                lo_value = value;
+                if last_moved == Some(false) {
+                    hi_value *= 0.5;
+                }
+            }
+            last_moved = Some(moved);
+        }
+
+        located.push(Located {
+            index,
+            direction,
+            fraction: hi,
+            snapshot: past,
+        });
+    }
+
+    located.sort_by(|a, b| a.fraction.total_cmp(&b.fraction));
+    Ok(located)
+}
@@ -0,0 +1,359 @@
+//! Dense output for transient solution histories.
+//!
+//! Integrators record snapshots only at step boundaries, but results are often
+//! needed at other values of the independent variable, such as every hour of
+//! a simulated year, without forcing the step size to match. The functions in
+//! this module interpolate a solution history at any requested values within
+//! its span, returning the state at each.
+//!
+//! - [`linear`] blends the states at either end of the surrounding step. Its
+//!   error is second order in the step size, which matches the accuracy of
+//!   [`euler`](super::euler) and [`backward_euler`](super::backward_euler).
+//! - [`hermite`] also uses the derivatives at either end to build a cubic that
+//!   matches both states and both slopes. It is fourth-order accurate, which
+//!   suits the higher-order methods: [`rk4`](super::rk4),
+//!   [`dopri5`](super::dopri5), and [`bdf`](super::bdf).
+//!
+//! The state must implement [`Interpolate`], and the problem must implement
+//! [`IndependentVariable`] so each snapshot can be placed along the history.
+//! Neither function calls the model; derivatives come from the recorded
+//! outputs. The fraction of the way through each step is found by dividing
+//! deltas, so `Delta` must divide into an `f64`.
+//!
+//! The history must be ordered by increasing independent variable, as every
+//! solver produces it. Requested values may come in any order, and values that
+//! land exactly on a snapshot return its state unchanged.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use twine_solvers::transient::{dense, rk4};
+//!
+//! let solution = rk4::solve_until_unobserved(&model, &problem, initial_input, 900.0, YEAR)?;
+//! let hourly = (0..8760).map(|hour| f64::from(hour) * 3600.0);
+//! let states = dense::hermite(&problem, &solution.history, hourly)?;
+//! ```
+
+mod error;
+
+pub use error::Error;
+
+use std::ops::{Div, Mul, Sub};
+
+use twine_core::{IndependentVariable, Interpolate, Snapshot, StepIntegrable};
+
+/// Interpolates the history linearly between step boundaries.
+///
+/// Returns one state for each value in `at`, in the same order.
+///
+/// # Errors
+///
+/// Returns [`Error::OutOfRange`] if a value lies outside the span of the
+/// history, or an error if the problem fails to extract a state or
+/// independent variable.
+pub fn linear<P, A>(
+    problem: &P,
+    history: &[Snapshot<P::Input, P::Output>],
+    at: A,
+) -> Result<Vec<P::State>, Error>
+where
+    P: IndependentVariable,
+    P::State: Interpolate,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Div<Output = f64>,
+    A: IntoIterator<Item = P::Delta>,
+{
+    sample(problem, history, at, |start, end, _span, fraction| {
+        let start = problem.state(&start.input).map_err(Error::problem)?;
+        let end = problem.state(&end.input).map_err(Error::problem)?;
+        Ok(start.interpolate(&end, fraction))
+    })
+}
+
+/// Interpolates the history with cubic Hermite polynomials between step
+/// boundaries.
+///
+/// Each cubic matches the states and derivatives at both ends of its step.
+/// It is built from a linear blend of the states plus one step along each
+/// derivative:
+///
+/// ```text
+/// state(θ) = blend(start, end, 3θ² − 2θ³)
+///          + start_derivative * dt * (θ³ − 2θ² + θ)
+///          + end_derivative   * dt * (θ³ − θ²)
+/// ```
+///
+/// where `θ` is the fraction of the way through the step.
+///
+/// Returns one state for each value in `at`, in the same order.
+///
+/// # Errors
+///
+/// Returns [`Error::OutOfRange`] if a value lies outside the span of the
+/// history, or an error if the problem fails to extract a state, derivative,
+/// or independent variable.
+pub fn hermite<P, A>(
+    problem: &P,
+    history: &[Snapshot<P::Input, P::Output>],
+    at: A,
+) -> Result<Vec<P::State>, Error>
+where
+    P: IndependentVariable,
+    P::State: Interpolate,
+    P::Delta: Clone
+        + PartialOrd
+        + Sub<Output = P::Delta>
+        + Div<Output = f64>
+        + Mul<f64, Output = P::Delta>,
+    A: IntoIterator<Item = P::Delta>,
+{
+    sample(problem, history, at, |start, end, span, fraction| {
+        let start_state = problem.state(&start.input).map_err(Error::problem)?;
+        let end_state = problem.state(&end.input).map_err(Error::problem)?;
+        let start_derivative = problem
+            .derivative(&start.input, &start.output)
+            .map_err(Error::problem)?;
+        let end_derivative = problem
+            .derivative(&end.input, &end.output)
+            .map_err(Error::problem)?;
+
+        let squared = fraction * fraction;
+        let cubed = squared * fraction;
+        let blend = 3.0 * squared - 2.0 * cubed;
+        let start_weight = cubed - 2.0 * squared + fraction;
+        let end_weight = cubed - squared;
+
+        Ok(start_state
+            .interpolate(&end_state, blend)
+            .step(start_derivative, span.clone() * start_weight)
+            .step(end_derivative, span * end_weight))
+    })
+}
+
+/// Locates each requested value in the history and interpolates within its
+/// step using `between`.
+///
+/// The closure receives the snapshots at the start and end of the step, the
+/// step size, and the fraction of the way through it. It is only called for
+/// values strictly inside a step, so the step size is never zero.
+fn sample<P, A, F>(
+    problem: &P,
+    history: &[Snapshot<P::Input, P::Output>],
+    at: A,
+    mut between: F,
+) -> Result<Vec<P::State>, Error>
+where
+    P: IndependentVariable,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Div<Output = f64>,
+    A: IntoIterator<Item = P::Delta>,
+    F: FnMut(
+        &Snapshot<P::Input, P::Output>,
+        &Snapshot<P::Input, P::Output>,
+        P::Delta,
+        f64,
+    ) -> Result<P::State, Error>,
+{
+    let times = history
+        .iter()
+        .map(|snapshot| problem.independent_variable(&snapshot.input))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(Error::problem)?;
+
+    at.into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            // First snapshot at or after the requested value.
+            let upper = times.partition_point(|time| *time < value);
+
+            match times.get(upper) {
+                Some(time) if *time == value => {
+                    problem.state(&history[upper].input).map_err(Error::problem)
+                }
+                Some(time) if upper > 0 => {
+                    let lower = upper - 1;
+                    let span = time.clone() - times[lower].clone();
+                    let fraction = (value - times[lower].clone()) / span.clone();
+                    between(&history[lower], &history[upper], span, fraction)
+                }
+                _ => Err(Error::OutOfRange { index }),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use twine_core::{Model, OdeProblem};
+
+    use crate::transient::{euler, rk4};
+
+    // --- Test fixtures ---
+
+    /// Scalar state.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Value(f64);
+
+    /// Derivative of the scalar state.
+    #[derive(Debug, Clone, Copy)]
+    struct Rate(f64);
+
+    impl StepIntegrable<f64> for Value {
+        type Derivative = Rate;
+
+        fn step(&self, derivative: Rate, dt: f64) -> Self {
+            Value(self.0 + derivative.0 * dt)
+        }
+    }
+
+    impl Interpolate for Value {
+        fn interpolate(&self, other: &Self, fraction: f64) -> Self {
+            Value(self.0 + (other.0 - self.0) * fraction)
+        }
+    }
+
+    /// Model input: current value and time.
+    #[derive(Debug, Clone)]
+    struct Input {
+        value: Value,
+        time: f64,
+    }
+
+    /// Model whose rate is a fixed multiple of the value.
+    struct Exponential {
+        rate: f64,
+    }
+
+    impl Model for Exponential {
+        type Input = Input;
+        type Output = Rate;
+        type Error = Infallible;
+
+        fn call(&self, input: &Input) -> Result<Rate, Infallible> {
+            Ok(Rate(self.rate * input.value.0))
+        }
+    }
+
+    /// Problem that extracts the value and steps time along with it.
+    struct ValueProblem;
+
+    impl OdeProblem for ValueProblem {
+        type Input = Input;
+        type Output = Rate;
+        type Delta = f64;
+        type State = Value;
+        type Error = Infallible;
+
+        fn state(&self, input: &Input) -> Result<Value, Infallible> {
+            Ok(input.value)
+        }
+
+        fn derivative(&self, _input: &Input, output: &Rate) -> Result<Rate, Infallible> {
+            Ok(*output)
+        }
+
+        fn build_input(
+            &self,
+            base: &Input,
+            state: &Value,
+            delta: &f64,
+        ) -> Result<Input, Infallible> {
+            Ok(Input {
+                value: *state,
+                time: base.time + delta,
+            })
+        }
+    }
+
+    impl IndependentVariable for ValueProblem {
+        fn independent_variable(&self, input: &Input) -> Result<f64, Infallible> {
+            Ok(input.time)
+        }
+    }
+
+    fn initial() -> Input {
+        Input {
+            value: Value(1.0),
+            time: 0.0,
+        }
+    }
+
+    fn decay_history(dt: f64) -> Vec<Snapshot<Input, Rate>> {
+        rk4::solve_until_unobserved(
+            &Exponential { rate: -1.0 },
+            &ValueProblem,
+            initial(),
+            dt,
+            2.0,
+        )
+        .expect("should solve")
+        .history
+    }
+
+    // --- Tests ---
+
+    #[test]
+    fn linear_matches_euler_between_steps() {
+        // Euler steps are straight lines, so linear interpolation is exact.
+        let solution =
+            euler::solve_unobserved(&Exponential { rate: 1.0 }, &ValueProblem, initial(), 0.5, 2)
+                .expect("should solve");
+
+        let states =
+            linear(&ValueProblem, &solution.history, [0.25, 0.75]).expect("should interpolate");
+
+        assert_relative_eq!(states[0].0, 1.25);
+        assert_relative_eq!(states[1].0, 1.5 + 0.75 * 0.5);
+    }
+
+    #[test]
+    fn hermite_is_more_accurate_than_linear() {
+        let history = decay_history(0.25);
+        // Report times that never line up with the steps.
+        let at: Vec<f64> = (0..20).map(|i| 0.05 + 0.1 * f64::from(i)).collect();
+
+        let max_error = |states: Vec<Value>| {
+            at.iter()
+                .zip(states)
+                .map(|(t, state)| (state.0 - (-t).exp()).abs())
+                .fold(0.0, f64::max)
+        };
+        let linear_error =
+            max_error(linear(&ValueProblem, &history, at.clone()).expect("should interpolate"));
+        let hermite_error =
+            max_error(hermite(&ValueProblem, &history, at.clone()).expect("should interpolate"));
+
+        assert!(hermite_error < 1e-4, "hermite error = {hermite_error}");
+        assert!(
+            hermite_error < linear_error / 10.0,
+            "hermite: {hermite_error}, linear: {linear_error}"
+        );
+    }
+
+    #[test]
+    fn step_boundaries_return_recorded_states() {
+        let history = decay_history(0.5);
+
+        let states = hermite(&ValueProblem, &history, [2.0, 0.0, 1.0]).expect("should interpolate");
+
+        assert_eq!(states[0], history[4].input.value);
+        assert_eq!(states[1], history[0].input.value);
+        assert_eq!(states[2], history[2].input.value);
+    }
+
+    #[test]
+    fn rejects_values_outside_history() {
+        let history = decay_history(0.5);
+
+        for value in [-0.1, 2.1, f64::NAN] {
+            let result = linear(&ValueProblem, &history, [1.0, value]);
+            assert!(matches!(result, Err(Error::OutOfRange { index: 1 })));
+        }
+
+        let result = hermite(&ValueProblem, &[], [0.0]);
+        assert!(matches!(result, Err(Error::OutOfRange { index: 0 })));
+    }
+}
@@ -0,0 +1,17 @@
+use std::error::Error as StdError;
+
+/// Errors that can occur when interpolating a solution history.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("requested value {index} is outside the span of the history")]
+    OutOfRange { index: usize },
+
+    #[error("problem error: {0}")]
+    Problem(#[source] Box<dyn StdError + Send + Sync>),
+}
+
+impl Error {
+    pub(crate) fn problem<E: StdError + Send + Sync + 'static>(err: E) -> Self {
+        Self::Problem(Box::new(err))
+    }
+}
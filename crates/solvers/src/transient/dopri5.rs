@@ -0,0 +1,657 @@
+//! Adaptive Dormand–Prince 5(4) solver for ODE problems.
+//!
+//! This module provides an error-controlled explicit Runge–Kutta integrator.
+//! Each step computes a fifth-order solution and an embedded fourth-order
+//! solution; their difference estimates the local error. Steps within
+//! tolerance are accepted and the fifth-order solution is propagated. Steps
+//! outside tolerance are rejected and retried with a smaller step. A PI
+//! controller proposes the next step size either way.
+//!
+//! Instead of choosing `dt` and `steps`, the caller provides the total `span`
+//! to integrate over and a [`Config`] with tolerances and step limits. The
+//! state must implement [`ErrorNorm`] in addition to [`StepIntegrable`] so the
+//! solver can reduce the two estimates to a single scaled error.
+//!
+//! Each attempted step calls the model six times for the intermediate stages,
+//! and each accepted step calls it once more on the finalized input. Intermediate
+//! stages never call [`OdeProblem::finalize_step`].
+//!
+//! [`StepIntegrable`]: twine_core::StepIntegrable
+//!
+//! # Example
+//!
+//! ```ignore
+//! use twine_solvers::transient::dopri5;
+//!
+//! let config = dopri5::Config {
+//!     abs_tol: 1e-9,
+//!     rel_tol: 1e-6,
+//!     ..dopri5::Config::new(1e-3, 1e-9, 1.0)
+//! };
+//! let solution = dopri5::solve_unobserved(&model, &problem, initial_input, 10.0, &config)?;
+//! ```
+
+mod config;
+mod controller;
+mod error;
+mod event;
+mod solution;
+mod stages;
+mod tableau;
+
+pub use config::{Config, ConfigError};
+pub use error::Error;
+pub use event::Event;
+pub use solution::{Solution, Status};
+
+pub use super::euler::Action;
+
+use std::{
+    cmp::Ordering,
+    ops::{Add, Mul, Sub},
+};
+
+use twine_core::{DerivativeOf, ErrorNorm, Model, Observer, OdeProblem, Snapshot};
+
+use crate::{Stats, stats::Counted};
+
+use controller::Controller;
+
+/// Result of [`solve`], spelled out once to keep signatures readable.
+type SolveResult<M, P> =
+    Result<Solution<<M as Model>::Input, <M as Model>::Output, <P as OdeProblem>::Delta>, Error>;
+
+/// Integrates an ODE problem over `span` using adaptive Dormand–Prince 5(4).
+///
+/// # Algorithm
+///
+/// 1. Call the model with the initial input to get the initial snapshot.
+/// 2. Until the full span is covered:
+///    - Shorten the step if needed to land exactly on the end of the span.
+///    - Evaluate the stages and compute the scaled error norm.
+///    - If the error is at most 1, accept: build and finalize the next input,
+///      call the model, and emit [`Event::Accepted`].
+///    - Otherwise reject and emit [`Event::Rejected`].
+///    - Propose the next step size with the PI controller, clamped to
+///      `[min_step, max_step]`.
+/// 3. Return the solution with the history of accepted steps.
+///
+/// # Observer
+///
+/// The observer receives an [`Event`] for the initial state and for every
+/// accepted or rejected step, and may return [`Action::StopEarly`] to
+/// terminate the simulation early.
+///
+/// # Errors
+///
+/// Returns an error if the config or span is invalid, a rejected step is
+/// already at `min_step`, or the model or problem returns an error.
+pub fn solve<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    span: P::Delta,
+    config: &Config<P::Delta>,
+    mut observer: Obs,
+) -> SolveResult<M, P>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::State: ErrorNorm,
+    P::Delta: Clone
+        + PartialOrd
+        + Add<Output = P::Delta>
+        + Sub<Output = P::Delta>
+        + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone,
+    Obs: Observer<Event<M::Input, M::Output, P::Delta>, Action>,
+{
+    config.validate()?;
+    let zero = span.clone() * 0.0;
+    if !matches!(
+        span.partial_cmp(&zero),
+        Some(Ordering::Greater | Ordering::Equal)
+    ) {
+        return Err(Error::InvalidSpan);
+    }
+
+    // Count every evaluation, including rejected attempts.
+    let model = &Counted::new(model);
+
+    // Evaluate initial state.
+    let initial_output = model.call(&initial).map_err(Error::model)?;
+    let mut current = Snapshot::new(initial, initial_output);
+    let mut progress = Progress::new(span, zero, current.clone());
+
+    let event = Event::Initial {
+        snapshot: current.clone(),
+    };
+    let action = observer.observe(&event);
+    model.tally().observed(action.as_ref());
+    if let Some(Action::StopEarly) = action {
+        return Ok(progress.finish(Status::StoppedByObserver, model.tally().stats()));
+    }
+
+    let mut controller = Controller::new(config);
+    let mut dt = config.initial_step.clone();
+
+    while progress.elapsed < progress.span {
+        if progress.steps + progress.rejected >= config.max_steps {
+            return Ok(progress.finish(Status::MaxSteps, model.tally().stats()));
+        }
+
+        // Land exactly on the end of the span.
+        let remaining = progress.span.clone() - progress.elapsed.clone();
+        let is_last = dt >= remaining;
+        let step_dt = if is_last { remaining } else { dt.clone() };
+
+        let attempt = stages::attempt(model, problem, &current, &step_dt)?;
+        let error = attempt
+            .high
+            .error_norm(&attempt.low, config.abs_tol, config.rel_tol);
+
+        if error <= 1.0 {
+            // Build, finalize, and evaluate the accepted input.
+            let next_input = problem
+                .build_input(&current.input, &attempt.high, &step_dt)
+                .map_err(Error::problem)?;
+            let next_input = problem
+                .finalize_step(next_input, &current.input, &current.output, &step_dt)
+                .map_err(Error::problem)?;
+            let next_output = model.call(&next_input).map_err(Error::model)?;
+            current = Snapshot::new(next_input, next_output);
+            progress.accept(current.clone(), step_dt.clone(), is_last);
+
+            let event = Event::Accepted {
+                step: progress.steps,
+                elapsed: progress.elapsed.clone(),
+                dt: step_dt.clone(),
+                error,
+                snapshot: current.clone(),
+            };
+            let action = observer.observe(&event);
+            model.tally().observed(action.as_ref());
+            if let Some(Action::StopEarly) = action {
+                return Ok(progress.finish(Status::StoppedByObserver, model.tally().stats()));
+            }
+
+            dt = clamp_step(step_dt * controller.accept(error), config);
+        } else {
+            progress.rejected += 1;
+
+            let event = Event::Rejected {
+                elapsed: progress.elapsed.clone(),
+                dt: step_dt.clone(),
+                error,
+            };
+            let action = observer.observe(&event);
+            model.tally().observed(action.as_ref());
+            if let Some(Action::StopEarly) = action {
+                return Ok(progress.finish(Status::StoppedByObserver, model.tally().stats()));
+            }
+
+            // A final step shortened to hit the end can be below `min_step`
+            // without the controller having shrunk it, so check its step.
+            if dt <= config.min_step {
+                return Err(Error::StepSizeTooSmall);
+            }
+            dt = clamp_step(step_dt * controller.reject(error), config);
+        }
+    }
+
+    Ok(progress.finish(Status::Complete, model.tally().stats()))
+}
+
+/// Integrates an ODE problem using Dormand–Prince without observation.
+///
+/// This is a convenience wrapper around [`solve`] that discards events.
+///
+/// # Errors
+///
+/// Returns an error if the config or span is invalid, the step size falls
+/// below `min_step`, or the model or problem returns an error.
+pub fn solve_unobserved<M, P>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    span: P::Delta,
+    config: &Config<P::Delta>,
+) -> SolveResult<M, P>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::State: ErrorNorm,
+    P::Delta: Clone
+        + PartialOrd
+        + Add<Output = P::Delta>
+        + Sub<Output = P::Delta>
+        + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone,
+{
+    solve(model, problem, initial, span, config, ())
+}
+
+/// Progress through the span, shared by every exit path of [`solve`].
+struct Progress<I, O, D> {
+    span: D,
+    elapsed: D,
+    history: Vec<Snapshot<I, O>>,
+    steps: usize,
+    rejected: usize,
+}
+
+impl<I, O, D: Clone + Add<Output = D>> Progress<I, O, D> {
+    fn new(span: D, zero: D, initial: Snapshot<I, O>) -> Self {
+        Self {
+            span,
+            elapsed: zero,
+            history: vec![initial],
+            steps: 0,
+            rejected: 0,
+        }
+    }
+
+    /// Records an accepted step, snapping to the end of the span on the last.
+    fn accept(&mut self, snapshot: Snapshot<I, O>, dt: D, is_last: bool) {
+        self.history.push(snapshot);
+        self.steps += 1;
+        self.elapsed = if is_last {
+            self.span.clone()
+        } else {
+            self.elapsed.clone() + dt
+        };
+    }
+
+    #[allow(clippy::similar_names)]
+    fn finish(self, status: Status, stats: Stats) -> Solution<I, O, D> {
+        Solution {
+            status,
+            history: self.history,
+            steps: self.steps,
+            rejected: self.rejected,
+            stats,
+            elapsed: self.elapsed,
+        }
+    }
+}
+
+/// Clamps a proposed step to the configured limits.
+fn clamp_step<D: Clone + PartialOrd>(dt: D, config: &Config<D>) -> D {
+    if dt < config.min_step {
+        config.min_step.clone()
+    } else if dt > config.max_step {
+        config.max_step.clone()
+    } else {
+        dt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{cell::Cell, convert::Infallible};
+
+    use approx::assert_relative_eq;
+    use twine_core::StepIntegrable;
+
+    // --- Test fixtures ---
+
+    /// Scalar state.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Value(f64);
+
+    /// Derivative of the scalar state.
+    #[derive(Debug, Clone, Copy)]
+    struct Rate(f64);
+
+    impl StepIntegrable<f64> for Value {
+        type Derivative = Rate;
+
+        fn step(&self, derivative: Rate, dt: f64) -> Self {
+            Value(self.0 + derivative.0 * dt)
+        }
+    }
+
+    impl ErrorNorm for Value {
+        fn error_norm(&self, other: &Self, abs_tol: f64, rel_tol: f64) -> f64 {
+            let scale = abs_tol + rel_tol * self.0.abs().max(other.0.abs());
+            (self.0 - other.0).abs() / scale
+        }
+    }
+
+    /// Model input: current value and time.
+    #[derive(Debug, Clone)]
+    struct Input {
+        value: Value,
+        time: f64,
+    }
+
+    /// Model output: rate of change at the current state.
+    #[derive(Debug, Clone)]
+    struct Output {
+        rate: Rate,
+    }
+
+    /// Exponential decay: `dy/dt = -k y`.
+    struct DecayModel {
+        k: f64,
+    }
+
+    impl Model for DecayModel {
+        type Input = Input;
+        type Output = Output;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(Output {
+                rate: Rate(-self.k * input.value.0),
+            })
+        }
+    }
+
+    /// Exponential decay whose first evaluation past `kick_after` returns a
+    /// wildly wrong rate, forcing the step that contains it to be rejected.
+    struct KickedDecayModel {
+        kick_after: f64,
+        kicked: Cell<bool>,
+    }
+
+    impl Model for KickedDecayModel {
+        type Input = Input;
+        type Output = Output;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            if input.time > self.kick_after && !self.kicked.replace(true) {
+                return Ok(Output { rate: Rate(1e3) });
+            }
+            Ok(Output {
+                rate: Rate(-input.value.0),
+            })
+        }
+    }
+
+    /// Problem that counts how often `finalize_step` is called.
+    #[derive(Default)]
+    struct ValueProblem {
+        finalized: Cell<usize>,
+    }
+
+    impl OdeProblem for ValueProblem {
+        type Input = Input;
+        type Output = Output;
+        type Delta = f64;
+        type State = Value;
+        type Error = Infallible;
+
+        fn state(&self, input: &Self::Input) -> Result<Self::State, Self::Error> {
+            Ok(input.value)
+        }
+
+        fn derivative(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<DerivativeOf<Self::State, Self::Delta>, Self::Error> {
+            Ok(output.rate)
+        }
+
+        fn build_input(
+            &self,
+            base: &Self::Input,
+            state: &Self::State,
+            delta: &Self::Delta,
+        ) -> Result<Self::Input, Self::Error> {
+            Ok(Input {
+                value: *state,
+                time: base.time + delta,
+            })
+        }
+
+        fn finalize_step(
+            &self,
+            next_input: Self::Input,
+            _prev_input: &Self::Input,
+            _prev_output: &Self::Output,
+            _step_delta: &Self::Delta,
+        ) -> Result<Self::Input, Self::Error> {
+            self.finalized.set(self.finalized.get() + 1);
+            Ok(next_input)
+        }
+    }
+
+    fn initial() -> Input {
+        Input {
+            value: Value(1.0),
+            time: 0.0,
+        }
+    }
+
+    fn tight_config() -> Config<f64> {
+        Config {
+            abs_tol: 1e-10,
+            rel_tol: 1e-10,
+            ..Config::new(0.1, 1e-12, 1.0)
+        }
+    }
+
+    // --- Tests ---
+
+    #[test]
+    fn exponential_decay_meets_tolerance() {
+        let problem = ValueProblem::default();
+
+        let solution = solve_unobserved(
+            &DecayModel { k: 1.0 },
+            &problem,
+            initial(),
+            2.0,
+            &tight_config(),
+        )
+        .expect("should solve");
+
+        assert_eq!(solution.status, Status::Complete);
+        assert_relative_eq!(solution.elapsed, 2.0);
+        assert_eq!(solution.history.len(), solution.steps + 1);
+        assert_eq!(problem.finalized.get(), solution.steps);
+
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(last.input.time, 2.0, epsilon = 1e-12);
+        assert_relative_eq!(last.input.value.0, (-2.0_f64).exp(), epsilon = 1e-8);
+    }
+
+    #[test]
+    fn oversized_initial_step_is_rejected_and_retried() {
+        let config = Config {
+            initial_step: 1.0,
+            ..tight_config()
+        };
+
+        let mut rejected_steps = Vec::new();
+        let solution = solve(
+            &DecayModel { k: 5.0 },
+            &ValueProblem::default(),
+            initial(),
+            1.0,
+            &config,
+            |event: &Event<Input, Output, f64>| {
+                if let Event::Rejected { dt, error, .. } = event {
+                    rejected_steps.push((*dt, *error));
+                }
+                None
+            },
+        )
+        .expect("should solve");
+
+        assert!(solution.rejected > 0);
+        assert_eq!(rejected_steps.len(), solution.rejected);
+        assert!(rejected_steps.iter().all(|(_, error)| *error > 1.0));
+        assert_relative_eq!((rejected_steps[0].0), 1.0);
+
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(last.input.value.0, (-5.0_f64).exp(), epsilon = 1e-8);
+    }
+
+    #[test]
+    fn steps_respect_max_step_and_grow_when_smooth() {
+        let config = Config {
+            initial_step: 1e-4,
+            max_step: 0.25,
+            ..Config::new(1e-4, 1e-12, 0.25)
+        };
+
+        let mut dts = Vec::new();
+        solve(
+            &DecayModel { k: 1.0 },
+            &ValueProblem::default(),
+            initial(),
+            3.0,
+            &config,
+            |event: &Event<Input, Output, f64>| {
+                if let Event::Accepted { dt, .. } = event {
+                    dts.push(*dt);
+                }
+                None
+            },
+        )
+        .expect("should solve");
+
+        assert!(dts.iter().all(|dt| *dt <= 0.25));
+        assert!(dts.iter().any(|dt| *dt > 1e-2));
+    }
+
+    #[test]
+    fn errors_when_step_cannot_shrink_further() {
+        let config = Config {
+            abs_tol: 1e-14,
+            rel_tol: 0.0,
+            ..Config::new(0.5, 0.5, 1.0)
+        };
+
+        let result = solve_unobserved(
+            &DecayModel { k: 1.0 },
+            &ValueProblem::default(),
+            initial(),
+            1.0,
+            &config,
+        );
+
+        assert!(matches!(result, Err(Error::StepSizeTooSmall)));
+    }
+
+    #[test]
+    fn rejected_final_sliver_below_min_step_is_retried() {
+        // Steps stay at 0.3, leaving a final sliver of 0.05 < min_step.
+        let config = Config {
+            abs_tol: 1e-3,
+            rel_tol: 1e-3,
+            ..Config::new(0.3, 0.1, 0.3)
+        };
+        let model = KickedDecayModel {
+            kick_after: 0.92,
+            kicked: Cell::new(false),
+        };
+
+        let mut rejected = Vec::new();
+        let solution = solve(
+            &model,
+            &ValueProblem::default(),
+            initial(),
+            0.95,
+            &config,
+            |event: &Event<Input, Output, f64>| {
+                if let Event::Rejected { dt, .. } = event {
+                    rejected.push(*dt);
+                }
+                None
+            },
+        )
+        .expect("a rejected sliver is retried, not treated as too small");
+
+        assert_eq!(solution.status, Status::Complete);
+        assert_eq!(rejected.len(), 1);
+        assert_relative_eq!(rejected[0], 0.05, epsilon = 1e-12);
+
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(last.input.time, 0.95, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn stops_at_max_steps() {
+        let config = Config {
+            max_steps: 3,
+            ..Config::new(0.01, 1e-12, 0.01)
+        };
+
+        let solution = solve_unobserved(
+            &DecayModel { k: 1.0 },
+            &ValueProblem::default(),
+            initial(),
+            1.0,
+            &config,
+        )
+        .expect("should return partial solution");
+
+        assert_eq!(solution.status, Status::MaxSteps);
+        assert_eq!(solution.steps, 3);
+        assert_relative_eq!(solution.elapsed, 0.03, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn observer_can_stop_early() {
+        let observer = |event: &Event<Input, Output, f64>| {
+            matches!(event, Event::Accepted { step: 2, .. }).then_some(Action::StopEarly)
+        };
+
+        let solution = solve(
+            &DecayModel { k: 1.0 },
+            &ValueProblem::default(),
+            initial(),
+            10.0,
+            &tight_config(),
+            observer,
+        )
+        .expect("should stop early");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.steps, 2);
+        assert_eq!(solution.history.len(), 3);
+    }
+
+    #[test]
+    fn zero_span_returns_initial() {
+        let solution = solve_unobserved(
+            &DecayModel { k: 1.0 },
+            &ValueProblem::default(),
+            initial(),
+            0.0,
+            &tight_config(),
+        )
+        .expect("should return initial");
+
+        assert_eq!(solution.status, Status::Complete);
+        assert_eq!(solution.steps, 0);
+        assert_eq!(solution.history.len(), 1);
+    }
+
+    #[test]
+    fn rejects_invalid_span_and_config() {
+        let model = DecayModel { k: 1.0 };
+        let problem = ValueProblem::default();
+
+        let result = solve_unobserved(&model, &problem, initial(), -1.0, &tight_config());
+        assert!(matches!(result, Err(Error::InvalidSpan)));
+
+        let config = Config::new(2.0, 1e-9, 1.0);
+        let result = solve_unobserved(&model, &problem, initial(), 1.0, &config);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidConfig(ConfigError::Steps))
+        ));
+    }
+}
@@ -0,0 +1,159 @@
+use std::ops::Mul;
+
+use thiserror::Error;
+
+/// Configuration for the Dormand–Prince solver.
+///
+/// Step sizes use the problem's `Delta` type, so dimensioned deltas like
+/// `uom::Time` work as well as plain `f64`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config<D> {
+    /// Size of the first attempted step.
+    pub initial_step: D,
+    /// Smallest step the controller may take before giving up.
+    pub min_step: D,
+    /// Largest step the controller may take.
+    pub max_step: D,
+    /// Absolute tolerance passed to [`ErrorNorm::error_norm`].
+    ///
+    /// [`ErrorNorm::error_norm`]: twine_core::ErrorNorm::error_norm
+    pub abs_tol: f64,
+    /// Relative tolerance passed to [`ErrorNorm::error_norm`].
+    ///
+    /// [`ErrorNorm::error_norm`]: twine_core::ErrorNorm::error_norm
+    pub rel_tol: f64,
+    /// Maximum number of attempted steps, accepted or rejected.
+    pub max_steps: usize,
+    /// Safety factor applied to every proposed step size.
+    pub safety: f64,
+    /// Smallest factor by which a step may shrink.
+    pub min_factor: f64,
+    /// Largest factor by which a step may grow.
+    pub max_factor: f64,
+}
+
+/// Errors that can occur when validating a Dormand–Prince config.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("steps must satisfy 0 < min_step <= initial_step <= max_step")]
+    Steps,
+
+    #[error("tolerances must be finite, non-negative, and not both zero")]
+    Tolerances,
+
+    #[error("safety must be in (0, 1]")]
+    Safety,
+
+    #[error("factors must satisfy 0 < min_factor < 1 < max_factor")]
+    Factors,
+}
+
+impl<D> Config<D> {
+    /// Creates a config with the given step limits and default tolerances.
+    ///
+    /// Defaults are `abs_tol = 1e-6`, `rel_tol = 1e-3`, `max_steps = 100_000`,
+    /// `safety = 0.9`, `min_factor = 0.2`, and `max_factor = 10.0`.
+    pub fn new(initial_step: D, min_step: D, max_step: D) -> Self {
+        Self {
+            initial_step,
+            min_step,
+            max_step,
+            abs_tol: 1e-6,
+            rel_tol: 1e-3,
+            max_steps: 100_000,
+            safety: 0.9,
+            min_factor: 0.2,
+            max_factor: 10.0,
+        }
+    }
+}
+
+impl<D> Config<D>
+where
+    D: Clone + PartialOrd + Mul<f64, Output = D>,
+{
+    /// Validates step limits, tolerances, and controller factors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any setting is out of range.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let zero = self.max_step.clone() * 0.0;
+        if !(self.min_step > zero
+            && self.min_step <= self.initial_step
+            && self.initial_step <= self.max_step)
+        {
+            return Err(ConfigError::Steps);
+        }
+
+        let tol_ok = |tol: f64| tol.is_finite() && tol >= 0.0;
+        if !tol_ok(self.abs_tol)
+            || !tol_ok(self.rel_tol)
+            || (self.abs_tol == 0.0 && self.rel_tol == 0.0)
+        {
+            return Err(ConfigError::Tolerances);
+        }
+
+        if !(self.safety > 0.0 && self.safety <= 1.0) {
+            return Err(ConfigError::Safety);
+        }
+
+        if !(self.min_factor > 0.0
+            && self.min_factor < 1.0
+            && self.max_factor > 1.0
+            && self.max_factor.is_finite())
+        {
+            return Err(ConfigError::Factors);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_is_valid() {
+        assert!(Config::new(0.01, 1e-9, 1.0).validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_unordered_steps() {
+        assert_eq!(
+            Config::new(2.0, 1e-9, 1.0).validate(),
+            Err(ConfigError::Steps)
+        );
+        assert_eq!(
+            Config::new(0.1, 0.0, 1.0).validate(),
+            Err(ConfigError::Steps)
+        );
+        assert_eq!(
+            Config::new(0.1, f64::NAN, 1.0).validate(),
+            Err(ConfigError::Steps)
+        );
+    }
+
+    #[test]
+    fn rejects_bad_tolerances_and_factors() {
+        let config = Config {
+            abs_tol: 0.0,
+            rel_tol: 0.0,
+            ..Config::new(0.1, 1e-9, 1.0)
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Tolerances));
+
+        let config = Config {
+            safety: 1.5,
+            ..Config::new(0.1, 1e-9, 1.0)
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Safety));
+
+        let config = Config {
+            max_factor: 0.5,
+            ..Config::new(0.1, 1e-9, 1.0)
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Factors));
+    }
+}
@@ -0,0 +1,101 @@
+//! PI step-size controller.
+//!
+//! Follows the stabilized controller of Hairer, Nørsett & Wanner (II.4):
+//! the proposed factor depends on both the current and the previous accepted
+//! error, which damps oscillating step sizes compared to a plain I controller.
+
+use super::Config;
+
+/// Exponent on the previous error.
+const BETA: f64 = 0.04;
+
+/// Exponent on the current error, `1/5 - 0.75 * BETA` for a fifth-order method.
+const ALPHA: f64 = 0.2 - 0.75 * BETA;
+
+/// Smallest previous error remembered, to keep the factor bounded.
+const MIN_PREV_ERROR: f64 = 1e-4;
+
+/// Proposes step-size factors from scaled error norms.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Controller {
+    safety: f64,
+    min_factor: f64,
+    max_factor: f64,
+    prev_error: f64,
+    rejected_last: bool,
+}
+
+impl Controller {
+    pub(super) fn new<D>(config: &Config<D>) -> Self {
+        Self {
+            safety: config.safety,
+            min_factor: config.min_factor,
+            max_factor: config.max_factor,
+            prev_error: 1.0,
+            rejected_last: false,
+        }
+    }
+
+    /// Returns the factor for the step after an accepted step.
+    ///
+    /// The step is not allowed to grow immediately after a rejection.
+    pub(super) fn accept(&mut self, error: f64) -> f64 {
+        let factor = if error > 0.0 {
+            self.safety * error.powf(-ALPHA) * self.prev_error.powf(BETA)
+        } else {
+            self.max_factor
+        };
+        let factor = factor.clamp(self.min_factor, self.max_factor);
+        let factor = if self.rejected_last {
+            factor.min(1.0)
+        } else {
+            factor
+        };
+
+        self.prev_error = error.max(MIN_PREV_ERROR);
+        self.rejected_last = false;
+        factor
+    }
+
+    /// Returns the factor for retrying a rejected step.
+    pub(super) fn reject(&mut self, error: f64) -> f64 {
+        self.rejected_last = true;
+        if error.is_finite() {
+            (self.safety * error.powf(-0.2)).clamp(self.min_factor, 1.0)
+        } else {
+            self.min_factor
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn controller() -> Controller {
+        Controller::new(&Config::new(0.1, 1e-9, 1.0))
+    }
+
+    #[test]
+    fn small_errors_grow_the_step() {
+        let mut controller = controller();
+        assert!(controller.accept(1e-3) > 1.0);
+        assert!((controller.accept(0.0) - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rejections_shrink_the_step() {
+        let mut controller = controller();
+        let factor = controller.reject(100.0);
+        assert!((0.2..1.0).contains(&factor));
+        assert!((controller.reject(f64::NAN) - 0.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn no_growth_right_after_rejection() {
+        let mut controller = controller();
+        controller.reject(2.0);
+        assert!(controller.accept(1e-6) <= 1.0);
+        assert!(controller.accept(1e-6) > 1.0);
+    }
+}
@@ -0,0 +1,32 @@
+use std::error::Error as StdError;
+
+use super::ConfigError;
+
+/// Errors that can occur during Dormand–Prince integration.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("invalid config: {0}")]
+    InvalidConfig(#[from] ConfigError),
+
+    #[error("span must be non-negative")]
+    InvalidSpan,
+
+    #[error("step size fell below min_step without meeting tolerances")]
+    StepSizeTooSmall,
+
+    #[error("model error: {0}")]
+    Model(#[source] Box<dyn StdError + Send + Sync>),
+
+    #[error("problem error: {0}")]
+    Problem(#[source] Box<dyn StdError + Send + Sync>),
+}
+
+impl Error {
+    pub(crate) fn model<E: StdError + Send + Sync + 'static>(err: E) -> Self {
+        Self::Model(Box::new(err))
+    }
+
+    pub(crate) fn problem<E: StdError + Send + Sync + 'static>(err: E) -> Self {
+        Self::Problem(Box::new(err))
+    }
+}
@@ -0,0 +1,46 @@
+use twine_core::Snapshot;
+
+/// Event emitted by the Dormand–Prince solver.
+#[derive(Debug, Clone)]
+pub enum Event<I, O, D> {
+    /// The initial state before any integration.
+    Initial {
+        /// Snapshot of the initial model input and output.
+        snapshot: Snapshot<I, O>,
+    },
+
+    /// A step that met the tolerances and was accepted.
+    Accepted {
+        /// The accepted step number, starting at 1.
+        step: usize,
+        /// Total delta integrated, including this step.
+        elapsed: D,
+        /// Size of this step.
+        dt: D,
+        /// Scaled error norm of this step (at most 1).
+        error: f64,
+        /// Snapshot of the model input and output after this step.
+        snapshot: Snapshot<I, O>,
+    },
+
+    /// A step that exceeded the tolerances and will be retried smaller.
+    Rejected {
+        /// Total delta integrated before this attempt.
+        elapsed: D,
+        /// Size of the rejected step.
+        dt: D,
+        /// Scaled error norm of the attempt (greater than 1 or non-finite).
+        error: f64,
+    },
+}
+
+impl<I, O, D> Event<I, O, D> {
+    /// Returns the snapshot for initial and accepted events.
+    #[must_use]
+    pub fn snapshot(&self) -> Option<&Snapshot<I, O>> {
+        match self {
+            Event::Initial { snapshot } | Event::Accepted { snapshot, .. } => Some(snapshot),
+            Event::Rejected { .. } => None,
+        }
+    }
+}
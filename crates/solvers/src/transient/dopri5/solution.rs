@@ -0,0 +1,38 @@
+use twine_core::Snapshot;
+
+use crate::Stats;
+
+/// Indicates how the solver terminated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Integrated over the full span.
+    Complete,
+
+    /// Reached the maximum number of attempted steps before the end of the span.
+    MaxSteps,
+
+    /// Stopped early due to an observer action.
+    StoppedByObserver,
+}
+
+/// The result of a Dormand–Prince integration.
+#[derive(Debug, Clone)]
+pub struct Solution<I, O, D> {
+    /// How the solver terminated.
+    pub status: Status,
+
+    /// History of snapshots from each accepted step (including initial state).
+    pub history: Vec<Snapshot<I, O>>,
+
+    /// Number of accepted steps.
+    pub steps: usize,
+
+    /// Number of rejected step attempts.
+    pub rejected: usize,
+
+    /// Model evaluations and time spent, including rejected attempts.
+    pub stats: Stats,
+
+    /// Total delta integrated.
+    pub elapsed: D,
+}
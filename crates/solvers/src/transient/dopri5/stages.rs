@@ -0,0 +1,85 @@
+//! Stage evaluation for a single Dormand–Prince step attempt.
+
+use std::ops::Mul;
+
+use twine_core::{DerivativeOf, Model, OdeProblem, Snapshot, StepIntegrable};
+
+use super::{
+    Error,
+    tableau::{A, B, B_HAT, C},
+};
+
+/// The two solution estimates produced by one step attempt.
+pub(super) struct Attempt<S> {
+    /// Fifth-order estimate, propagated if the step is accepted.
+    pub(super) high: S,
+    /// Embedded fourth-order estimate, used only for the error norm.
+    pub(super) low: S,
+}
+
+/// Evaluates all stages of a step of size `dt` from `current`.
+///
+/// Calls the model six times (stages 2 through 7). The first stage reuses the
+/// output already stored in `current`.
+pub(super) fn attempt<M, P>(
+    model: &M,
+    problem: &P,
+    current: &Snapshot<M::Input, M::Output>,
+    dt: &P::Delta,
+) -> Result<Attempt<P::State>, Error>
+where
+    M: Model,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone,
+{
+    let base = &current.input;
+    let state = problem.state(base).map_err(Error::problem)?;
+
+    let mut derivatives = Vec::with_capacity(B.len());
+    derivatives.push(
+        problem
+            .derivative(base, &current.output)
+            .map_err(Error::problem)?,
+    );
+
+    for (weights, &c) in A.iter().zip(&C) {
+        let stage_state = combine(&state, &derivatives, weights, dt);
+        let delta = dt.clone() * c;
+        let input = problem
+            .build_input(base, &stage_state, &delta)
+            .map_err(Error::problem)?;
+        let output = model.call(&input).map_err(Error::model)?;
+        derivatives.push(
+            problem
+                .derivative(&input, &output)
+                .map_err(Error::problem)?,
+        );
+    }
+
+    Ok(Attempt {
+        high: combine(&state, &derivatives, &B, dt),
+        low: combine(&state, &derivatives, &B_HAT, dt),
+    })
+}
+
+/// Returns `state + dt * sum(weights[i] * derivatives[i])`.
+///
+/// The sum is applied as consecutive [`StepIntegrable::step`] calls, skipping
+/// zero weights, so states only need the usual stepping contract.
+fn combine<S, D>(state: &S, derivatives: &[S::Derivative], weights: &[f64], dt: &D) -> S
+where
+    S: StepIntegrable<D>,
+    S::Derivative: Clone,
+    D: Clone + Mul<f64, Output = D>,
+{
+    let mut result: Option<S> = None;
+    for (derivative, &weight) in derivatives.iter().zip(weights) {
+        if weight == 0.0 {
+            continue;
+        }
+        let from = result.as_ref().unwrap_or(state);
+        result = Some(from.step(derivative.clone(), dt.clone() * weight));
+    }
+    result.unwrap_or_else(|| state.step(derivatives[0].clone(), dt.clone() * 0.0))
+}
@@ -0,0 +1,77 @@
+//! Butcher tableau for the Dormand–Prince 5(4) method.
+//!
+//! Coefficients are from Dormand & Prince (1980), as tabulated in
+//! Hairer, Nørsett & Wanner, *Solving Ordinary Differential Equations I*.
+
+/// Stage offsets as fractions of the step, for stages 2 through 7.
+pub(super) const C: [f64; 6] = [1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+
+/// Stage weights for stages 2 through 7, applied to the preceding derivatives.
+pub(super) const A: [&[f64]; 6] = [
+    &[1.0 / 5.0],
+    &[3.0 / 40.0, 9.0 / 40.0],
+    &[44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0],
+    &[
+        19372.0 / 6561.0,
+        -25360.0 / 2187.0,
+        64448.0 / 6561.0,
+        -212.0 / 729.0,
+    ],
+    &[
+        9017.0 / 3168.0,
+        -355.0 / 33.0,
+        46732.0 / 5247.0,
+        49.0 / 176.0,
+        -5103.0 / 18656.0,
+    ],
+    &[
+        35.0 / 384.0,
+        0.0,
+        500.0 / 1113.0,
+        125.0 / 192.0,
+        -2187.0 / 6784.0,
+        11.0 / 84.0,
+    ],
+];
+
+/// Fifth-order solution weights (the propagated solution).
+pub(super) const B: [f64; 7] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+    0.0,
+];
+
+/// Embedded fourth-order solution weights (for the error estimate).
+pub(super) const B_HAT: [f64; 7] = [
+    5179.0 / 57600.0,
+    0.0,
+    7571.0 / 16695.0,
+    393.0 / 640.0,
+    -92097.0 / 339_200.0,
+    187.0 / 2100.0,
+    1.0 / 40.0,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn rows_sum_to_stage_offsets() {
+        for (row, c) in A.iter().zip(C) {
+            assert_relative_eq!(row.iter().sum::<f64>(), c, epsilon = 1e-14);
+        }
+    }
+
+    #[test]
+    fn weights_sum_to_one() {
+        assert_relative_eq!(B.iter().sum::<f64>(), 1.0, epsilon = 1e-14);
+        assert_relative_eq!(B_HAT.iter().sum::<f64>(), 1.0, epsilon = 1e-14);
+    }
+}
@@ -18,6 +18,15 @@
 //!     println!("t={}: {:?}", snapshot.input, snapshot.output);
 //! }
 //! ```
+//!
+//! When the problem implements [`IndependentVariable`], [`solve_until`]
+//! integrates up to a target value instead of a step count, shortening the
+//! final step to land on it:
+//!
+//! ```ignore
+//! let solution = euler::solve_until_unobserved(&model, &problem, initial_input, dt, end)?;
+//! assert_eq!(solution.end, end);
+//! ```
 
 mod action;
 mod error;
@@ -29,7 +38,18 @@ pub use error::Error;
 pub use event::Event;
 pub use solution::{Solution, Status};
 
-use twine_core::{Model, Observer, OdeProblem, Snapshot, StepIntegrable};
+use std::ops::{Mul, Sub};
+
+use twine_core::{
+    IndependentVariable, Model, Observer, OdeProblem, Snapshot, StepIntegrable, ZeroCrossing,
+};
+
+use crate::stats::Counted;
+
+use super::{
+    crossing,
+    fixed_step::{self, UntilResult},
+};
 
 /// Integrates an ODE problem using forward Euler.
 ///
@@ -61,7 +81,7 @@ pub fn solve<M, P, Obs>(
     initial: M::Input,
     dt: P::Delta,
     steps: usize,
-    mut observer: Obs,
+    observer: Obs,
 ) -> Result<Solution<M::Input, M::Output>, Error>
 where
     M: Model,
@@ -71,74 +91,16 @@ where
     P::Delta: Clone,
     Obs: Observer<Event<M::Input, M::Output>, Action>,
 {
-    // Evaluate initial state.
-    let initial_output = model.call(&initial).map_err(Error::model)?;
-    let initial_snapshot = Snapshot::new(initial, initial_output);
-
-    let mut history = Vec::with_capacity(steps + 1);
-    history.push(initial_snapshot.clone());
-
-    // Emit initial event.
-    let event = Event {
-        step: 0,
-        snapshot: initial_snapshot.clone(),
-    };
-    if let Some(Action::StopEarly) = observer.observe(&event) {
-        return Ok(Solution {
-            status: Status::StoppedByObserver,
-            history,
-            steps: 0,
-        });
-    }
-
-    let mut current = initial_snapshot;
-
-    for step in 1..=steps {
-        // Extract state and compute derivative.
-        let state = problem.state(&current.input).map_err(Error::problem)?;
-        let derivative = problem
-            .derivative(&current.input, &current.output)
-            .map_err(Error::problem)?;
-
-        // Step state forward.
-        let next_state = state.step(derivative, dt.clone());
-
-        // Build and finalize next input.
-        let next_input = problem
-            .build_input(&current.input, &next_state, &dt)
-            .map_err(Error::problem)?;
-        let next_input = problem
-            .finalize_step(next_input, &current.input, &current.output, &dt)
-            .map_err(Error::problem)?;
-
-        // Evaluate model at next state.
-        let next_output = model.call(&next_input).map_err(Error::model)?;
-        let next_snapshot = Snapshot::new(next_input, next_output);
-
-        history.push(next_snapshot.clone());
-
-        // Emit event to observer.
-        let event = Event {
-            step,
-            snapshot: next_snapshot.clone(),
-        };
-
-        if let Some(Action::StopEarly) = observer.observe(&event) {
-            return Ok(Solution {
-                status: Status::StoppedByObserver,
-                history,
-                steps: step,
-            });
-        }
-
-        current = next_snapshot;
-    }
-
-    Ok(Solution {
-        status: Status::Complete,
-        history,
+    let model = &Counted::new(model);
+    fixed_step::integrate(
+        model,
+        problem,
+        initial,
+        dt,
         steps,
-    })
+        observer,
+        |current, dt| advance(problem, current, dt),
+    )
 }
 
 /// Integrates an ODE problem using forward Euler without observation.
@@ -165,6 +127,133 @@ where
     solve(model, problem, initial, dt, steps, ())
 }
 
+/// Integrates an ODE problem using forward Euler until the independent
+/// variable reaches `end`.
+///
+/// Steps of size `dt` are taken from the initial input, and the final step is
+/// shortened so the last snapshot lands exactly on `end`. The value actually
+/// reached, as reported by [`IndependentVariable::independent_variable`], is
+/// recorded in [`Solution::end`].
+///
+/// If the initial input is already at or past `end`, no steps are taken.
+///
+/// # Observer
+///
+/// The observer receives the same [`Event`]s as in [`solve`].
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidStep`] if `dt` is not positive,
+/// [`Error::Stalled`] if a step does not advance the independent variable, or
+/// an error if the model or problem returns an error at any point.
+pub fn solve_until<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+    observer: Obs,
+) -> UntilResult<M, P>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+{
+    let model = &Counted::new(model);
+    fixed_step::integrate_until(model, problem, initial, dt, end, observer, |current, dt| {
+        advance(problem, current, dt)
+    })
+}
+
+/// Integrates an ODE problem using forward Euler until `end` without observation.
+///
+/// This is a convenience wrapper around [`solve_until`] that discards events.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidStep`] if `dt` is not positive,
+/// [`Error::Stalled`] if a step does not advance the independent variable, or
+/// an error if the model or problem returns an error at any point.
+pub fn solve_until_unobserved<M, P>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+) -> UntilResult<M, P>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+{
+    solve_until(model, problem, initial, dt, end, ())
+}
+
+/// Integrates an ODE problem using forward Euler until `end`, locating zero
+/// crossings of the problem's event functions.
+///
+/// Stepping matches [`solve_until`]. After each step, event functions that
+/// changed sign are located within the step and reported as
+/// [`crossing::Event::Crossing`]; see the [`crossing`] module for how the
+/// observer's response stops, resets, or continues the integration.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidStep`] if `dt` is not positive,
+/// [`Error::Stalled`] if a step does not advance the independent variable, or
+/// an error if the model or problem returns an error at any point, including while locating a
+/// crossing.
+pub fn solve_with_crossings<M, P, Obs, const K: usize>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+    observer: Obs,
+) -> UntilResult<M, P>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>
+        + ZeroCrossing<K, Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+    Obs: Observer<crossing::Event<M::Input, M::Output>, crossing::Action>,
+{
+    let model = &Counted::new(model);
+    crossing::integrate(model, problem, initial, dt, end, observer, |current, dt| {
+        advance(problem, current, dt)
+    })
+}
+
+/// Computes the next (unfinalized) input with a forward Euler step.
+pub(super) fn advance<P>(
+    problem: &P,
+    current: &Snapshot<P::Input, P::Output>,
+    dt: &P::Delta,
+) -> Result<P::Input, Error>
+where
+    P: OdeProblem,
+    P::Delta: Clone,
+{
+    // Extract state and compute derivative.
+    let state = problem.state(&current.input).map_err(Error::problem)?;
+    let derivative = problem
+        .derivative(&current.input, &current.output)
+        .map_err(Error::problem)?;
+
+    // Step state forward and build next input.
+    let next_state = state.step(derivative, dt.clone());
+    problem
+        .build_input(&current.input, &next_state, dt)
+        .map_err(Error::problem)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,6 +346,53 @@ mod tests {
         }
     }
 
+    impl IndependentVariable for MotionProblem {
+        fn independent_variable(&self, input: &Self::Input) -> Result<Self::Delta, Self::Error> {
+            Ok(input.time)
+        }
+    }
+
+    /// Motion problem whose `build_input` forgets to advance the time.
+    struct FrozenClock;
+
+    impl OdeProblem for FrozenClock {
+        type Input = Input;
+        type Output = Output;
+        type Delta = f64;
+        type State = Position;
+        type Error = Infallible;
+
+        fn state(&self, input: &Self::Input) -> Result<Self::State, Self::Error> {
+            Ok(input.position)
+        }
+
+        fn derivative(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<DerivativeOf<Self::State, Self::Delta>, Self::Error> {
+            Ok(output.velocity)
+        }
+
+        fn build_input(
+            &self,
+            base: &Self::Input,
+            state: &Self::State,
+            _delta: &Self::Delta,
+        ) -> Result<Self::Input, Self::Error> {
+            Ok(Input {
+                position: *state,
+                time: base.time,
+            })
+        }
+    }
+
+    impl IndependentVariable for FrozenClock {
+        fn independent_variable(&self, input: &Self::Input) -> Result<Self::Delta, Self::Error> {
+            Ok(input.time)
+        }
+    }
+
     // --- Tests ---
 
     #[test]
@@ -417,4 +553,82 @@ mod tests {
 
         assert_eq!(step_values, vec![0, 1, 2, 3, 4]);
     }
+
+    #[test]
+    fn solve_until_shortens_final_step() {
+        let model = ConstantVelocityModel { velocity: 2.0 };
+        let initial = Input {
+            position: Position(0.0),
+            time: 0.0,
+        };
+
+        let solution = solve_until_unobserved(&model, &MotionProblem, initial, 0.3, 1.0)
+            .expect("should solve");
+
+        // Steps of 0.3, 0.3, 0.3, then 0.1 to land on t = 1.0.
+        assert_eq!(solution.status, Status::Complete);
+        assert_eq!(solution.steps, 4);
+        assert_relative_eq!(solution.end, 1.0);
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(last.input.time, 1.0);
+        assert_relative_eq!(last.input.position.0, 2.0);
+    }
+
+    #[test]
+    fn solve_until_does_not_take_sliver_steps() {
+        let model = ConstantVelocityModel { velocity: 1.0 };
+        let initial = Input {
+            position: Position(0.0),
+            time: 0.0,
+        };
+
+        // Accumulating 0.1 ten times falls just short of 1.0 in floating point.
+        let solution = solve_until_unobserved(&model, &MotionProblem, initial, 0.1, 1.0)
+            .expect("should solve");
+
+        assert_eq!(solution.steps, 10);
+        assert_relative_eq!(solution.end, 1.0);
+    }
+
+    #[test]
+    fn solve_until_past_end_returns_initial() {
+        let model = ConstantVelocityModel { velocity: 1.0 };
+        let initial = Input {
+            position: Position(0.0),
+            time: 2.0,
+        };
+
+        let solution = solve_until_unobserved(&model, &MotionProblem, initial, 0.1, 1.0)
+            .expect("should return initial");
+
+        assert_eq!(solution.steps, 0);
+        assert_eq!(solution.history.len(), 1);
+        assert_relative_eq!(solution.end, 2.0);
+    }
+
+    #[test]
+    fn solve_until_rejects_non_positive_dt() {
+        let model = ConstantVelocityModel { velocity: 1.0 };
+        let initial = Input {
+            position: Position(0.0),
+            time: 0.0,
+        };
+
+        let result = solve_until_unobserved(&model, &MotionProblem, initial, 0.0, 1.0);
+
+        assert!(matches!(result, Err(Error::InvalidStep)));
+    }
+
+    #[test]
+    fn solve_until_rejects_stuck_time() {
+        let model = ConstantVelocityModel { velocity: 1.0 };
+        let initial = Input {
+            position: Position(0.0),
+            time: 0.0,
+        };
+
+        let result = solve_until_unobserved(&model, &FrozenClock, initial, 0.1, 1.0);
+
+        assert!(matches!(result, Err(Error::Stalled)));
+    }
 }
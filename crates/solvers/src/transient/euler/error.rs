@@ -1,11 +1,32 @@
 use std::error::Error as StdError;
 
-/// Errors that can occur during Euler integration.
+/// Errors that can occur during fixed-step integration.
+///
+/// Shared by [`euler`](super) and [`rk4`](crate::transient::rk4), and wrapped
+/// by the error type of the implicit integrators.
+///
+/// The enum is non-exhaustive so that new ways for a step to fail can be
+/// added without breaking downstream matches.
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
+    /// The step size is not positive.
+    ///
+    /// Produced by the `solve_until` and `solve_with_crossings` functions.
+    #[error("dt must be positive")]
+    InvalidStep,
+
+    /// A step did not move the independent variable closer to the end value.
+    ///
+    /// Produced by the `solve_until` and `solve_with_crossings` functions.
+    #[error("step did not advance the independent variable toward the end")]
+    Stalled,
+
+    /// The model returned an error.
     #[error("model error: {0}")]
     Model(#[source] Box<dyn StdError + Send + Sync>),
 
+    /// The problem returned an error.
     #[error("problem error: {0}")]
     Problem(#[source] Box<dyn StdError + Send + Sync>),
 }
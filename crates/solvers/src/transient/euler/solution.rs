@@ -1,5 +1,7 @@
 use twine_core::Snapshot;
 
+use crate::Stats;
+
 /// Indicates how the solver terminated.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -11,8 +13,11 @@ pub enum Status {
 }
 
 /// The result of an Euler integration.
+///
+/// The `D` parameter is the type of the independent variable recorded in
+/// [`end`](Self::end). Fixed-count solves don't track it and use `()`.
 #[derive(Debug, Clone)]
-pub struct Solution<I, O> {
+pub struct Solution<I, O, D = ()> {
     /// How the solver terminated.
     pub status: Status,
 
@@ -21,4 +26,25 @@ pub struct Solution<I, O> {
 
     /// Number of integration steps completed.
     pub steps: usize,
+
+    /// Model evaluations and time spent, including intermediate stages.
+    pub stats: Stats,
+
+    /// Independent variable of the last snapshot.
+    ///
+    /// Populated by `solve_until`, which reports the value actually reached.
+    pub end: D,
+}
+
+impl<I, O> Solution<I, O> {
+    /// Attaches the independent variable reached by the last snapshot.
+    pub(crate) fn with_end<D>(self, end: D) -> Solution<I, O, D> {
+        Solution {
+            status: self.status,
+            history: self.history,
+            steps: self.steps,
+            stats: self.stats,
+            end,
+        }
+    }
 }
@@ -0,0 +1,290 @@
+//! Shared driver for explicit fixed-step integrators.
+//!
+//! Euler and RK4 differ only in how they produce the next input from the
+//! current snapshot. Everything else — evaluating the initial state, calling
+//! `finalize_step` on accepted steps, recording history, and consulting the
+//! observer — lives here so the integrators behave identically.
+//!
+//! Two schedules decide how many steps to take and how large each one is:
+//! [`integrate`] takes a fixed number of equal steps, and [`integrate_until`]
+//! steps until the independent variable reaches a target, shortening the
+//! final step to land on it.
+//!
+//! The drivers are generic over the integrator's error type, so integrators
+//! with failures of their own (such as the implicit Newton solve) can report
+//! them from `advance` while sharing every [`Error`] raised here.
+
+use std::{
+    cmp::Ordering,
+    ops::{Mul, Sub},
+};
+
+use twine_core::{IndependentVariable, Model, Observer, OdeProblem, Snapshot};
+
+use crate::stats::Counted;
+
+use super::euler::{Action, Error, Event, Solution, Status};
+
+/// Relative slack for merging a final sliver into the previous step.
+///
+/// Without it, rounding in the independent variable can leave a remainder on
+/// the order of machine epsilon, which would become a pointless extra step.
+const SLIVER: f64 = 1e-9;
+
+/// Result of the `solve_until` family, which records the end value reached.
+pub(crate) type UntilResult<M, P, E = Error> =
+    Result<Solution<<M as Model>::Input, <M as Model>::Output, <P as OdeProblem>::Delta>, E>;
+
+/// Integrates `steps` fixed steps of size `dt`.
+///
+/// The `advance` closure receives the current snapshot and step size and
+/// returns the unfinalized next input. The driver then applies
+/// [`OdeProblem::finalize_step`], calls the model, and emits an [`Event`].
+/// The closure should evaluate any stages through `model` so they are counted.
+pub(crate) fn integrate<M, P, Obs, F, E>(
+    model: &Counted<'_, M>,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    steps: usize,
+    observer: Obs,
+    advance: F,
+) -> Result<Solution<M::Input, M::Output>, E>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+    F: FnMut(&Snapshot<M::Input, M::Output>, &P::Delta) -> Result<M::Input, E>,
+    E: From<Error>,
+{
+    let mut remaining = steps;
+    let schedule = |_: &Snapshot<M::Input, M::Output>| {
+        Ok::<_, Error>((remaining > 0).then(|| {
+            remaining -= 1;
+            dt.clone()
+        }))
+    };
+
+    run(
+        model,
+        problem,
+        initial,
+        steps + 1,
+        schedule,
+        observer,
+        advance,
+    )
+}
+
+/// Integrates steps of size `dt` until the independent variable reaches `end`.
+///
+/// The final step is shortened so the last snapshot lands on `end`. If the
+/// initial input is already at or past `end`, no steps are taken.
+pub(crate) fn integrate_until<M, P, Obs, F, E>(
+    model: &Counted<'_, M>,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+    observer: Obs,
+    advance: F,
+) -> UntilResult<M, P, E>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+    F: FnMut(&Snapshot<M::Input, M::Output>, &P::Delta) -> Result<M::Input, E>,
+    E: From<Error>,
+{
+    let mut until = Until::new(problem, dt, end)?;
+    let schedule = |current: &Snapshot<M::Input, M::Output>| until.next(current);
+    let solution = run(model, problem, initial, 1, schedule, observer, advance)?;
+
+    let last = solution
+        .history
+        .last()
+        .expect("history always contains the initial snapshot");
+    let end = problem
+        .independent_variable(&last.input)
+        .map_err(Error::problem)?;
+
+    Ok(solution.with_end(end))
+}
+
+/// Step schedule for integrating until the independent variable reaches `end`.
+///
+/// Each call to [`Until::next`] measures the remaining distance from the
+/// current snapshot, so the schedule stays correct when a step is truncated
+/// (for example, at a zero crossing). A remainder within the sliver tolerance
+/// of `dt` is folded into the final step rather than taken on its own.
+///
+/// The remaining distance must shrink with every step. A problem whose
+/// independent variable does not advance would otherwise step forever.
+pub(super) struct Until<'a, P: OdeProblem> {
+    problem: &'a P,
+    dt: P::Delta,
+    end: P::Delta,
+    previous: Option<P::Delta>,
+}
+
+impl<'a, P> Until<'a, P>
+where
+    P: IndependentVariable,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+{
+    /// Creates the schedule, validating that `dt` is positive.
+    pub(super) fn new(problem: &'a P, dt: P::Delta, end: P::Delta) -> Result<Self, Error> {
+        let zero = dt.clone() * 0.0;
+        if dt <= zero || dt.partial_cmp(&zero).is_none() {
+            return Err(Error::InvalidStep);
+        }
+        Ok(Self {
+            problem,
+            dt,
+            end,
+            previous: None,
+        })
+    }
+
+    /// Returns the next step size, or `None` once `end` has been reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Stalled`] if the remaining distance did not shrink
+    /// since the previous call.
+    pub(super) fn next(
+        &mut self,
+        current: &Snapshot<P::Input, P::Output>,
+    ) -> Result<Option<P::Delta>, Error> {
+        let now = self
+            .problem
+            .independent_variable(&current.input)
+            .map_err(Error::problem)?;
+        let remaining = self.end.clone() - now;
+
+        if let Some(previous) = self.previous.replace(remaining.clone())
+            && remaining.partial_cmp(&previous) != Some(Ordering::Less)
+        {
+            return Err(Error::Stalled);
+        }
+
+        if remaining <= self.dt.clone() * SLIVER {
+            Ok(None)
+        } else if self.dt.clone() * (1.0 + SLIVER) >= remaining {
+            Ok(Some(remaining))
+        } else {
+            Ok(Some(self.dt.clone()))
+        }
+    }
+}
+
+/// Finalizes an accepted input and evaluates the model on it.
+pub(super) fn accept<M, P>(
+    model: &M,
+    problem: &P,
+    current: &Snapshot<M::Input, M::Output>,
+    next_input: M::Input,
+    dt: &P::Delta,
+) -> Result<Snapshot<M::Input, M::Output>, Error>
+where
+    M: Model,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+{
+    let next_input = problem
+        .finalize_step(next_input, &current.input, &current.output, dt)
+        .map_err(Error::problem)?;
+    let next_output = model.call(&next_input).map_err(Error::model)?;
+    Ok(Snapshot::new(next_input, next_output))
+}
+
+/// Runs the integration loop, taking steps until `schedule` returns `None`.
+fn run<M, P, Obs, S, F, E>(
+    model: &Counted<'_, M>,
+    problem: &P,
+    initial: M::Input,
+    capacity: usize,
+    mut schedule: S,
+    mut observer: Obs,
+    mut advance: F,
+) -> Result<Solution<M::Input, M::Output>, E>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+    S: FnMut(&Snapshot<M::Input, M::Output>) -> Result<Option<P::Delta>, Error>,
+    F: FnMut(&Snapshot<M::Input, M::Output>, &P::Delta) -> Result<M::Input, E>,
+    E: From<Error>,
+{
+    // Evaluate initial state.
+    let initial_output = model.call(&initial).map_err(Error::model)?;
+    let initial_snapshot = Snapshot::new(initial, initial_output);
+
+    let mut history = Vec::with_capacity(capacity);
+    history.push(initial_snapshot.clone());
+
+    // Emit initial event.
+    let event = Event {
+        step: 0,
+        snapshot: initial_snapshot.clone(),
+    };
+    let action = observer.observe(&event);
+    model.tally().observed(action.as_ref());
+    if let Some(Action::StopEarly) = action {
+        return Ok(Solution {
+            status: Status::StoppedByObserver,
+            history,
+            steps: 0,
+            stats: model.tally().stats(),
+            end: (),
+        });
+    }
+
+    let mut current = initial_snapshot;
+    let mut step = 0;
+
+    while let Some(dt) = schedule(&current)? {
+        step += 1;
+
+        // Advance, finalize, and evaluate the next state.
+        let next_input = advance(&current, &dt)?;
+        let next_snapshot = accept(model, problem, &current, next_input, &dt)?;
+
+        history.push(next_snapshot.clone());
+
+        // Emit event to observer.
+        let event = Event {
+            step,
+            snapshot: next_snapshot.clone(),
+        };
+
+        let action = observer.observe(&event);
+        model.tally().observed(action.as_ref());
+        if let Some(Action::StopEarly) = action {
+            return Ok(Solution {
+                status: Status::StoppedByObserver,
+                history,
+                steps: step,
+                stats: model.tally().stats(),
+                end: (),
+            });
+        }
+
+        current = next_snapshot;
+    }
+
+    Ok(Solution {
+        status: Status::Complete,
+        history,
+        steps: step,
+        stats: model.tally().stats(),
+        end: (),
+    })
+}
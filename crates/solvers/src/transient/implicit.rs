@@ -0,0 +1,135 @@
+//! Implicit stage solves shared by the backward Euler and BDF integrators.
+//!
+//! Both methods find the next state `y` from an equation of the form
+//!
+//! ```text
+//! y = base + f(t_{n+1}, y) * gamma
+//! ```
+//!
+//! where `base` combines states from previous steps and `gamma` is a multiple
+//! of `dt`. Written with [`StepIntegrable::step`], the residual is
+//!
+//! ```text
+//! r(y) = flatten(y) - flatten(base.step(f(y), gamma))
+//! ```
+//!
+//! which [`newton`] drives to zero over the flattened components of `y`, using
+//! a finite-difference Jacobian. The stage problem builds the input for the
+//! end of the step from each candidate `y`, and the stage model calls the
+//! model on it once. Both adapters return the wrapped problem's and model's
+//! own errors, so Newton reports them as problem and model errors directly.
+
+use std::marker::PhantomData;
+
+use twine_core::{EquationProblem, Flatten, Model, OdeProblem, Snapshot, StepIntegrable};
+
+use crate::{equation::newton, transient::euler};
+
+use super::backward_euler::Error;
+
+/// Solves the implicit stage equation and returns the unfinalized next input.
+///
+/// The Newton iteration starts from `base`, which is already a reasonable
+/// predictor for the next state.
+///
+/// # Errors
+///
+/// Returns [`Error::NotConverged`] if Newton hits its iteration limit,
+/// [`Error::Implicit`] for other Newton failures (such as a singular
+/// Jacobian), or the model or problem error raised during the solve.
+pub(super) fn solve<M, P, const N: usize>(
+    model: &M,
+    problem: &P,
+    current: &Snapshot<M::Input, M::Output>,
+    dt: &P::Delta,
+    base: &P::State,
+    gamma: P::Delta,
+    config: &newton::Config,
+) -> Result<M::Input, Error>
+where
+    M: Model,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone,
+    P::State: Flatten<N>,
+{
+    let stage_model = StageModel {
+        model,
+        len: PhantomData,
+    };
+    let stage_problem = StageProblem {
+        problem,
+        current,
+        dt,
+        base,
+        gamma,
+    };
+
+    match newton::solve_unobserved(&stage_model, &stage_problem, base.flatten(), config) {
+        Ok(solution) if solution.status == newton::Status::Converged => {
+            let (_, input) = solution.snapshot.input;
+            Ok(input)
+        }
+        Ok(solution) => Err(Error::NotConverged {
+            iters: solution.iters,
+        }),
+        Err(newton::Error::Model(err)) => Err(euler::Error::Model(err).into()),
+        Err(newton::Error::Problem(err)) => Err(euler::Error::Problem(err).into()),
+        Err(err) => Err(Error::Implicit(err)),
+    }
+}
+
+/// Model adapter that calls the model on the input built for a candidate.
+struct StageModel<'a, M, const N: usize> {
+    model: &'a M,
+    len: PhantomData<[f64; N]>,
+}
+
+impl<M: Model, const N: usize> Model for StageModel<'_, M, N> {
+    type Input = ([f64; N], M::Input);
+    type Output = M::Output;
+    type Error = M::Error;
+
+    fn call(&self, (_, input): &Self::Input) -> Result<Self::Output, Self::Error> {
+        self.model.call(input)
+    }
+}
+
+/// Equation problem adapter for the implicit stage residual.
+struct StageProblem<'a, P: OdeProblem> {
+    problem: &'a P,
+    current: &'a Snapshot<P::Input, P::Output>,
+    dt: &'a P::Delta,
+    /// Combined previous states, whose non-numeric components also carry over
+    /// to each candidate.
+    base: &'a P::State,
+    gamma: P::Delta,
+}
+
+impl<P, const N: usize> EquationProblem<N> for StageProblem<'_, P>
+where
+    P: OdeProblem,
+    P::Delta: Clone,
+    P::State: Flatten<N>,
+{
+    type Input = ([f64; N], P::Input);
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn input(&self, y: &[f64; N]) -> Result<Self::Input, Self::Error> {
+        let state = self.base.unflatten(y);
+        let input = self
+            .problem
+            .build_input(&self.current.input, &state, self.dt)?;
+        Ok((*y, input))
+    }
+
+    fn residuals(
+        &self,
+        (y, input): &Self::Input,
+        output: &Self::Output,
+    ) -> Result<[f64; N], Self::Error> {
+        let derivative = self.problem.derivative(input, output)?;
+        let next = self.base.step(derivative, self.gamma.clone()).flatten();
+        Ok(std::array::from_fn(|i| y[i] - next[i]))
+    }
+}
@@ -0,0 +1,532 @@
+//! Classic fourth-order Runge–Kutta solver for ODE problems.
+//!
+//! This module provides a fixed-step RK4 integrator for transient simulations.
+//! Each step evaluates the model at four stages and combines the derivatives:
+//!
+//! ```text
+//! k1 = f(t_n,          state_n)
+//! k2 = f(t_n + dt / 2, state_n + k1 * dt / 2)
+//! k3 = f(t_n + dt / 2, state_n + k2 * dt / 2)
+//! k4 = f(t_n + dt,     state_n + k3 * dt)
+//!
+//! state_{n+1} = state_n + (k1 + 2 k2 + 2 k3 + k4) * dt / 6
+//! ```
+//!
+//! The weighted sum is applied as four consecutive calls to
+//! [`StepIntegrable::step`], so states only need the same stepping contract
+//! that [`euler`](super::euler) uses. Intermediate stage inputs are built with
+//! [`OdeProblem::build_input`], while [`OdeProblem::finalize_step`] is only
+//! called on the accepted end-of-step input.
+//!
+//! The solver shares its [`Event`], [`Action`], [`Solution`], [`Status`], and
+//! [`Error`] types with Euler, so observers work with either integrator.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use twine_solvers::transient::rk4;
+//!
+//! let solution = rk4::solve_unobserved(&model, &problem, initial_input, dt, steps)?;
+//! ```
+
+pub use super::euler::{Action, Error, Event, Solution, Status};
+
+use std::ops::{Mul, Sub};
+
+use twine_core::{
+    DerivativeOf, IndependentVariable, Model, Observer, OdeProblem, Snapshot, StepIntegrable,
+    ZeroCrossing,
+};
+
+use crate::stats::Counted;
+
+use super::{
+    crossing,
+    fixed_step::{self, UntilResult},
+};
+
+/// Integrates an ODE problem using the classic fourth-order Runge–Kutta method.
+///
+/// # Algorithm
+///
+/// 1. Call the model with the initial input to get the initial snapshot.
+/// 2. For each step:
+///    - Compute the four stage derivatives, building an input and calling
+///      the model for each intermediate stage.
+///    - Step the state forward with the weighted stage derivatives.
+///    - Build the next input and finalize the step.
+///    - Call the model to get the next output.
+///    - Emit an `Event` to the observer.
+///    - If the observer returns `StopEarly`, terminate.
+/// 3. Return the solution with the full history.
+///
+/// Each step calls the model four times: three intermediate stages plus the
+/// accepted end-of-step input.
+///
+/// # Observer
+///
+/// The observer receives an [`Event`] after each accepted step (not for
+/// intermediate stages) and may return [`Action::StopEarly`] to terminate the
+/// simulation early.
+///
+/// # Errors
+///
+/// Returns an error if the model or problem returns an error at any point,
+/// including during intermediate stages.
+pub fn solve<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    steps: usize,
+    observer: Obs,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+{
+    let model = &Counted::new(model);
+    fixed_step::integrate(
+        model,
+        problem,
+        initial,
+        dt,
+        steps,
+        observer,
+        |current, dt| {
+            let next_state = advance(model, problem, current, dt)?;
+            problem
+                .build_input(&current.input, &next_state, dt)
+                .map_err(Error::problem)
+        },
+    )
+}
+
+/// Integrates an ODE problem using RK4 without observation.
+///
+/// This is a convenience wrapper around [`solve`] that discards events.
+///
+/// # Errors
+///
+/// Returns an error if the model or problem returns an error at any point.
+pub fn solve_unobserved<M, P>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    steps: usize,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone,
+{
+    solve(model, problem, initial, dt, steps, ())
+}
+
+/// Integrates an ODE problem using RK4 until the independent variable
+/// reaches `end`.
+///
+/// Behaves like [`euler::solve_until`](super::euler::solve_until): the final
+/// step is shortened to land exactly on `end`, and the value reached is
+/// recorded in [`Solution::end`].
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidStep`] if `dt` is not positive,
+/// [`Error::Stalled`] if a step does not advance the independent variable, or
+/// an error if the model or problem returns an error at any point.
+pub fn solve_until<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+    observer: Obs,
+) -> UntilResult<M, P>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+{
+    let model = &Counted::new(model);
+    fixed_step::integrate_until(model, problem, initial, dt, end, observer, |current, dt| {
+        let next_state = advance(model, problem, current, dt)?;
+        problem
+            .build_input(&current.input, &next_state, dt)
+            .map_err(Error::problem)
+    })
+}
+
+/// Integrates an ODE problem using RK4 until `end` without observation.
+///
+/// This is a convenience wrapper around [`solve_until`] that discards events.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidStep`] if `dt` is not positive,
+/// [`Error::Stalled`] if a step does not advance the independent variable, or
+/// an error if the model or problem returns an error at any point.
+pub fn solve_until_unobserved<M, P>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+) -> UntilResult<M, P>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone,
+{
+    solve_until(model, problem, initial, dt, end, ())
+}
+
+/// Integrates an ODE problem using RK4 until `end`, locating zero crossings
+/// of the problem's event functions.
+///
+/// Behaves like [`euler::solve_with_crossings`](super::euler::solve_with_crossings),
+/// with crossings located by re-taking RK4 steps over a fraction of the step.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidStep`] if `dt` is not positive,
+/// [`Error::Stalled`] if a step does not advance the independent variable, or
+/// an error if the model or problem returns an error at any point, including while locating a
+/// crossing.
+pub fn solve_with_crossings<M, P, Obs, const K: usize>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    dt: P::Delta,
+    end: P::Delta,
+    observer: Obs,
+) -> UntilResult<M, P>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: IndependentVariable<Input = M::Input, Output = M::Output>
+        + ZeroCrossing<K, Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Sub<Output = P::Delta> + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone,
+    Obs: Observer<crossing::Event<M::Input, M::Output>, crossing::Action>,
+{
+    let model = &Counted::new(model);
+    crossing::integrate(model, problem, initial, dt, end, observer, |current, dt| {
+        let next_state = advance(model, problem, current, dt)?;
+        problem
+            .build_input(&current.input, &next_state, dt)
+            .map_err(Error::problem)
+    })
+}
+
+/// Computes the RK4 state at the end of a step from `current`.
+pub(super) fn advance<M, P>(
+    model: &M,
+    problem: &P,
+    current: &Snapshot<M::Input, M::Output>,
+    dt: &P::Delta,
+) -> Result<P::State, Error>
+where
+    M: Model,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone,
+{
+    let base = &current.input;
+    let state = problem.state(base).map_err(Error::problem)?;
+    let half = dt.clone() * 0.5;
+
+    // Derivative at an intermediate stage reached by stepping from the base.
+    let derivative_at = |derivative: DerivativeOf<P::State, P::Delta>, delta: &P::Delta| {
+        let stage_state = state.step(derivative, delta.clone());
+        let input = problem
+            .build_input(base, &stage_state, delta)
+            .map_err(Error::problem)?;
+        let output = model.call(&input).map_err(Error::model)?;
+        problem.derivative(&input, &output).map_err(Error::problem)
+    };
+
+    let k1 = problem
+        .derivative(base, &current.output)
+        .map_err(Error::problem)?;
+    let k2 = derivative_at(k1.clone(), &half)?;
+    let k3 = derivative_at(k2.clone(), &half)?;
+    let k4 = derivative_at(k3.clone(), dt)?;
+
+    let sixth = dt.clone() * (1.0 / 6.0);
+    let third = dt.clone() * (1.0 / 3.0);
+
+    Ok(state
+        .step(k1, sixth.clone())
+        .step(k2, third.clone())
+        .step(k3, third)
+        .step(k4, sixth))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{cell::Cell, convert::Infallible};
+
+    use approx::assert_relative_eq;
+
+    use crate::transient::euler;
+
+    // --- Test fixtures ---
+
+    /// Scalar state.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Value(f64);
+
+    /// Derivative of the scalar state.
+    #[derive(Debug, Clone, Copy)]
+    struct Rate(f64);
+
+    impl StepIntegrable<f64> for Value {
+        type Derivative = Rate;
+
+        fn step(&self, derivative: Rate, dt: f64) -> Self {
+            Value(self.0 + derivative.0 * dt)
+        }
+    }
+
+    /// Model input: current value and time.
+    #[derive(Debug, Clone)]
+    struct Input {
+        value: Value,
+        time: f64,
+    }
+
+    /// Model output: rate of change at the current state.
+    #[derive(Debug, Clone)]
+    struct Output {
+        rate: Rate,
+    }
+
+    /// Exponential decay: `dy/dt = -y`.
+    struct DecayModel;
+
+    impl Model for DecayModel {
+        type Input = Input;
+        type Output = Output;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(Output {
+                rate: Rate(-input.value.0),
+            })
+        }
+    }
+
+    /// Time-dependent rate: `dy/dt = 3 t^2`.
+    struct CubicModel;
+
+    impl Model for CubicModel {
+        type Input = Input;
+        type Output = Output;
+        type Error = Infallible;
+
+        fn call(&self, input: &Self::Input) -> Result<Self::Output, Self::Error> {
+            Ok(Output {
+                rate: Rate(3.0 * input.time * input.time),
+            })
+        }
+    }
+
+    /// Problem that counts how often `finalize_step` is called.
+    #[derive(Default)]
+    struct ValueProblem {
+        finalized: Cell<usize>,
+    }
+
+    impl OdeProblem for ValueProblem {
+        type Input = Input;
+        type Output = Output;
+        type Delta = f64;
+        type State = Value;
+        type Error = Infallible;
+
+        fn state(&self, input: &Self::Input) -> Result<Self::State, Self::Error> {
+            Ok(input.value)
+        }
+
+        fn derivative(
+            &self,
+            _input: &Self::Input,
+            output: &Self::Output,
+        ) -> Result<DerivativeOf<Self::State, Self::Delta>, Self::Error> {
+            Ok(output.rate)
+        }
+
+        fn build_input(
+            &self,
+            base: &Self::Input,
+            state: &Self::State,
+            delta: &Self::Delta,
+        ) -> Result<Self::Input, Self::Error> {
+            Ok(Input {
+                value: *state,
+                time: base.time + delta,
+            })
+        }
+
+        fn finalize_step(
+            &self,
+            next_input: Self::Input,
+            _prev_input: &Self::Input,
+            _prev_output: &Self::Output,
+            _step_delta: &Self::Delta,
+        ) -> Result<Self::Input, Self::Error> {
+            self.finalized.set(self.finalized.get() + 1);
+            Ok(next_input)
+        }
+    }
+
+    impl IndependentVariable for ValueProblem {
+        fn independent_variable(&self, input: &Self::Input) -> Result<Self::Delta, Self::Error> {
+            Ok(input.time)
+        }
+    }
+
+    fn initial(value: f64) -> Input {
+        Input {
+            value: Value(value),
+            time: 0.0,
+        }
+    }
+
+    /// Absolute error in `y(1)` for exponential decay with `steps` steps.
+    fn decay_error(steps: u32) -> f64 {
+        let dt = 1.0 / f64::from(steps);
+        let solution = solve_unobserved(
+            &DecayModel,
+            &ValueProblem::default(),
+            initial(1.0),
+            dt,
+            steps as usize,
+        )
+        .expect("should solve");
+
+        let last = solution.history.last().unwrap();
+        (last.input.value.0 - (-1.0_f64).exp()).abs()
+    }
+
+    // --- Tests ---
+
+    #[test]
+    fn exponential_decay_is_accurate() {
+        let solution =
+            solve_unobserved(&DecayModel, &ValueProblem::default(), initial(1.0), 0.1, 10)
+                .expect("should solve");
+
+        assert_eq!(solution.status, Status::Complete);
+        assert_eq!(solution.steps, 10);
+        assert_eq!(solution.history.len(), 11);
+
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(last.input.time, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(last.input.value.0, (-1.0_f64).exp(), epsilon = 1e-6);
+
+        // Euler at the same step size is far less accurate.
+        let euler =
+            euler::solve_unobserved(&DecayModel, &ValueProblem::default(), initial(1.0), 0.1, 10)
+                .expect("should solve");
+        let euler_error = (euler.history[10].input.value.0 - (-1.0_f64).exp()).abs();
+        assert!(euler_error > 1e-2);
+
+        // Three stage evaluations and one accepted evaluation per step, against
+        // Euler's one, plus the initial state.
+        assert_eq!(solution.stats.evaluations, 41);
+        assert_eq!(euler.stats.evaluations, 11);
+    }
+
+    #[test]
+    fn error_is_fourth_order() {
+        let ratio = decay_error(10) / decay_error(20);
+        assert!((14.0..18.0).contains(&ratio), "ratio = {ratio}");
+    }
+
+    #[test]
+    fn stages_use_intermediate_times() {
+        // RK4 integrates a cubic in time exactly, which requires the stage
+        // inputs to be built at t + dt/2 and t + dt.
+        let solution =
+            solve_unobserved(&CubicModel, &ValueProblem::default(), initial(0.0), 0.5, 4)
+                .expect("should solve");
+
+        for snapshot in &solution.history {
+            let t = snapshot.input.time;
+            assert_relative_eq!(snapshot.input.value.0, t * t * t, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn finalize_step_only_on_accepted_steps() {
+        let problem = ValueProblem::default();
+
+        solve_unobserved(&DecayModel, &problem, initial(1.0), 0.1, 5).expect("should solve");
+
+        assert_eq!(problem.finalized.get(), 5);
+    }
+
+    #[test]
+    fn observer_can_stop_early() {
+        let observer = |event: &Event<Input, Output>| {
+            if event.step >= 3 {
+                Some(Action::StopEarly)
+            } else {
+                None
+            }
+        };
+
+        let solution = solve(
+            &DecayModel,
+            &ValueProblem::default(),
+            initial(1.0),
+            0.1,
+            100,
+            observer,
+        )
+        .expect("should stop early");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert_eq!(solution.steps, 3);
+        assert_eq!(solution.history.len(), 4);
+    }
+
+    #[test]
+    fn solve_until_lands_on_end() {
+        let solution = solve_until_unobserved(
+            &CubicModel,
+            &ValueProblem::default(),
+            initial(0.0),
+            0.4,
+            1.0,
+        )
+        .expect("should solve");
+
+        // Steps of 0.4, 0.4, then 0.2; RK4 stays exact for the cubic.
+        assert_eq!(solution.steps, 3);
+        assert_relative_eq!(solution.end, 1.0);
+        let last = solution.history.last().unwrap();
+        assert_relative_eq!(last.input.value.0, 1.0, epsilon = 1e-12);
+    }
+}
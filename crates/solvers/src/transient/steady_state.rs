@@ -0,0 +1,404 @@
+//! Steady-state solver for ODE problems.
+//!
+//! Many transient models are only integrated to find where they settle. This
+//! module steps an [`OdeProblem`] forward with a fixed step until the state
+//! stops changing, instead of for a chosen number of steps or up to a chosen
+//! time.
+//!
+//! The state's derivative must implement [`Norm`]. After every step, the norm
+//! of the derivative is compared against [`Config::tol`], and the solver
+//! finishes with [`Status::SteadyState`] once [`Config::window`] consecutive
+//! snapshots are within tolerance. Requiring a window guards against stopping
+//! where the derivative passes through zero, as at the turning point of an
+//! oscillation. If the state has not settled after [`Config::max_steps`], the
+//! solver finishes with [`Status::MaxSteps`].
+//!
+//! Steps are taken with forward Euler or RK4, chosen by [`Config::method`].
+//! Only the last snapshot is kept; observers receive every one.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use twine_solvers::transient::steady_state;
+//!
+//! let config = steady_state::Config {
+//!     tol: 1e-9,
+//!     ..steady_state::Config::new(0.5)
+//! };
+//! let solution = steady_state::solve_unobserved(&model, &problem, initial_input, &config)?;
+//! assert_eq!(solution.status, steady_state::Status::SteadyState);
+//! ```
+
+mod config;
+mod error;
+mod event;
+mod solution;
+
+pub use config::{Config, ConfigError, Method};
+pub use error::Error;
+pub use event::Event;
+pub use solution::{Solution, Status};
+
+pub use super::euler::Action;
+
+use std::ops::Mul;
+
+use twine_core::{DerivativeOf, Model, Norm, Observer, OdeProblem, Snapshot};
+
+use crate::stats::Counted;
+
+use super::{euler, fixed_step, rk4};
+
+/// Integrates an ODE problem until its derivative settles within tolerance.
+///
+/// # Algorithm
+///
+/// 1. Call the model with the initial input.
+/// 2. For each snapshot, starting with the initial one:
+///    - Compute the norm of the derivative and update the count of
+///      consecutive settled snapshots.
+///    - Emit an [`Event`] to the observer.
+///    - Finish if the observer returns `StopEarly`, the window is full, or
+///      the step limit is reached.
+///    - Otherwise, take a step with the configured method, finalize it, and
+///      call the model on the next input.
+///
+/// # Observer
+///
+/// The observer receives an [`Event`] for every snapshot and may return
+/// [`Action::StopEarly`] to terminate early.
+///
+/// # Errors
+///
+/// Returns an error if the config is invalid, or if the model or problem
+/// returns an error at any point.
+pub fn solve<M, P, Obs>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    config: &Config<P::Delta>,
+    mut observer: Obs,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone + Norm,
+    Obs: Observer<Event<M::Input, M::Output>, Action>,
+{
+    config.validate()?;
+    let model = &Counted::new(model);
+
+    // Evaluate initial state.
+    let initial_output = model.call(&initial).map_err(Error::model)?;
+    let mut current = Snapshot::new(initial, initial_output);
+    let mut settled = 0;
+    let mut step = 0;
+
+    loop {
+        let norm = problem
+            .derivative(&current.input, &current.output)
+            .map_err(Error::problem)?
+            .norm();
+        settled = if norm <= config.tol { settled + 1 } else { 0 };
+
+        let event = Event {
+            step,
+            norm,
+            settled,
+            snapshot: current.clone(),
+        };
+        let action = observer.observe(&event);
+        model.tally().observed(action.as_ref());
+
+        let status = if let Some(Action::StopEarly) = action {
+            Some(Status::StoppedByObserver)
+        } else if settled >= config.window {
+            Some(Status::SteadyState)
+        } else if step >= config.max_steps {
+            Some(Status::MaxSteps)
+        } else {
+            None
+        };
+
+        if let Some(status) = status {
+            return Ok(Solution {
+                status,
+                snapshot: current,
+                norm,
+                steps: step,
+                stats: model.tally().stats(),
+            });
+        }
+
+        step += 1;
+        let next_input = advance(model, problem, &current, config).map_err(Error::step)?;
+        current = fixed_step::accept(model, problem, &current, next_input, &config.dt)
+            .map_err(Error::step)?;
+    }
+}
+
+/// Integrates an ODE problem to steady state without observation.
+///
+/// This is a convenience wrapper around [`solve`] that discards events.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`solve`].
+pub fn solve_unobserved<M, P>(
+    model: &M,
+    problem: &P,
+    initial: M::Input,
+    config: &Config<P::Delta>,
+) -> Result<Solution<M::Input, M::Output>, Error>
+where
+    M: Model,
+    M::Input: Clone,
+    M::Output: Clone,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + PartialOrd + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone + Norm,
+{
+    solve(model, problem, initial, config, ())
+}
+
+/// Computes the next (unfinalized) input with the configured method.
+fn advance<M, P>(
+    model: &M,
+    problem: &P,
+    current: &Snapshot<M::Input, M::Output>,
+    config: &Config<P::Delta>,
+) -> Result<M::Input, euler::Error>
+where
+    M: Model,
+    P: OdeProblem<Input = M::Input, Output = M::Output>,
+    P::Delta: Clone + Mul<f64, Output = P::Delta>,
+    DerivativeOf<P::State, P::Delta>: Clone,
+{
+    match config.method {
+        Method::Euler => euler::advance(problem, current, &config.dt),
+        Method::Rk4 => {
+            let next_state = rk4::advance(model, problem, current, &config.dt)?;
+            problem
+                .build_input(&current.input, &next_state, &config.dt)
+                .map_err(euler::Error::problem)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    use approx::assert_relative_eq;
+    use twine_core::StepIntegrable;
+
+    // --- Test fixtures ---
+
+    /// Tank temperature.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Temperature(f64);
+
+    /// Rate of change of the tank temperature.
+    #[derive(Debug, Clone, Copy)]
+    struct Rate(f64);
+
+    impl StepIntegrable<f64> for Temperature {
+        type Derivative = Rate;
+
+        fn step(&self, derivative: Rate, dt: f64) -> Self {
+            Temperature(self.0 + derivative.0 * dt)
+        }
+    }
+
+    impl Norm for Rate {
+        fn norm(&self) -> f64 {
+            self.0.abs()
+        }
+    }
+
+    /// Tank that relaxes toward its surroundings.
+    struct Cooling {
+        surroundings: f64,
+        rate: f64,
+    }
+
+    impl Model for Cooling {
+        type Input = Temperature;
+        type Output = Rate;
+        type Error = Infallible;
+
+        fn call(&self, input: &Temperature) -> Result<Rate, Infallible> {
+            Ok(Rate(self.rate * (self.surroundings - input.0)))
+        }
+    }
+
+    /// Tank that heats forever, so it never settles.
+    struct Heating;
+
+    impl Model for Heating {
+        type Input = Temperature;
+        type Output = Rate;
+        type Error = Infallible;
+
+        fn call(&self, _input: &Temperature) -> Result<Rate, Infallible> {
+            Ok(Rate(1.0))
+        }
+    }
+
+    /// Problem whose state is the whole input and derivative the whole output.
+    struct TankProblem;
+
+    impl OdeProblem for TankProblem {
+        type Input = Temperature;
+        type Output = Rate;
+        type Delta = f64;
+        type State = Temperature;
+        type Error = Infallible;
+
+        fn state(&self, input: &Temperature) -> Result<Temperature, Infallible> {
+            Ok(*input)
+        }
+
+        fn derivative(&self, _input: &Temperature, output: &Rate) -> Result<Rate, Infallible> {
+            Ok(*output)
+        }
+
+        fn build_input(
+            &self,
+            _base: &Temperature,
+            state: &Temperature,
+            _delta: &f64,
+        ) -> Result<Temperature, Infallible> {
+            Ok(*state)
+        }
+    }
+
+    fn cooling() -> Cooling {
+        Cooling {
+            surroundings: 20.0,
+            rate: 0.5,
+        }
+    }
+
+    // --- Tests ---
+
+    #[test]
+    fn settles_at_surroundings() {
+        for method in [Method::Euler, Method::Rk4] {
+            let config = Config {
+                tol: 1e-9,
+                method,
+                ..Config::new(0.5)
+            };
+
+            let solution = solve_unobserved(&cooling(), &TankProblem, Temperature(80.0), &config)
+                .expect("should settle");
+
+            assert_eq!(solution.status, Status::SteadyState, "{method:?}");
+            assert!(solution.norm <= 1e-9);
+            assert_relative_eq!(solution.snapshot.input.0, 20.0, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn window_requires_consecutive_settled_snapshots() {
+        let config = Config {
+            tol: 1e-3,
+            window: 5,
+            method: Method::Euler,
+            ..Config::new(0.5)
+        };
+
+        let mut first_settled = None;
+        let observer = |event: &Event<Temperature, Rate>| {
+            if event.settled == 1 {
+                first_settled = Some(event.step);
+            }
+            None
+        };
+        let solution = solve(
+            &cooling(),
+            &TankProblem,
+            Temperature(80.0),
+            &config,
+            observer,
+        )
+        .expect("should settle");
+
+        let first_settled = first_settled.expect("should have settled");
+        assert_eq!(solution.status, Status::SteadyState);
+        assert_eq!(solution.steps, first_settled + 4);
+    }
+
+    #[test]
+    fn already_settled_finishes_without_steps() {
+        let config = Config {
+            window: 1,
+            ..Config::new(0.5)
+        };
+
+        let solution = solve_unobserved(&cooling(), &TankProblem, Temperature(20.0), &config)
+            .expect("should settle");
+
+        assert_eq!(solution.status, Status::SteadyState);
+        assert_eq!(solution.steps, 0);
+        assert_eq!(solution.stats.evaluations, 1);
+    }
+
+    #[test]
+    fn reports_max_steps_when_not_settled() {
+        let config = Config {
+            max_steps: 10,
+            method: Method::Euler,
+            ..Config::new(0.5)
+        };
+
+        let solution = solve_unobserved(&Heating, &TankProblem, Temperature(20.0), &config)
+            .expect("should finish");
+
+        assert_eq!(solution.status, Status::MaxSteps);
+        assert_eq!(solution.steps, 10);
+        assert_relative_eq!(solution.snapshot.input.0, 25.0);
+        assert_relative_eq!(solution.norm, 1.0);
+    }
+
+    #[test]
+    fn observer_can_stop_early() {
+        let observer = |event: &Event<Temperature, Rate>| {
+            (event.snapshot.input.0 < 50.0).then_some(Action::StopEarly)
+        };
+
+        let solution = solve(
+            &cooling(),
+            &TankProblem,
+            Temperature(80.0),
+            &Config::new(0.5),
+            observer,
+        )
+        .expect("should stop cleanly");
+
+        assert_eq!(solution.status, Status::StoppedByObserver);
+        assert!(solution.snapshot.input.0 < 50.0);
+        assert_eq!(solution.stats.overridden, 1);
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        let result = solve_unobserved(
+            &cooling(),
+            &TankProblem,
+            Temperature(80.0),
+            &Config::new(-0.5),
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidConfig(ConfigError::Step))
+        ));
+    }
+}
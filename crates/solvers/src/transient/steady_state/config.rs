@@ -0,0 +1,116 @@
+use std::ops::Mul;
+
+use thiserror::Error;
+
+/// Explicit method used to take each step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Forward Euler, with one model call per step.
+    Euler,
+    /// Classic fourth-order Runge–Kutta, with four model calls per step.
+    ///
+    /// Stable for larger steps than Euler, so it often settles in fewer calls.
+    Rk4,
+}
+
+/// Configuration for the steady-state solver.
+///
+/// The step size uses the problem's `Delta` type, so dimensioned deltas like
+/// `uom::Time` work as well as plain `f64`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config<D> {
+    /// Size of every step.
+    pub dt: D,
+    /// Derivative norm at or below which a snapshot counts as settled.
+    pub tol: f64,
+    /// Number of consecutive settled snapshots required to finish.
+    pub window: usize,
+    /// Maximum number of steps.
+    pub max_steps: usize,
+    /// Method used to take each step.
+    pub method: Method,
+}
+
+/// Errors that can occur when validating a steady-state config.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("dt must be positive")]
+    Step,
+
+    #[error("tol must be finite and non-negative")]
+    Tolerance,
+
+    #[error("window must be at least 1")]
+    Window,
+}
+
+impl<D> Config<D> {
+    /// Creates a config with the given step size and default settings.
+    ///
+    /// Defaults are `tol = 1e-6`, `window = 10`, `max_steps = 100_000`, and
+    /// `method = Method::Rk4`.
+    pub fn new(dt: D) -> Self {
+        Self {
+            dt,
+            tol: 1e-6,
+            window: 10,
+            max_steps: 100_000,
+            method: Method::Rk4,
+        }
+    }
+}
+
+impl<D> Config<D>
+where
+    D: Clone + PartialOrd + Mul<f64, Output = D>,
+{
+    /// Validates the step size, tolerance, and window.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any setting is out of range.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let zero = self.dt.clone() * 0.0;
+        if self.dt <= zero || self.dt.partial_cmp(&zero).is_none() {
+            return Err(ConfigError::Step);
+        }
+
+        if !self.tol.is_finite() || self.tol < 0.0 {
+            return Err(ConfigError::Tolerance);
+        }
+
+        if self.window == 0 {
+            return Err(ConfigError::Window);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_is_valid() {
+        assert!(Config::new(0.1).validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_out_of_range_settings() {
+        assert_eq!(Config::new(0.0).validate(), Err(ConfigError::Step));
+        assert_eq!(Config::new(f64::NAN).validate(), Err(ConfigError::Step));
+
+        let config = Config {
+            tol: -1.0,
+            ..Config::new(0.1)
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Tolerance));
+
+        let config = Config {
+            window: 0,
+            ..Config::new(0.1)
+        };
+        assert_eq!(config.validate(), Err(ConfigError::Window));
+    }
+}
@@ -0,0 +1,38 @@
+use std::error::Error as StdError;
+
+use super::{super::euler, ConfigError};
+
+/// Errors that can occur during a steady-state solve.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("invalid config: {0}")]
+    InvalidConfig(#[from] ConfigError),
+
+    #[error("model error: {0}")]
+    Model(#[source] Box<dyn StdError + Send + Sync>),
+
+    #[error("problem error: {0}")]
+    Problem(#[source] Box<dyn StdError + Send + Sync>),
+}
+
+impl Error {
+    pub(crate) fn model<E: StdError + Send + Sync + 'static>(err: E) -> Self {
+        Self::Model(Box::new(err))
+    }
+
+    pub(crate) fn problem<E: StdError + Send + Sync + 'static>(err: E) -> Self {
+        Self::Problem(Box::new(err))
+    }
+
+    /// Converts an error from the shared explicit step functions.
+    ///
+    /// Explicit steps only fail in the model or problem, so any other variant
+    /// is kept as a problem error rather than lost.
+    pub(super) fn step(err: euler::Error) -> Self {
+        match err {
+            euler::Error::Model(err) => Self::Model(err),
+            euler::Error::Problem(err) => Self::Problem(err),
+            err => Self::problem(err),
+        }
+    }
+}
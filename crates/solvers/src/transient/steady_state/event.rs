@@ -0,0 +1,19 @@
+use twine_core::Snapshot;
+
+/// Event emitted by the steady-state solver for each snapshot.
+///
+/// Step 0 is the initial state before any integration.
+#[derive(Debug, Clone)]
+pub struct Event<I, O> {
+    /// The step number (0 for the initial state).
+    pub step: usize,
+
+    /// Norm of the state's derivative at this snapshot.
+    pub norm: f64,
+
+    /// Number of consecutive settled snapshots, including this one.
+    pub settled: usize,
+
+    /// Snapshot of the model input and output at this step.
+    pub snapshot: Snapshot<I, O>,
+}
@@ -0,0 +1,35 @@
+use twine_core::Snapshot;
+
+use crate::Stats;
+
+/// Indicates how the solver terminated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The derivative norm stayed within tolerance for the whole window.
+    SteadyState,
+
+    /// Reached the maximum number of steps before settling.
+    MaxSteps,
+
+    /// Stopped early due to an observer action.
+    StoppedByObserver,
+}
+
+/// The result of a steady-state solve.
+#[derive(Debug, Clone)]
+pub struct Solution<I, O> {
+    /// How the solver terminated.
+    pub status: Status,
+
+    /// The last snapshot, which is the settled state when converged.
+    pub snapshot: Snapshot<I, O>,
+
+    /// Norm of the state's derivative at the last snapshot.
+    pub norm: f64,
+
+    /// Number of integration steps completed.
+    pub steps: usize,
+
+    /// Model evaluations and time spent, including intermediate stages.
+    pub stats: Stats,
+}